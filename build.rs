@@ -0,0 +1,132 @@
+//! Generates the unit and adjoint tables of the octavian integers from their defining data in `src/definitions.rs`.
+//! The units are the E8 roots, found by closing the simple roots under the simple reflections.
+//! The adjoint matrices record the products of the simple roots, computed in the octonion basis with the Fano-plane table.
+
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+include!("src/definitions.rs");
+
+/// Returns the inner product of two vectors of E8 lattice coordinates.
+fn inner_product(x: &[i64; 8], y: &[i64; 8]) -> i64 {
+    let mut sum = 0;
+    for (i, row) in GRAM_MATRIX.iter().enumerate() {
+        for (j, &g) in row.iter().enumerate() {
+            sum += i64::from(g) * x[i] * y[j];
+        }
+    }
+    sum
+}
+
+/// Returns the 240 roots in increasing order, the orbit of the simple roots under the simple reflections
+/// x ↦ x - <x, a> a.
+fn roots() -> Vec<[i64; 8]> {
+    let simple: Vec<[i64; 8]> = (0..8)
+        .map(|i| std::array::from_fn(|j| i64::from(i == j)))
+        .collect();
+    let mut roots: BTreeSet<[i64; 8]> = simple.iter().copied().collect();
+    let mut frontier = simple.clone();
+    while let Some(x) = frontier.pop() {
+        for a in &simple {
+            let c = inner_product(&x, a);
+            let image = std::array::from_fn(|i| x[i] - c * a[i]);
+            if roots.insert(image) {
+                frontier.push(image);
+            }
+        }
+    }
+    assert_eq!(roots.len(), 240, "E8 has 240 roots");
+    roots.into_iter().collect()
+}
+
+/// Multiplies two octonions given by their coordinates in the basis 1, e1, ..., e7.
+fn octonion_mul(a: &[i64; 8], b: &[i64; 8]) -> [i64; 8] {
+    // Entry [i][j] is (sign, k) with e_i e_j = sign e_k.
+    let mut table = [[(1i64, 0usize); 8]; 8];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = (1, i);
+        row[i] = (-1, 0);
+    }
+    table[0] = std::array::from_fn(|i| (1, i));
+    for [i, j, k] in FANO_TRIPLES {
+        for (x, y, z) in [(i, j, k), (j, k, i), (k, i, j)] {
+            table[x][y] = (1, z);
+            table[y][x] = (-1, z);
+        }
+    }
+    let mut result = [0; 8];
+    for i in 0..8 {
+        for j in 0..8 {
+            let (sign, k) = table[i][j];
+            result[k] += sign * a[i] * b[j];
+        }
+    }
+    result
+}
+
+/// Returns the left adjoint matrices of the simple roots: entry [k][i][j] is the i-th coefficient of b_k b_j.
+fn adjoint_matrices() -> [[[i64; 8]; 8]; 8] {
+    let octonion_basis = OCTONION_BASIS.map(|row| row.map(i64::from));
+    // Twice the octonion coordinates of x, which are the inner products with the orthonormal octonion basis.
+    let doubled = |x: &[i64; 8]| octonion_basis.map(|e| inner_product(x, &e));
+    let simple: [[i64; 8]; 8] = std::array::from_fn(|i| std::array::from_fn(|j| i64::from(i == j)));
+    let mut adjoint = [[[0; 8]; 8]; 8];
+    for (k, b_k) in simple.iter().enumerate() {
+        for (j, b_j) in simple.iter().enumerate() {
+            // Four times the octonion coordinates of b_k b_j.
+            let product = octonion_mul(&doubled(b_k), &doubled(b_j));
+            for (i, row) in adjoint[k].iter_mut().enumerate() {
+                let c: i64 = (0..8).map(|r| product[r] * octonion_basis[r][i]).sum();
+                assert_eq!(
+                    c % 4,
+                    0,
+                    "the octavian integers are closed under multiplication"
+                );
+                row[j] = c / 4;
+            }
+        }
+    }
+    adjoint
+}
+
+/// Formats nested arrays of integers as a Rust array expression.
+fn write_array(out: &mut String, rows: impl IntoIterator<Item = String>, indent: usize) {
+    out.push_str("[\n");
+    for row in rows {
+        let _ = writeln!(out, "{}{},", " ".repeat(indent + 4), row);
+    }
+    let _ = write!(out, "{}]", " ".repeat(indent));
+}
+
+fn format_row(row: &[i64; 8]) -> String {
+    assert!(row.iter().all(|&x| i8::try_from(x).is_ok()));
+    let entries: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+    format!("[{}]", entries.join(", "))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/definitions.rs");
+
+    let mut out = String::from("// Generated by build.rs from src/definitions.rs.\n\n");
+    out.push_str("pub const OCTAVIAN_ADJOINT_MATRICES: [[[i8; 8]; 8]; 8] = ");
+    let matrices = adjoint_matrices().map(|m| {
+        let mut s = String::new();
+        write_array(&mut s, m.iter().map(format_row), 4);
+        s
+    });
+    write_array(&mut out, matrices, 0);
+    out.push_str(";\n");
+    // Without the `tables` feature only the adjoint matrices are needed, which is all multiplication uses.
+    if env::var_os("CARGO_FEATURE_TABLES").is_some() {
+        out.push_str("\npub const OCTAVIAN_UNITS_COEFFICIENTS: [[i8; 8]; 240] = ");
+        write_array(&mut out, roots().iter().map(format_row), 0);
+        out.push_str(";\n");
+    }
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("tables.rs");
+    fs::write(path, out).unwrap();
+}
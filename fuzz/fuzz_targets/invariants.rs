@@ -0,0 +1,34 @@
+//! Builds two octavians from the input bytes and checks the algebraic identities between them.
+
+#![no_main]
+
+use alco_rs::invariants::check_invariants;
+use alco_rs::octavian::Octavian;
+use libfuzzer_sys::fuzz_target;
+
+/// Reads an octavian from up to 64 bytes, 8 little-endian bytes per coefficient, padding with zeros.
+/// Shorter coefficients are sign-extended from fewer bytes, so small elements are easy to reach.
+fn octavian(bytes: &[u8], width: usize) -> Octavian<i64> {
+    let mut coefficients = [0i64; 8];
+    for (c, chunk) in coefficients.iter_mut().zip(bytes.chunks(width)) {
+        let mut buffer = [0u8; 8];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let shift = 64 - 8 * chunk.len() as u32;
+        *c = i64::from_le_bytes(buffer)
+            .wrapping_shl(shift)
+            .wrapping_shr(shift);
+    }
+    Octavian::new(coefficients)
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, rest)) = data.split_first() else {
+        return;
+    };
+    let width = usize::from(selector % 8) + 1;
+    let (left, right) = rest.split_at(rest.len().min(8 * width));
+    let (a, b) = (octavian(left, width), octavian(right, width));
+    if let Err(message) = check_invariants(&a, &b) {
+        panic!("{}", message);
+    }
+});
@@ -0,0 +1,17 @@
+//! Parses arbitrary text as an octavian, checking that whatever parses prints back to the same element.
+
+#![no_main]
+
+use alco_rs::octavian::Octavian;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    let _ = s.parse::<Octavian<i8>>();
+    if let Ok(x) = s.parse::<Octavian<i64>>() {
+        let printed: Vec<String> = x.coefficients.iter().map(|c| c.to_string()).collect();
+        assert_eq!(Ok(x), printed.join(",").parse());
+    }
+});
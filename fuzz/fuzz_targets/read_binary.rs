@@ -0,0 +1,18 @@
+//! Reads arbitrary bytes in the binary format, checking that whatever reads writes back to the same bytes,
+//! apart from the two reserved header bytes, which the reader ignores and the writer zeroes.
+
+#![no_main]
+
+use alco_rs::io;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = io::read_binary::<_, i8>(data);
+    let _ = io::read_binary_widening::<_, i128>(data);
+    if let Ok(elements) = io::read_binary::<_, i64>(data) {
+        let mut buffer = Vec::new();
+        io::write_binary(&mut buffer, &elements).unwrap();
+        assert_eq!(data[..6], buffer[..6]);
+        assert_eq!(data[8..], buffer[8..]);
+    }
+});
@@ -0,0 +1,14 @@
+//! Reads arbitrary bytes as CSV, checking that whatever reads writes back to the same elements.
+
+#![no_main]
+
+use alco_rs::io;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(elements) = io::read_csv(data) {
+        let mut buffer = Vec::new();
+        io::write_csv(&mut buffer, &elements).unwrap();
+        assert_eq!(elements, io::read_csv(buffer.as_slice()).unwrap());
+    }
+});
@@ -0,0 +1,76 @@
+#ifndef ALCO_H
+#define ALCO_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The result of a call through the C interface.
+ */
+typedef enum AlcoStatus {
+  /**
+   * The call succeeded and wrote its result.
+   */
+  ALCO_STATUS_OK = 0,
+  /**
+   * A pointer argument was null.
+   */
+  ALCO_STATUS_NULL_POINTER = 1,
+  /**
+   * The result does not fit in `int64_t`.
+   */
+  ALCO_STATUS_OVERFLOW = 2,
+  /**
+   * The input has no result, such as a point with a coordinate that is not finite.
+   */
+  ALCO_STATUS_UNDEFINED = 3,
+  /**
+   * The computation panicked.
+   */
+  ALCO_STATUS_PANIC = 4,
+} AlcoStatus;
+
+/**
+ * Writes the product a * b to `out`.
+ * The product is computed in 128-bit integers with checked arithmetic, and `Overflow` is returned
+ * if it does not fit in `int64_t` or a partial sum does not fit in 128 bits.
+ *
+ * # Safety
+ * `a` and `b` must each point to 8 readable `int64_t`, and `out` to 8 writable ones.
+ */
+enum AlcoStatus alco_mul(const int64_t *a,
+                         const int64_t *b,
+                         int64_t *out);
+
+/**
+ * Writes the norm of a to `out`.
+ * The norm is computed in 128-bit integers with checked arithmetic, and `Overflow` is returned
+ * if it does not fit in `int64_t` or a partial sum does not fit in 128 bits.
+ *
+ * # Safety
+ * `a` must point to 8 readable `int64_t`, and `out` to one writable `int64_t`.
+ */
+enum AlcoStatus alco_norm(const int64_t *a, int64_t *out);
+
+/**
+ * Writes the conjugate of a to `out`.
+ *
+ * # Safety
+ * `a` must point to 8 readable `int64_t`, and `out` to 8 writable ones.
+ */
+enum AlcoStatus alco_conj(const int64_t *a, int64_t *out);
+
+/**
+ * Writes an octavian nearest to the point x, given in E8 lattice coordinates, to `out`.
+ *
+ * # Safety
+ * `x` must point to 8 readable `double`, and `out` to 8 writable `int64_t`.
+ */
+enum AlcoStatus alco_closest_lattice_point(const double *x, int64_t *out);
+
+#endif  /* ALCO_H */
@@ -0,0 +1,41 @@
+//! Conversions between slices of octavians and `ndarray` arrays with one octavian per row.
+
+use crate::octavian::{Coefficient, Octavian};
+use crate::tables;
+use core::ops::Neg;
+use ndarray::{Array2, ArrayView2};
+use num_traits::{FromPrimitive, Num};
+
+/// Returns an n × 8 array whose i-th row holds the coefficients of the i-th octavian.
+pub fn octavians_to_array2<T>(octavians: &[Octavian<T>]) -> Array2<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    Array2::from_shape_fn((octavians.len(), 8), |(i, j)| octavians[i].coefficients[j])
+}
+
+/// Returns the octavians whose coefficients are the rows of the array, or `None` if the array does not have 8 columns.
+pub fn array2_to_octavians<T>(array: ArrayView2<'_, T>) -> Option<Vec<Octavian<T>>>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    if array.ncols() != 8 {
+        return None;
+    }
+    Some(
+        array
+            .rows()
+            .into_iter()
+            .map(|row| Octavian::new(core::array::from_fn(|j| row[j])))
+            .collect(),
+    )
+}
+
+/// Returns the Gram matrix of the E8 lattice coordinates as an 8 × 8 array.
+pub fn gram_as_array2<T>() -> Array2<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    let gram = tables::gram_matrix_typed::<T>();
+    Array2::from_shape_fn((8, 8), |(i, j)| gram[i][j])
+}
@@ -0,0 +1,217 @@
+use crate::octavian::Octavian;
+use crate::tables;
+use num::rational::Ratio;
+use num_traits::{One, Signed, Zero};
+
+/// A Z-basis of the octavian integers, related to the default E8 basis by a unimodular change-of-basis matrix.
+/// The Gram matrix and the adjoint matrices in the new basis are derived once, on construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basis {
+    /// Row `i` holds the default E8 lattice coordinates of the `i`-th vector of this basis.
+    matrix: [[i64; 8]; 8],
+    /// The inverse of `matrix`, which is again an integer matrix since `matrix` is unimodular.
+    inverse: [[i64; 8]; 8],
+    gram_matrix: [[i64; 8]; 8],
+    adjoint_matrices: [[[i64; 8]; 8]; 8],
+}
+
+impl Basis {
+    /// Create a new `Basis` from a change-of-basis matrix whose rows are the default coordinates of the new basis vectors.
+    /// Returns `None` unless the matrix is unimodular, since otherwise the rows do not span the octavian integers.
+    pub fn new(matrix: [[i64; 8]; 8]) -> Option<Self> {
+        let inverse = unimodular_inverse(&matrix)?;
+        let gram = tables::gram_matrix_i64();
+        let adjoint = tables::adjoint_matrices_i64();
+
+        // G' = P G P^T
+        let mut gram_matrix = [[0i64; 8]; 8];
+        for (i, gram_row) in gram_matrix.iter_mut().enumerate() {
+            for (j, entry) in gram_row.iter_mut().enumerate() {
+                for a in 0..8 {
+                    for b in 0..8 {
+                        *entry += matrix[i][a] * gram[a][b] * matrix[j][b];
+                    }
+                }
+            }
+        }
+
+        // The product of the k-th and j-th new basis vectors, expressed in default coordinates and then converted back.
+        let mut adjoint_matrices = [[[0i64; 8]; 8]; 8];
+        for (k, adjoint_matrix) in adjoint_matrices.iter_mut().enumerate() {
+            for j in 0..8 {
+                let mut product = [0i64; 8];
+                for a in 0..8 {
+                    for b in 0..8 {
+                        let c = matrix[k][a] * matrix[j][b];
+                        if c == 0 {
+                            continue;
+                        }
+                        for (m, p) in product.iter_mut().enumerate() {
+                            *p += c * adjoint[a][m][b];
+                        }
+                    }
+                }
+                for (i, row) in adjoint_matrix.iter_mut().enumerate() {
+                    row[j] = (0..8).map(|m| product[m] * inverse[m][i]).sum();
+                }
+            }
+        }
+
+        Some(Basis {
+            matrix,
+            inverse,
+            gram_matrix,
+            adjoint_matrices,
+        })
+    }
+
+    /// The default E8 basis used by `Octavian`.
+    pub fn standard() -> Self {
+        let mut matrix = [[0i64; 8]; 8];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1;
+        }
+        Self::new(matrix).unwrap()
+    }
+
+    /// Returns the change-of-basis matrix, whose rows are the default coordinates of the basis vectors.
+    pub fn matrix(&self) -> &[[i64; 8]; 8] {
+        &self.matrix
+    }
+
+    /// Returns the inner products between the basis vectors.
+    pub fn gram_matrix(&self) -> &[[i64; 8]; 8] {
+        &self.gram_matrix
+    }
+
+    /// Returns the left adjoint matrices of the basis vectors, in the same layout as `Octavian::OCTAVIAN_ADJOINT_MATRICES`.
+    pub fn adjoint_matrices(&self) -> &[[[i64; 8]; 8]; 8] {
+        &self.adjoint_matrices
+    }
+
+    /// Converts coordinates in this basis into default coordinates.
+    pub fn to_standard(&self, x: &Octavian<i64>) -> Octavian<i64> {
+        let mut coefficients = [0i64; 8];
+        for (j, c) in coefficients.iter_mut().enumerate() {
+            *c = (0..8).map(|i| x.coefficients[i] * self.matrix[i][j]).sum();
+        }
+        Octavian::new(coefficients)
+    }
+
+    /// Converts default coordinates into coordinates in this basis.
+    pub fn from_standard(&self, x: &Octavian<i64>) -> Octavian<i64> {
+        let mut coefficients = [0i64; 8];
+        for (j, c) in coefficients.iter_mut().enumerate() {
+            *c = (0..8).map(|i| x.coefficients[i] * self.inverse[i][j]).sum();
+        }
+        Octavian::new(coefficients)
+    }
+
+    /// Computes the left adjoint matrix of an element given in this basis.
+    pub fn left_adjoint_matrix(&self, x: &Octavian<i64>) -> [[i64; 8]; 8] {
+        let mut result = [[0i64; 8]; 8];
+        for (matrix, &coeff) in self.adjoint_matrices.iter().zip(&x.coefficients) {
+            for (i, row) in matrix.iter().enumerate() {
+                for (j, &value) in row.iter().enumerate() {
+                    result[i][j] += value * coeff;
+                }
+            }
+        }
+        result
+    }
+
+    /// Multiplies two elements given in this basis.
+    pub fn mul(&self, x: &Octavian<i64>, y: &Octavian<i64>) -> Octavian<i64> {
+        let left_matrix = self.left_adjoint_matrix(x);
+        let mut coefficients = [0i64; 8];
+        for (c, row) in coefficients.iter_mut().zip(&left_matrix) {
+            *c = row.iter().zip(&y.coefficients).map(|(l, y)| l * y).sum();
+        }
+        Octavian::new(coefficients)
+    }
+
+    /// Returns the inner product of two elements given in this basis.
+    pub fn inner_product(&self, x: &Octavian<i64>, y: &Octavian<i64>) -> i64 {
+        let mut result = 0;
+        for (i, row) in self.gram_matrix.iter().enumerate() {
+            for (j, &g) in row.iter().enumerate() {
+                result += x.coefficients[i] * g * y.coefficients[j];
+            }
+        }
+        result
+    }
+
+    /// Returns the norm of an element given in this basis.
+    pub fn norm(&self, x: &Octavian<i64>) -> i64 {
+        self.inner_product(x, x) / 2
+    }
+}
+
+impl Octavian<i64> {
+    /// Converts coordinates in the basis `from` into coordinates in the basis `to`.
+    pub fn rebase(&self, from: &Basis, to: &Basis) -> Self {
+        to.from_standard(&from.to_standard(self))
+    }
+}
+
+impl Octavian<Ratio<i64>> {
+    /// Returns the dual basis b*_0, ..., b*_7, characterized by <b*_i, b_j> = 1 if i = j and 0 otherwise.
+    /// The dual basis vectors are the rows of the inverse Gram matrix, which is integral because E8 is unimodular.
+    pub fn dual_basis() -> [Self; 8] {
+        tables::inverse_gram_matrix_i64().map(|row| Octavian::new(row.map(Ratio::from_integer)))
+    }
+
+    /// Returns the E8 lattice coordinates of a rational octonion given in the standard basis 1, e1, ..., e7.
+    /// The i-th coordinate is the inner product of the octonion with the dual basis vector b*_i.
+    pub fn coordinates_of(x: &[Ratio<i64>; 8]) -> [Ratio<i64>; 8] {
+        let basis =
+            Self::OCTONION_BASIS.map(|row| Octavian::new(row.map(|c| Ratio::from(i64::from(c)))));
+        Self::dual_basis().map(|d| {
+            basis
+                .iter()
+                .zip(x)
+                .map(|(e, &c)| d.inner_product(e) * c)
+                .sum()
+        })
+    }
+}
+
+/// Inverts an integer matrix by Gauss-Jordan elimination over the rationals.
+/// Returns `None` when the determinant is not ±1.
+pub(crate) fn unimodular_inverse(matrix: &[[i64; 8]; 8]) -> Option<[[i64; 8]; 8]> {
+    let mut a = matrix.map(|row| row.map(|x| Ratio::from_integer(x as i128)));
+    let mut inv = [[Ratio::<i128>::zero(); 8]; 8];
+    for (i, row) in inv.iter_mut().enumerate() {
+        row[i] = Ratio::one();
+    }
+    let mut det = Ratio::<i128>::one();
+    for col in 0..8 {
+        let pivot = (col..8).find(|&r| !a[r][col].is_zero())?;
+        if pivot != col {
+            a.swap(pivot, col);
+            inv.swap(pivot, col);
+            det = -det;
+        }
+        let p = a[col][col];
+        det *= p;
+        for j in 0..8 {
+            a[col][j] /= p;
+            inv[col][j] /= p;
+        }
+        for r in 0..8 {
+            if r == col || a[r][col].is_zero() {
+                continue;
+            }
+            let f = a[r][col];
+            for j in 0..8 {
+                let (x, y) = (a[col][j], inv[col][j]);
+                a[r][j] -= f * x;
+                inv[r][j] -= f * y;
+            }
+        }
+    }
+    if det.abs() != Ratio::one() {
+        return None;
+    }
+    Some(inv.map(|row| row.map(|x| x.to_integer() as i64)))
+}
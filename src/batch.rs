@@ -0,0 +1,145 @@
+use crate::error::AlcoError;
+use crate::factor::factor;
+#[cfg(feature = "tables")]
+use crate::octavian::gcrd;
+use crate::octavian::{Coefficient, Octavian};
+use crate::tables;
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+#[cfg(feature = "par")]
+use rayon::prelude::*;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Returns every product x * y with x in `lhs` and y in `rhs`, in row-major order: all products with `lhs[0]` first.
+/// The left multiplier of each x is computed once and reused across `rhs`.
+pub fn multiply_all_pairs<T>(lhs: &[Octavian<T>], rhs: &[Octavian<T>]) -> Vec<Octavian<T>>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    lhs.iter()
+        .flat_map(|x| {
+            let left = x.left_multiplier();
+            rhs.iter().map(move |y| left.apply(y))
+        })
+        .collect()
+}
+
+/// Returns every product x * y with x in `lhs` and y in `rhs` in parallel, in the same order as `multiply_all_pairs`.
+#[cfg(feature = "par")]
+pub fn multiply_all_pairs_par<T>(lhs: &[Octavian<T>], rhs: &[Octavian<T>]) -> Vec<Octavian<T>>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Send + Sync + Coefficient,
+{
+    lhs.par_iter()
+        .flat_map_iter(|x| {
+            let left = x.left_multiplier();
+            rhs.iter().map(move |y| left.apply(y))
+        })
+        .collect()
+}
+
+/// Returns the products a[i] * b[i], or `None` if the slices have different lengths.
+pub fn multiply_pairwise<T>(a: &[Octavian<T>], b: &[Octavian<T>]) -> Option<Vec<Octavian<T>>>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(a.iter().zip(b).map(|(&x, &y)| x * y).collect())
+}
+
+/// Returns the products a[i] * b[i] in parallel, or `None` if the slices have different lengths.
+#[cfg(feature = "par")]
+pub fn multiply_pairwise_par<T>(a: &[Octavian<T>], b: &[Octavian<T>]) -> Option<Vec<Octavian<T>>>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Send + Sync + Coefficient,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(a.par_iter().zip(b).map(|(&x, &y)| x * y).collect())
+}
+
+/// A panic raised while processing one element of a batch, caught so that the rest of the batch still completes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchPanic {
+    /// The position of the element in the input.
+    pub index: usize,
+    /// The panic message, when the payload was a string.
+    pub message: String,
+}
+
+/// Runs `f` on the element at `index`, turning a panic into a `BatchPanic`.
+fn catch_element<X, R>(
+    index: usize,
+    x: &X,
+    f: &(impl Fn(&X) -> R + ?Sized),
+) -> Result<R, BatchPanic> {
+    panic::catch_unwind(AssertUnwindSafe(|| f(x))).map_err(|payload| BatchPanic {
+        index,
+        message: payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default(),
+    })
+}
+
+/// Returns `f` applied to each element, in input order, with a panic in any one element returned as that element's error.
+/// The shared tables are computed before the first element, so `f` only reads them.
+pub fn map_each<X, R>(elements: &[X], f: impl Fn(&X) -> R) -> Vec<Result<R, BatchPanic>> {
+    tables::warm_tables();
+    elements
+        .iter()
+        .enumerate()
+        .map(|(i, x)| catch_element(i, x, &f))
+        .collect()
+}
+
+/// Returns `f` applied to each element in parallel, in the same order and with the same errors as `map_each`.
+#[cfg(feature = "par")]
+pub fn map_each_par<X, R>(
+    elements: &[X],
+    f: impl Fn(&X) -> R + Send + Sync,
+) -> Vec<Result<R, BatchPanic>>
+where
+    X: Sync,
+    R: Send,
+{
+    tables::warm_tables();
+    elements
+        .par_iter()
+        .enumerate()
+        .map(|(i, x)| catch_element(i, x, &f))
+        .collect()
+}
+
+/// Returns `factor::factor` of each element, in input order, with the errors of each element kept separately.
+/// The shared tables are computed before the first element.
+pub fn factor_many(elements: &[Octavian<i64>]) -> Vec<Result<Vec<Octavian<i64>>, AlcoError>> {
+    tables::warm_tables();
+    elements.iter().map(factor).collect()
+}
+
+/// Returns `factor::factor` of each element in parallel, in the same order as `factor_many`.
+#[cfg(feature = "par")]
+pub fn factor_many_par(elements: &[Octavian<i64>]) -> Vec<Result<Vec<Octavian<i64>>, AlcoError>> {
+    tables::warm_tables();
+    elements.par_iter().map(factor).collect()
+}
+
+/// Returns `octavian::gcrd` of each pair, in input order.
+/// The shared tables are computed before the first pair.
+#[cfg(feature = "tables")]
+pub fn gcrd_many(pairs: &[(Octavian<i64>, Octavian<i64>)]) -> Vec<Option<Octavian<i64>>> {
+    tables::warm_tables();
+    pairs.iter().map(|(a, b)| gcrd(a, b)).collect()
+}
+
+/// Returns `octavian::gcrd` of each pair in parallel, in the same order as `gcrd_many`.
+#[cfg(all(feature = "tables", feature = "par"))]
+pub fn gcrd_many_par(pairs: &[(Octavian<i64>, Octavian<i64>)]) -> Vec<Option<Octavian<i64>>> {
+    tables::warm_tables();
+    pairs.par_iter().map(|(a, b)| gcrd(a, b)).collect()
+}
@@ -0,0 +1,179 @@
+use crate::octavian::Octavian;
+use crate::tables::unit_multiplication_table;
+use crate::units::inverse_index;
+use std::collections::VecDeque;
+
+/// The left-translation Cayley graph of the unit loop with respect to a set of generators.
+/// The vertices are the unit indices, with an undirected edge between x and g·x for each generator g.
+/// Since the unit loop has the inverse property, the edge also joins g·x back to x by the inverse of g.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CayleyGraph {
+    generators: Vec<u16>,
+    adjacency: Vec<Vec<u16>>,
+}
+
+/// Returns the left-translation Cayley graph of the unit loop for the generators with the given unit indices.
+/// Translations by the identity would only add loops at each vertex and are omitted.
+pub fn unit_cayley_graph(generators: &[u16]) -> CayleyGraph {
+    let table = unit_multiplication_table();
+    let mut adjacency = vec![Vec::new(); 240];
+    for x in 0..240u16 {
+        for &g in generators {
+            let y = table[g as usize][x as usize];
+            if y != x {
+                adjacency[x as usize].push(y);
+                adjacency[y as usize].push(x);
+            }
+        }
+    }
+    for neighbors in &mut adjacency {
+        neighbors.sort_unstable();
+        neighbors.dedup();
+    }
+    CayleyGraph {
+        generators: generators.to_vec(),
+        adjacency,
+    }
+}
+
+impl CayleyGraph {
+    /// Returns the unit indices of the generators.
+    pub fn generators(&self) -> &[u16] {
+        &self.generators
+    }
+
+    /// Returns the neighbours of a vertex in increasing order.
+    pub fn neighbors(&self, x: u16) -> &[u16] {
+        &self.adjacency[x as usize]
+    }
+
+    /// Returns the number of edges of the graph.
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Returns the distance from `x` to every vertex by breadth-first search, or `None` for unreachable vertices.
+    pub fn distances_from(&self, x: u16) -> [Option<u32>; 240] {
+        let mut distances = [None; 240];
+        distances[x as usize] = Some(0);
+        let mut queue = VecDeque::from([x]);
+        while let Some(y) = queue.pop_front() {
+            let d = distances[y as usize].unwrap();
+            for &z in self.neighbors(y) {
+                if distances[z as usize].is_none() {
+                    distances[z as usize] = Some(d + 1);
+                    queue.push_back(z);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Tests whether every unit can be reached from every other.
+    pub fn is_connected(&self) -> bool {
+        let identity = Octavian::<i8>::one().unit_index().unwrap();
+        self.distances_from(identity).iter().all(Option::is_some)
+    }
+
+    /// Returns the largest distance between two vertices, or `None` if the graph is disconnected.
+    pub fn diameter(&self) -> Option<u32> {
+        let mut diameter = 0;
+        for x in 0..240 {
+            for d in self.distances_from(x) {
+                diameter = diameter.max(d?);
+            }
+        }
+        Some(diameter)
+    }
+
+    /// Returns the length of a shortest cycle, or `None` if the graph has no cycles.
+    pub fn girth(&self) -> Option<u32> {
+        let mut girth: Option<u32> = None;
+        for x in 0..240u16 {
+            let mut distances = [None; 240];
+            let mut parents = [None; 240];
+            distances[x as usize] = Some(0u32);
+            let mut queue = VecDeque::from([x]);
+            while let Some(y) = queue.pop_front() {
+                let d = distances[y as usize].unwrap();
+                for &z in self.neighbors(y) {
+                    match distances[z as usize] {
+                        None => {
+                            distances[z as usize] = Some(d + 1);
+                            parents[z as usize] = Some(y);
+                            queue.push_back(z);
+                        }
+                        Some(e) if parents[y as usize] != Some(z) => {
+                            let length = d + e + 1;
+                            girth = Some(girth.map_or(length, |g| g.min(length)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        girth
+    }
+}
+
+/// Returns the letters available for words in the given generators: the generators and their inverses, without the identity.
+fn letters(generators: &[u16]) -> Vec<u16> {
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let mut letters: Vec<u16> = generators
+        .iter()
+        .flat_map(|&g| [g, inverse_index(g)])
+        .filter(|&g| g != identity)
+        .collect();
+    letters.sort_unstable();
+    letters.dedup();
+    letters
+}
+
+/// Evaluates a word in the generators, returning `None` if some letter is neither a generator nor the inverse of one.
+///
+/// The loop is not associative, so the word `[w1, w2, ..., wk]` is evaluated right-nested as `w1(w2(...(wk)))`.
+/// This matches the left-translation Cayley graph: the word traces a path from the identity to its value.
+/// The empty word evaluates to the identity.
+pub fn evaluate_word(word: &[u16], generators: &[u16]) -> Option<u16> {
+    let table = unit_multiplication_table();
+    let letters = letters(generators);
+    let mut value = Octavian::<i8>::one().unit_index().unwrap();
+    for &w in word.iter().rev() {
+        letters.binary_search(&w).ok()?;
+        value = table[w as usize][value as usize];
+    }
+    Some(value)
+}
+
+/// Returns a shortest word in the generators and their inverses evaluating to the target unit, as unit indices.
+/// See `evaluate_word` for the evaluation order. Returns `None` if the target cannot be reached.
+pub fn unit_word(target: u16, generators: &[u16]) -> Option<Vec<u16>> {
+    let table = unit_multiplication_table();
+    let letters = letters(generators);
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    // For each reached unit, the letter whose left translation first reached it and the unit it was reached from.
+    let mut previous: [Option<(u16, u16)>; 240] = [None; 240];
+    let mut reached = [false; 240];
+    reached[identity as usize] = true;
+    let mut queue = VecDeque::from([identity]);
+    while let Some(x) = queue.pop_front() {
+        for &l in &letters {
+            let y = table[l as usize][x as usize];
+            if !reached[y as usize] {
+                reached[y as usize] = true;
+                previous[y as usize] = Some((l, x));
+                queue.push_back(y);
+            }
+        }
+    }
+    if !reached[target as usize] {
+        return None;
+    }
+    let mut word = Vec::new();
+    let mut x = target;
+    while let Some((l, y)) = previous[x as usize] {
+        word.push(l);
+        x = y;
+    }
+    Some(word)
+}
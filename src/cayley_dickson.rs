@@ -0,0 +1,320 @@
+//! Octonions built from complex numbers by two levels of Cayley–Dickson doubling.
+//! A frame of imaginary units i, j, l fixes the chain C ⊂ H ⊂ O, with C spanned by 1, i, H by C and j, and O by H and l.
+//! The complex quadruple (z0, z1, z2, z3) stands for the octonion (z0 + z1 j) + (z2 + z3 j) l,
+//! where a complex number a + bi is read as a + b i inside C.
+//!
+//! The same doubling, applied to the reals in the standard basis, gives the algebras of every power-of-two dimension
+//! through the sedenions. `AlgebraTable` abstracts an algebra with a basis as its structure constants, provided by
+//! `CayleyDickson` in the doubled basis and by `OctavianTable` in the E8 lattice basis,
+//! and `CdElement` is an element of a doubled algebra. `Octavian` keeps its own specialized multiplication.
+
+use crate::octavian::{Octavian, STRUCTURE_CONSTANTS};
+use crate::quaternion::HurwitzQuaternion;
+use core::ops::{Add, Mul, Neg, Sub};
+use num::complex::Complex;
+use num_traits::{FromPrimitive, Num};
+use std::sync::OnceLock;
+
+/// A chain of subalgebras C ⊂ H ⊂ O given by imaginary unit octavians i, j and l.
+/// Each of j and l is orthogonal to the subalgebra before it, so the eight products 1, i, j, ij, l, il, jl, (ij)l
+/// form an orthonormal basis of the octonions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubalgebraFrame {
+    basis: [Octavian<i64>; 8],
+}
+
+impl SubalgebraFrame {
+    /// Create a new `SubalgebraFrame` from the imaginary units i, j and l.
+    /// Returns `None` unless all three are imaginary units, i and j are orthogonal, and l is orthogonal to i, j and ij.
+    pub fn new(i: Octavian<i64>, j: Octavian<i64>, l: Octavian<i64>) -> Option<Self> {
+        let is_imaginary_unit = |x: &Octavian<i64>| x.trace() == 0 && x.norm() == 1;
+        let k = i * j;
+        let orthogonal =
+            i.inner_product(&j) == 0 && [i, j, k].iter().all(|x| x.inner_product(&l) == 0);
+        if ![i, j, l].iter().all(is_imaginary_unit) || !orthogonal {
+            return None;
+        }
+        Some(SubalgebraFrame {
+            basis: [Octavian::one(), i, j, k, l, i * l, j * l, k * l],
+        })
+    }
+
+    /// Returns the basis 1, i, j, ij, l, il, jl, (ij)l in E8 lattice coordinates.
+    pub fn basis(&self) -> [Octavian<i64>; 8] {
+        self.basis
+    }
+}
+
+/// Returns the octonion (z0 + z1 j) + (z2 + z3 j) l in E8 lattice coordinates.
+pub fn from_complex_quadruple(z: [Complex<f64>; 4], frame: &SubalgebraFrame) -> Octavian<f64> {
+    let coordinates = z.map(|w| [w.re, w.im]).concat();
+    let mut coefficients = [0.0; 8];
+    for (b, c) in frame.basis.iter().zip(coordinates) {
+        for (x, &y) in coefficients.iter_mut().zip(&b.coefficients) {
+            *x += c * y as f64;
+        }
+    }
+    Octavian::new(coefficients)
+}
+
+/// Returns the complex number a + bi as an octonion in the subalgebra C of the frame.
+pub fn from_complex(z: Complex<f64>, frame: &SubalgebraFrame) -> Octavian<f64> {
+    let zero = Complex::new(0.0, 0.0);
+    from_complex_quadruple([z, zero, zero, zero], frame)
+}
+
+/// Returns the complex quadruple (z0, z1, z2, z3) with x = (z0 + z1 j) + (z2 + z3 j) l, inverting `from_complex_quadruple`.
+pub fn to_complex_quadruple(x: &Octavian<f64>, frame: &SubalgebraFrame) -> [Complex<f64>; 4] {
+    // The basis is orthonormal with respect to the octonion norm, which is half the E8 inner product.
+    let c = frame
+        .basis
+        .map(|b| x.inner_product(&Octavian::new(b.coefficients.map(|c| c as f64))) / 2.0);
+    core::array::from_fn(|n| Complex::new(c[2 * n], c[2 * n + 1]))
+}
+
+/// An algebra of dimension N with a fixed basis, given by its structure constants.
+pub trait AlgebraTable<const N: usize> {
+    /// The nonzero structure constants (i, j, k, c): the product x y gains c x_k y_j in coordinate i.
+    fn structure_constants() -> &'static [(u8, u8, u8, i8)];
+
+    /// The coordinates of the identity.
+    fn identity() -> [i8; N];
+
+    /// Multiplies two elements given by their coordinates.
+    /// The sums are accumulated in T, so with narrow integer types they can overflow where `Octavian<i8>` does not.
+    fn multiply<T>(x: &[T; N], y: &[T; N]) -> [T; N]
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        let mut product = [T::zero(); N];
+        for &(i, j, k, c) in Self::structure_constants() {
+            let c = T::from_i8(c).expect("structure constants are small integers");
+            product[i as usize] = product[i as usize] + c * x[k as usize] * y[j as usize];
+        }
+        product
+    }
+}
+
+/// The octavian integers in the E8 lattice basis, multiplying with the same constants as `Octavian`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OctavianTable;
+
+impl AlgebraTable<8> for OctavianTable {
+    fn structure_constants() -> &'static [(u8, u8, u8, i8)] {
+        &STRUCTURE_CONSTANTS
+    }
+
+    fn identity() -> [i8; 8] {
+        Octavian::<i8>::one().coefficients
+    }
+}
+
+/// The algebras obtained from the reals by repeated Cayley–Dickson doubling, in the standard basis e_0 = 1, ..., e_{N-1}:
+/// the complex numbers, quaternions, octonions and sedenions for N = 2, 4, 8 and 16.
+/// The double of A is A × A with (a, b)(c, d) = (ac - d* b, da + b c*), and e_{i + N/2} is (0, e_i).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CayleyDickson;
+
+/// Returns (sign, k) with e_i e_j = sign e_k in the doubled algebra of dimension n.
+fn doubled_basis_product(i: usize, j: usize, n: usize) -> (i8, usize) {
+    if n == 1 {
+        return (1, 0);
+    }
+    let h = n / 2;
+    // The conjugate of a basis element is itself for e_0 and its negative otherwise.
+    let conjugate_sign = |i: usize| if i == 0 { 1 } else { -1 };
+    match (i < h, j < h) {
+        (true, true) => doubled_basis_product(i, j, h),
+        (true, false) => {
+            let (sign, k) = doubled_basis_product(j - h, i, h);
+            (sign, k + h)
+        }
+        (false, true) => {
+            let (sign, k) = doubled_basis_product(i - h, j, h);
+            (sign * conjugate_sign(j), k + h)
+        }
+        (false, false) => {
+            let (sign, k) = doubled_basis_product(j - h, i - h, h);
+            (-sign * conjugate_sign(j - h), k)
+        }
+    }
+}
+
+fn doubled_structure_constants(n: usize) -> Vec<(u8, u8, u8, i8)> {
+    let mut constants = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let (sign, k) = doubled_basis_product(i, j, n);
+            constants.push((k as u8, j as u8, i as u8, sign));
+        }
+    }
+    constants
+}
+
+macro_rules! cayley_dickson_table {
+    ($($n:literal),*) => {
+        $(
+            impl AlgebraTable<$n> for CayleyDickson {
+                fn structure_constants() -> &'static [(u8, u8, u8, i8)] {
+                    static CONSTANTS: OnceLock<Vec<(u8, u8, u8, i8)>> = OnceLock::new();
+                    CONSTANTS.get_or_init(|| doubled_structure_constants($n))
+                }
+
+                fn identity() -> [i8; $n] {
+                    core::array::from_fn(|i| i8::from(i == 0))
+                }
+            }
+        )*
+    };
+}
+
+cayley_dickson_table!(1, 2, 4, 8, 16, 32);
+
+/// An element of the Cayley–Dickson algebra of dimension N, by its coordinates in the standard basis.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct CdElement<T, const N: usize> {
+    pub coefficients: [T; N],
+}
+
+impl<T, const N: usize> CdElement<T, N>
+where
+    T: Num + Copy + FromPrimitive + Neg<Output = T>,
+    CayleyDickson: AlgebraTable<N>,
+{
+    /// Create a new `CdElement`.
+    pub const fn new(coefficients: [T; N]) -> Self {
+        CdElement { coefficients }
+    }
+
+    /// The additive identity.
+    pub fn zero() -> Self {
+        Self::new([T::zero(); N])
+    }
+
+    /// The multiplicative identity, the basis element e_0.
+    pub fn one() -> Self {
+        Self::basis_element(0)
+    }
+
+    /// Returns the basis element e_i.
+    pub fn basis_element(i: usize) -> Self {
+        Self::new(core::array::from_fn(|j| {
+            if i == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }))
+    }
+
+    /// Conjugation, which negates every coordinate but the first.
+    pub fn conjugate(&self) -> Self {
+        Self::new(core::array::from_fn(|i| {
+            if i == 0 {
+                self.coefficients[0]
+            } else {
+                -self.coefficients[i]
+            }
+        }))
+    }
+
+    /// Returns the norm, the sum of the squares of the coordinates.
+    /// It is multiplicative only up to dimension 8.
+    pub fn norm(&self) -> T {
+        self.coefficients
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + x * x)
+    }
+
+    /// Tests whether every coordinate is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|x| x.is_zero())
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Add for CdElement<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        CdElement {
+            coefficients: core::array::from_fn(|i| self.coefficients[i] + other.coefficients[i]),
+        }
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Sub for CdElement<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        CdElement {
+            coefficients: core::array::from_fn(|i| self.coefficients[i] - other.coefficients[i]),
+        }
+    }
+}
+
+impl<T: Neg<Output = T> + Copy, const N: usize> Neg for CdElement<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        CdElement {
+            coefficients: self.coefficients.map(|x| -x),
+        }
+    }
+}
+
+/// Multiplies with the structure constants of `CayleyDickson`.
+impl<T, const N: usize> Mul for CdElement<T, N>
+where
+    T: Num + Copy + FromPrimitive,
+    CayleyDickson: AlgebraTable<N>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        CdElement {
+            coefficients: CayleyDickson::multiply(&self.coefficients, &other.coefficients),
+        }
+    }
+}
+
+/// The quaternion with the same coordinates in the basis 1, i, j, k, which is the doubled basis e_0, ..., e_3.
+impl<T> From<HurwitzQuaternion<T>> for CdElement<T, 4> {
+    fn from(q: HurwitzQuaternion<T>) -> Self {
+        CdElement {
+            coefficients: q.coefficients,
+        }
+    }
+}
+
+impl<T> From<CdElement<T, 4>> for HurwitzQuaternion<T> {
+    fn from(x: CdElement<T, 4>) -> Self {
+        HurwitzQuaternion {
+            coefficients: x.coefficients,
+        }
+    }
+}
+
+/// Returns the pairs (x, y) with x y = 0 among the elements e_a ± e_b with 0 < a < b.
+/// There are none up to dimension 8, where the algebras are division algebras; the sedenions have many.
+pub fn basis_pair_zero_divisors<const N: usize>() -> Vec<(CdElement<i64, N>, CdElement<i64, N>)>
+where
+    CayleyDickson: AlgebraTable<N>,
+{
+    let mut candidates = Vec::new();
+    for a in 1..N {
+        for b in a + 1..N {
+            let (ea, eb) = (CdElement::basis_element(a), CdElement::basis_element(b));
+            candidates.push(ea + eb);
+            candidates.push(ea - eb);
+        }
+    }
+    let mut pairs = Vec::new();
+    for &x in &candidates {
+        for &y in &candidates {
+            if (x * y).is_zero() {
+                pairs.push((x, y));
+            }
+        }
+    }
+    pairs
+}
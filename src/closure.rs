@@ -0,0 +1,173 @@
+use crate::dedup::Dedup;
+use crate::octavian::{Coefficient, Octavian};
+use crate::progress::Progress;
+use core::hash::Hash;
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+#[cfg(feature = "par")]
+use rayon::prelude::*;
+
+/// The outcome of a closure computation: the elements found, in order of discovery starting with the generators,
+/// and whether the set stabilized or the computation stopped at the cap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosureResult<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T>,
+{
+    /// The elements found, at most `cap` of them.
+    pub elements: Vec<Octavian<T>>,
+    /// Whether the elements are closed under multiplication, rather than cut off at the cap.
+    pub stabilized: bool,
+}
+
+/// Collects the distinct generators and returns them with the set used for membership tests,
+/// which switches to a flat `DedupBuffer` once the closure grows large.
+fn distinct<T>(generators: &[Octavian<T>]) -> (Vec<Octavian<T>>, Dedup<T>)
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Coefficient,
+{
+    let mut seen = Dedup::new();
+    let elements = generators
+        .iter()
+        .copied()
+        .filter(|g| seen.insert(g))
+        .collect();
+    (elements, seen)
+}
+
+/// Returns the products x * y with x or y among `elements[start..]`, the new elements of the last round,
+/// in a fixed order: the new elements times all elements, then the older elements times the new ones.
+fn round_products<T>(
+    elements: &[Octavian<T>],
+    start: usize,
+) -> impl Iterator<Item = Octavian<T>> + '_
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    let new = &elements[start..];
+    let left = new.iter().flat_map(move |x| {
+        let multiplier = x.left_multiplier();
+        elements.iter().map(move |y| multiplier.apply(y))
+    });
+    let right = new.iter().flat_map(move |y| {
+        let multiplier = y.right_multiplier();
+        elements[..start].iter().map(move |x| multiplier.apply(x))
+    });
+    left.chain(right)
+}
+
+/// Returns the closure of the generators under multiplication, stopping once `cap` elements have been found.
+/// Each round only multiplies the elements found in the previous round with all elements found so far,
+/// on either side, since every other product was formed in an earlier round.
+pub fn multiplicative_closure<T>(generators: &[Octavian<T>], cap: usize) -> ClosureResult<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Coefficient,
+{
+    multiplicative_closure_with(generators, cap, &())
+}
+
+/// Returns the closure like `multiplicative_closure`, reporting the number of elements found after each round,
+/// with the cap as the total. If `progress` breaks before the set is known to be closed, the elements found so far
+/// are returned with `stabilized` false.
+pub fn multiplicative_closure_with<T>(
+    generators: &[Octavian<T>],
+    cap: usize,
+    progress: &impl Progress,
+) -> ClosureResult<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Coefficient,
+{
+    let (mut elements, mut seen) = distinct(generators);
+    if elements.len() > cap {
+        elements.truncate(cap);
+        return ClosureResult {
+            elements,
+            stabilized: false,
+        };
+    }
+    let mut start = 0;
+    while start < elements.len() {
+        let end = elements.len();
+        let mut found = Vec::new();
+        let mut truncated = false;
+        for product in round_products(&elements, start) {
+            if seen.insert(&product) {
+                if end + found.len() == cap {
+                    truncated = true;
+                    break;
+                }
+                found.push(product);
+            }
+        }
+        elements.extend(found);
+        if truncated {
+            return ClosureResult {
+                elements,
+                stabilized: false,
+            };
+        }
+        start = end;
+        let report = progress.report(elements.len() as u64, Some(cap as u64));
+        if report.is_break() && start < elements.len() {
+            return ClosureResult {
+                elements,
+                stabilized: false,
+            };
+        }
+    }
+    ClosureResult {
+        elements,
+        stabilized: true,
+    }
+}
+
+/// Returns the closure like `multiplicative_closure`, computing the products of each round in parallel.
+/// The products are merged in the same order as the serial version, so the results are identical.
+#[cfg(feature = "par")]
+pub fn multiplicative_closure_par<T>(generators: &[Octavian<T>], cap: usize) -> ClosureResult<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Send + Sync + Coefficient,
+{
+    let (mut elements, mut seen) = distinct(generators);
+    if elements.len() > cap {
+        elements.truncate(cap);
+        return ClosureResult {
+            elements,
+            stabilized: false,
+        };
+    }
+    let mut start = 0;
+    while start < elements.len() {
+        let end = elements.len();
+        let left: Vec<Octavian<T>> = elements[start..]
+            .par_iter()
+            .flat_map_iter(|x| {
+                let multiplier = x.left_multiplier();
+                elements.iter().map(move |y| multiplier.apply(y))
+            })
+            .collect();
+        let right: Vec<Octavian<T>> = elements[start..]
+            .par_iter()
+            .flat_map_iter(|y| {
+                let multiplier = y.right_multiplier();
+                elements[..start].iter().map(move |x| multiplier.apply(x))
+            })
+            .collect();
+        for product in left.into_iter().chain(right) {
+            if seen.insert(&product) {
+                if elements.len() == cap {
+                    return ClosureResult {
+                        elements,
+                        stabilized: false,
+                    };
+                }
+                elements.push(product);
+            }
+        }
+        start = end;
+    }
+    ClosureResult {
+        elements,
+        stabilized: true,
+    }
+}
@@ -0,0 +1,74 @@
+//! A short reversible text encoding of octavians with small coefficients, for URLs, filenames, and log lines.
+//!
+//! When every coefficient lies in -8..=7, the encoding is 8 lowercase hex digits, one per coefficient in order,
+//! each the 4-bit two's complement of the coefficient, so that `Octavian::one()` is `edcabcde`.
+//! Otherwise it is the escape `x` followed by the 8 coefficients in decimal separated by `.`, such as `x12.0.0.0.0.0.0.-9`.
+//! Only the characters `0-9`, `a-f`, `x`, `.` and `-` appear, so the encoding needs no quoting.
+
+use crate::error::AlcoError;
+use crate::octavian::Octavian;
+
+/// The range of coefficients packed into a hex digit.
+const NIBBLE_RANGE: core::ops::RangeInclusive<i8> = -8..=7;
+
+impl Octavian<i8> {
+    /// Encodes the octavian as 8 hex digits if its coefficients lie in -8..=7, and in the escaped verbose form otherwise.
+    /// Every unit octavian has the short form.
+    pub fn encode_compact(&self) -> String {
+        if self.coefficients.iter().all(|c| NIBBLE_RANGE.contains(c)) {
+            self.coefficients
+                .iter()
+                .map(|&c| char::from_digit(u32::from(c as u8 & 0xf), 16).unwrap())
+                .collect()
+        } else {
+            let fields: Vec<String> = self.coefficients.iter().map(|c| c.to_string()).collect();
+            format!("x{}", fields.join("."))
+        }
+    }
+
+    /// Decodes a string written by `encode_compact`.
+    /// Returns `AlcoError::WrongLength` if it does not hold 8 coefficients and `AlcoError::Parse` if one is malformed,
+    /// including uppercase hex digits, verbose coefficients outside the range of i8, and non-canonical decimals such as `+1` or `01`.
+    /// The verbose form of an octavian that has the short form, such as `x0.0.0.0.0.0.0.0`, is also rejected with `AlcoError::Parse`.
+    pub fn decode_compact(s: &str) -> Result<Self, AlcoError> {
+        if let Some(verbose) = s.strip_prefix('x') {
+            let fields: Vec<&str> = verbose.split('.').collect();
+            if fields.len() != 8 {
+                return Err(AlcoError::WrongLength {
+                    expected: 8,
+                    found: fields.len(),
+                });
+            }
+            let mut coefficients = [0i8; 8];
+            for (c, field) in coefficients.iter_mut().zip(fields) {
+                // Only the canonical decimal is accepted, so that each octavian has one encoding.
+                *c = field
+                    .parse()
+                    .ok()
+                    .filter(|c: &i8| c.to_string() == field)
+                    .ok_or_else(|| AlcoError::Parse(field.to_string()))?;
+            }
+            if coefficients.iter().all(|c| NIBBLE_RANGE.contains(c)) {
+                return Err(AlcoError::Parse(s.to_string()));
+            }
+            return Ok(Octavian::new(coefficients));
+        }
+        let digits: Vec<char> = s.chars().collect();
+        if digits.len() != 8 {
+            return Err(AlcoError::WrongLength {
+                expected: 8,
+                found: digits.len(),
+            });
+        }
+        let mut coefficients = [0i8; 8];
+        for (c, &digit) in coefficients.iter_mut().zip(&digits) {
+            let nibble = match digit {
+                '0'..='9' | 'a'..='f' => digit.to_digit(16).unwrap() as u8,
+                _ => return Err(AlcoError::Parse(digit.to_string())),
+            };
+            // Sign-extend the 4-bit two's complement.
+            *c = ((nibble << 4) as i8) >> 4;
+        }
+        Ok(Octavian::new(coefficients))
+    }
+}
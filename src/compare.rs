@@ -0,0 +1,83 @@
+//! Equality of octavians across coefficient types, so that a unit from the `i8` table can be compared
+//! with a computed `Octavian<i64>` without a cast.
+//! There is deliberately no `PartialEq` between different coefficient types: a second impl would make
+//! `Octavian::one() == x` ambiguous and break type inference wherever the coefficient type is left implicit.
+
+use crate::octavian::Octavian;
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+
+/// Equality of coefficients that may have different types.
+/// Every type equals itself, and the primitive signed integers compare by value through `i128`.
+pub trait CoefficientEq<U> {
+    fn coefficient_eq(&self, other: &U) -> bool;
+}
+
+impl<T: PartialEq> CoefficientEq<T> for T {
+    fn coefficient_eq(&self, other: &T) -> bool {
+        self == other
+    }
+}
+
+/// Implements `CoefficientEq` for each ordered pair of distinct types.
+macro_rules! cross_eq {
+    ($($a:ty => $($b:ty),+);+ $(;)?) => {$($(
+        impl CoefficientEq<$b> for $a {
+            fn coefficient_eq(&self, other: &$b) -> bool {
+                i128::from(*self) == i128::from(*other)
+            }
+        }
+    )+)+};
+}
+
+cross_eq! {
+    i8 => i16, i32, i64, i128;
+    i16 => i8, i32, i64, i128;
+    i32 => i8, i16, i64, i128;
+    i64 => i8, i16, i32, i128;
+    i128 => i8, i16, i32, i64;
+}
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T>,
+{
+    /// Tests whether the coefficients are equal to those of an octavian with another coefficient type.
+    pub fn eq_coeffs<U>(&self, other: &Octavian<U>) -> bool
+    where
+        T: CoefficientEq<U>,
+        U: FromPrimitive + Num + Copy + Neg<Output = U>,
+    {
+        self.first_difference(other).is_none()
+    }
+
+    /// Returns the index of the first coefficient that differs from that of `other`, or `None` if they are all equal.
+    pub fn first_difference<U>(&self, other: &Octavian<U>) -> Option<usize>
+    where
+        T: CoefficientEq<U>,
+        U: FromPrimitive + Num + Copy + Neg<Output = U>,
+    {
+        self.coefficients
+            .iter()
+            .zip(&other.coefficients)
+            .position(|(a, b)| !a.coefficient_eq(b))
+    }
+}
+
+/// Asserts that two octavians, possibly with different coefficient types, have equal coefficients.
+/// On failure the message names the first differing coefficient before printing both octavians.
+#[macro_export]
+macro_rules! assert_octavian_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some(i) = left.first_difference(right) {
+                    panic!(
+                        "octavians differ at coefficient {}: {:?} != {:?}\n  left: {:?}\n right: {:?}",
+                        i, left.coefficients[i], right.coefficients[i], left, right
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,159 @@
+use crate::octavian::{Coefficient, Octavian};
+use core::hash::{Hash, Hasher};
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+
+/// The number of elements above which `Dedup` moves from a `HashSet` to a `DedupBuffer`.
+pub const DEDUP_THRESHOLD: usize = 1 << 12;
+
+/// Marks an empty slot of the index table.
+const EMPTY: u32 = u32::MAX;
+
+/// A set of octavians that stores their coefficients in a single flat vector, in insertion order.
+/// Membership is tested through an open-addressing table of indices into the flat vector, kept at most half full,
+/// so each element costs its eight coefficients plus a few bytes of index, instead of a full `HashSet` bucket.
+#[derive(Debug, Clone, Default)]
+pub struct DedupBuffer<T> {
+    data: Vec<T>,
+    slots: Vec<u32>,
+}
+
+impl<T> DedupBuffer<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Coefficient,
+{
+    /// Create a new, empty `DedupBuffer`.
+    pub fn new() -> Self {
+        DedupBuffer {
+            data: Vec::new(),
+            slots: Vec::new(),
+        }
+    }
+
+    /// Create a new, empty `DedupBuffer` with room for `capacity` octavians.
+    pub fn with_capacity(capacity: usize) -> Self {
+        DedupBuffer {
+            data: Vec::with_capacity(8 * capacity),
+            slots: vec![EMPTY; (2 * capacity).next_power_of_two()],
+        }
+    }
+
+    /// Returns the number of octavians in the buffer.
+    pub fn len(&self) -> usize {
+        self.data.len() / 8
+    }
+
+    /// Tests whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the coefficients of the i-th octavian inserted.
+    fn coefficients(&self, i: usize) -> &[T] {
+        &self.data[8 * i..8 * i + 8]
+    }
+
+    /// Returns the i-th octavian inserted, or `None` if the index is out of range.
+    pub fn get(&self, i: usize) -> Option<Octavian<T>> {
+        let start = i.checked_mul(8)?;
+        let chunk = self.data.get(start..start.checked_add(8)?)?;
+        Some(Octavian::new(chunk.try_into().ok()?))
+    }
+
+    /// Returns the slot holding the index of `x`, or the empty slot where it belongs.
+    fn find_slot(&self, x: &[T; 8]) -> usize {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        let mask = self.slots.len() - 1;
+        let mut slot = hasher.finish() as usize & mask;
+        loop {
+            let index = self.slots[slot];
+            if index == EMPTY || self.coefficients(index as usize) == x {
+                return slot;
+            }
+            slot = (slot + 1) & mask;
+        }
+    }
+
+    /// Doubles the index table, or creates it, and reinserts every index.
+    fn grow(&mut self) {
+        let size = (2 * self.slots.len()).max(16);
+        self.slots = vec![EMPTY; size];
+        for i in 0..self.len() {
+            let x: [T; 8] = self.coefficients(i).try_into().unwrap();
+            let slot = self.find_slot(&x);
+            self.slots[slot] = i as u32;
+        }
+    }
+
+    /// Tests whether the buffer contains the octavian.
+    pub fn contains(&self, x: &Octavian<T>) -> bool {
+        !self.slots.is_empty() && self.slots[self.find_slot(&x.coefficients)] != EMPTY
+    }
+
+    /// Adds the octavian to the buffer, returning whether it was new.
+    pub fn insert(&mut self, x: &Octavian<T>) -> bool {
+        if 2 * (self.len() + 1) > self.slots.len() {
+            self.grow();
+        }
+        let slot = self.find_slot(&x.coefficients);
+        if self.slots[slot] != EMPTY {
+            return false;
+        }
+        self.slots[slot] = u32::try_from(self.len()).expect("too many octavians for a DedupBuffer");
+        self.data.extend_from_slice(&x.coefficients);
+        true
+    }
+
+    /// Returns an iterator over the octavians in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = Octavian<T>> + '_ {
+        self.data
+            .chunks_exact(8)
+            .map(|chunk| Octavian::new(chunk.try_into().unwrap()))
+    }
+
+    /// Returns the number of bytes the buffer has allocated, counting its capacity rather than its length.
+    pub fn allocated_bytes(&self) -> usize {
+        self.data.capacity() * size_of::<T>() + self.slots.capacity() * size_of::<u32>()
+    }
+}
+
+/// A set of octavians that starts as a `HashSet` and moves into a `DedupBuffer` once it exceeds `DEDUP_THRESHOLD`,
+/// so that small sets keep the fast path while large ones save memory.
+#[derive(Debug, Clone)]
+pub(crate) enum Dedup<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T>,
+{
+    Small(HashSet<Octavian<T>>),
+    Large(DedupBuffer<T>),
+}
+
+impl<T> Dedup<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Hash + Eq + Coefficient,
+{
+    pub(crate) fn new() -> Self {
+        Dedup::Small(HashSet::new())
+    }
+
+    /// Adds the octavian, returning whether it was new.
+    pub(crate) fn insert(&mut self, x: &Octavian<T>) -> bool {
+        match self {
+            Dedup::Small(set) => {
+                let inserted = set.insert(*x);
+                if set.len() > DEDUP_THRESHOLD {
+                    let mut buffer = DedupBuffer::with_capacity(2 * set.len());
+                    for y in set.iter() {
+                        buffer.insert(y);
+                    }
+                    *self = Dedup::Large(buffer);
+                }
+                inserted
+            }
+            Dedup::Large(buffer) => buffer.insert(x),
+        }
+    }
+}
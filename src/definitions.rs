@@ -0,0 +1,38 @@
+// The defining data of the octavian integers, from which `build.rs` generates the unit and adjoint tables.
+// This file is included both by the crate and by the build script, so it holds plain constants only.
+
+/// The Gram matrix of the simple roots, read off the E8 Dynkin diagram with edges 0-2, 1-3, 2-3, 3-4, 4-5, 5-6 and 6-7.
+pub const GRAM_MATRIX: [[i8; 8]; 8] = [
+    [2, 0, -1, 0, 0, 0, 0, 0],
+    [0, 2, 0, -1, 0, 0, 0, 0],
+    [-1, 0, 2, -1, 0, 0, 0, 0],
+    [0, -1, -1, 2, -1, 0, 0, 0],
+    [0, 0, 0, -1, 2, -1, 0, 0],
+    [0, 0, 0, 0, -1, 2, -1, 0],
+    [0, 0, 0, 0, 0, -1, 2, -1],
+    [0, 0, 0, 0, 0, 0, -1, 2],
+];
+
+/// The E8 lattice coordinates of the standard octonion basis 1, e1, ..., e7, the reference basis for multiplication.
+pub const OCTONION_BASIS: [[i8; 8]; 8] = [
+    [-2, -3, -4, -6, -5, -4, -3, -2],
+    [-2, -2, -3, -4, -3, -2, -1, 0],
+    [0, -1, -1, -2, -2, -2, -1, 0],
+    [0, 0, 0, 0, 0, 0, 1, 0],
+    [0, -1, -1, -2, -1, 0, 0, 0],
+    [0, 0, 0, 0, -1, 0, 0, 0],
+    [0, 0, 1, 0, 0, 0, 0, 0],
+    [0, 1, 0, 0, 0, 0, 0, 0],
+];
+
+/// The lines (a, b, c) of the Fano plane, each meaning e_a e_b = e_c together with its cyclic shifts.
+/// The build script derives the adjoint tables from these, and `fano` exposes them.
+pub const FANO_TRIPLES: [[usize; 3]; 7] = [
+    [1, 2, 3],
+    [1, 4, 5],
+    [1, 7, 6],
+    [2, 4, 6],
+    [2, 5, 7],
+    [3, 4, 7],
+    [3, 6, 5],
+];
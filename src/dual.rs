@@ -0,0 +1,150 @@
+//! Dual numbers a + b ε with ε² = 0, for forward-mode automatic differentiation of functions of octavians.
+//!
+//! `Dual<T>` implements the numeric traits required of coefficients, so `Octavian<Dual<f64>>` supports `scale`,
+//! addition, multiplication, `norm` and `inner_product` unchanged. Products use the dense adjoint matrix, as for
+//! other non-integral types. Seeding a parameter t as `Dual::variable(t)` carries the derivative with respect to t
+//! in the `eps` part of every coefficient computed from it.
+
+use crate::octavian::{sealed, Coefficient, Octavian};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use num_traits::{FromPrimitive, Num, One, Zero};
+
+/// A dual number `re + eps ε`, where ε² = 0.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual<T> {
+    /// The value.
+    pub re: T,
+    /// The derivative carried along with the value.
+    pub eps: T,
+}
+
+impl<T: Num + Copy> Dual<T> {
+    /// Create a new `Dual`.
+    pub const fn new(re: T, eps: T) -> Self {
+        Dual { re, eps }
+    }
+
+    /// Returns a constant, whose derivative is 0.
+    pub fn constant(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+
+    /// Returns the variable of differentiation at `re`, whose derivative is 1.
+    pub fn variable(re: T) -> Self {
+        Self::new(re, T::one())
+    }
+}
+
+impl<T: Num + Copy> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Num + Copy> Sub for Dual<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Num + Copy> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<T: Num + Copy> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Self::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+/// The remainder a - q b for the truncated quotient q of the values, which is locally constant,
+/// so the derivative is that of a - q b away from the jumps.
+impl<T: Num + Copy> Rem for Dual<T> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        let re = self.re % rhs.re;
+        let quotient = (self.re - re) / rhs.re;
+        Self::new(re, self.eps - quotient * rhs.eps)
+    }
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Dual<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl<T: Num + Copy> Zero for Dual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<T: Num + Copy> One for Dual<T> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+/// Parses the value, as a constant.
+impl<T: Num + Copy> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+/// Converts to constants. The conversions from floats go through `T`, so that `Octavian` treats `Dual<f64>`
+/// as a non-integral coefficient type.
+impl<T: Num + Copy + FromPrimitive> FromPrimitive for Dual<T> {
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).map(Self::constant)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).map(Self::constant)
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        T::from_f32(n).map(Self::constant)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).map(Self::constant)
+    }
+}
+
+impl<T> sealed::Sealed for Dual<T> {}
+
+impl<T: Num + Copy + FromPrimitive + Neg<Output = T>> Coefficient for Dual<T> {}
+
+/// Returns the octavian with constant coefficients equal to those of `x`.
+pub fn constant_octavian(x: &Octavian<f64>) -> Octavian<Dual<f64>> {
+    Octavian::new(x.coefficients.map(Dual::constant))
+}
+
+/// Returns the derivative of `f` at `t`, by evaluating it on the variable `Dual::variable(t)`.
+pub fn derivative(f: impl Fn(Dual<f64>) -> Dual<f64>, t: f64) -> f64 {
+    f(Dual::variable(t)).eps
+}
+
+/// Returns d/dt N(x + t y) at `t`, computed by differentiating through `scale`, addition and `norm`.
+/// Since the norm is half the inner product, the derivative is ⟨x, y⟩ + 2 t N(y).
+pub fn norm_derivative(x: &Octavian<f64>, y: &Octavian<f64>, t: f64) -> f64 {
+    let (x, y) = (constant_octavian(x), constant_octavian(y));
+    derivative(|t| (x + y.scale(t)).norm(), t)
+}
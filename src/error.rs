@@ -0,0 +1,113 @@
+//! The error type shared by the fallible functions of the crate.
+//!
+//! Functions that can only fail in one way return `Option`, as the `checked_*` methods do;
+//! those whose callers need to know why they failed return `Result<_, AlcoError>`.
+//! The operator traits keep the conventions of the standard library and panic instead:
+//! integer overflow in `Add`, `Sub`, `Neg` and `Mul` panics in debug builds,
+//! and `Octavian<i8>` products panic whenever a coefficient does not fit in i8.
+//! The functions of `units` taking unit indices, such as `units::unit_order`, return `AlcoError::IndexOutOfRange`
+//! for indices of 240 or more.
+//! The reading errors of `io` convert into `AlcoError::Csv` and `AlcoError::Binary`, which report them as their source.
+
+use crate::io::{BinaryError, CsvError};
+use core::fmt;
+use std::sync::Arc;
+
+/// The ways in which a fallible function of the crate can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AlcoError {
+    /// A sequence of coefficients did not have the expected length.
+    WrongLength {
+        /// The number of coefficients required.
+        expected: usize,
+        /// The number of coefficients given.
+        found: usize,
+    },
+    /// A division by zero.
+    DivisionByZero,
+    /// A division that does not come out exactly.
+    NotDivisible,
+    /// An element that had to be one of the 240 unit octavians is not.
+    NotAUnit,
+    /// A rational octavian that had to be an octavian integer is not.
+    NotIntegral,
+    /// Text that does not parse, with the part that failed.
+    Parse(String),
+    /// A result whose coefficients do not fit in the coefficient type.
+    Overflow,
+    /// An index past the end of a table, such as a unit index of 240 or more.
+    IndexOutOfRange {
+        /// The index given.
+        index: usize,
+        /// The number of entries in the table.
+        len: usize,
+    },
+    /// An iterator yielded more coefficients than expected, and was not read past the first surplus one.
+    TooManyCoefficients {
+        /// The number of coefficients required.
+        expected: usize,
+    },
+    /// Vectors that had to be a basis of a lattice of full rank, 8 linearly independent vectors, are not.
+    NotABasis,
+    /// Units that had to form a particular subgroup of the unit loop, such as a copy of 2T, do not.
+    NotASubgroup,
+    /// Zero was given where a nonzero octavian is required, as by `factor::factor`.
+    Zero,
+    /// A norm with a prime factor too large for `factor::factor` to split, `factor::MAX_PRIME` or more.
+    PrimeTooLarge,
+    /// CSV input that `io::read_csv` could not read, shared so that the error stays cloneable.
+    Csv(Arc<CsvError>),
+    /// Binary input that `io::read_binary` could not read, shared so that the error stays cloneable.
+    Binary(Arc<BinaryError>),
+}
+
+impl fmt::Display for AlcoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlcoError::WrongLength { expected, found } => {
+                write!(f, "expected {} coordinates, found {}", expected, found)
+            }
+            AlcoError::DivisionByZero => write!(f, "division by zero"),
+            AlcoError::NotDivisible => write!(f, "the division is not exact"),
+            AlcoError::NotAUnit => write!(f, "the element is not a unit octavian"),
+            AlcoError::NotIntegral => write!(f, "the element is not an octavian integer"),
+            AlcoError::Parse(s) => write!(f, "{:?} is not a valid coefficient", s),
+            AlcoError::Overflow => write!(f, "a coefficient does not fit in the coefficient type"),
+            AlcoError::IndexOutOfRange { index, len } => {
+                write!(f, "index {} is out of range for {} entries", index, len)
+            }
+            AlcoError::TooManyCoefficients { expected } => {
+                write!(f, "expected {} coordinates, found more", expected)
+            }
+            AlcoError::NotABasis => write!(f, "the vectors are not 8 linearly independent vectors"),
+            AlcoError::NotASubgroup => write!(f, "the units do not form the required subgroup"),
+            AlcoError::Zero => write!(f, "the octavian is zero"),
+            AlcoError::PrimeTooLarge => write!(f, "the norm has a prime factor too large to split"),
+            AlcoError::Csv(e) => write!(f, "invalid CSV: {}", e),
+            AlcoError::Binary(e) => write!(f, "invalid binary input: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AlcoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AlcoError::Csv(e) => Some(&**e),
+            AlcoError::Binary(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CsvError> for AlcoError {
+    fn from(e: CsvError) -> Self {
+        AlcoError::Csv(Arc::new(e))
+    }
+}
+
+impl From<BinaryError> for AlcoError {
+    fn from(e: BinaryError) -> Self {
+        AlcoError::Binary(Arc::new(e))
+    }
+}
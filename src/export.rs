@@ -0,0 +1,10 @@
+//! Exports of octavians and the constant tables as source code for computer algebra systems,
+//! so that results can be checked independently, and of unit graphs as Graphviz DOT.
+
+#[cfg(feature = "tables")]
+pub mod dot;
+pub mod gap;
+pub mod magma;
+
+#[cfg(feature = "tables")]
+pub use dot::{dot_cayley_graph, dot_root_adjacency};
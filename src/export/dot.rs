@@ -0,0 +1,109 @@
+//! Graphviz DOT. Graphs are undirected, each node is declared once with a label, and each edge appears once.
+
+use crate::cayley::CayleyGraph;
+use crate::root_graph::root_class_graph;
+
+/// Attributes applied to the whole graph, every node, and every edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotStyle {
+    /// The name of the graph.
+    pub name: String,
+    pub graph_attributes: Vec<(String, String)>,
+    pub node_attributes: Vec<(String, String)>,
+    pub edge_attributes: Vec<(String, String)>,
+}
+
+impl Default for DotStyle {
+    fn default() -> Self {
+        DotStyle {
+            name: "G".to_string(),
+            graph_attributes: Vec::new(),
+            node_attributes: Vec::new(),
+            edge_attributes: Vec::new(),
+        }
+    }
+}
+
+/// Quotes a DOT identifier or attribute value.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Formats attributes as a DOT attribute list.
+fn attribute_list(attributes: &[(String, String)]) -> String {
+    let entries: Vec<String> = attributes
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, quote(v)))
+        .collect();
+    format!("[{}]", entries.join(", "))
+}
+
+/// Writes a graph whose nodes are `prefix` followed by their index, given the labels and the edges with i < j.
+fn write_graph(
+    style: &DotStyle,
+    prefix: &str,
+    labels: &[String],
+    edges: impl Iterator<Item = (usize, usize)>,
+) -> String {
+    let mut lines = vec![format!("graph {} {{", quote(&style.name))];
+    for (kind, attributes) in [
+        ("graph", &style.graph_attributes),
+        ("node", &style.node_attributes),
+        ("edge", &style.edge_attributes),
+    ] {
+        if !attributes.is_empty() {
+            lines.push(format!("  {} {};", kind, attribute_list(attributes)));
+        }
+    }
+    for (i, label) in labels.iter().enumerate() {
+        lines.push(format!("  {}{} [label={}];", prefix, i, quote(label)));
+    }
+    for (i, j) in edges {
+        lines.push(format!("  {}{} -- {}{};", prefix, i, prefix, j));
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Returns the Cayley graph as DOT, with nodes `u0`, ..., `u239` named by unit index.
+/// Each node is labelled by `labels[i]` if given, and by its unit index otherwise.
+///
+/// # Panics
+/// Panics if `labels` does not have 240 entries.
+pub fn dot_cayley_graph(
+    graph: &CayleyGraph,
+    labels: Option<&[String]>,
+    style: &DotStyle,
+) -> String {
+    let labels: Vec<String> = match labels {
+        Some(labels) => {
+            assert_eq!(labels.len(), 240, "there must be one label for each unit");
+            labels.to_vec()
+        }
+        None => (0..240).map(|i| i.to_string()).collect(),
+    };
+    let edges = (0..240u16).flat_map(|x| {
+        graph
+            .neighbors(x)
+            .iter()
+            .filter(move |&&y| x < y)
+            .map(move |&y| (x as usize, y as usize))
+    });
+    write_graph(style, "u", &labels, edges)
+}
+
+/// Returns the graph on the 120 classes {u, -u} of units as DOT, with nodes `c0`, ..., `c119` labelled by class index,
+/// and an edge between two classes whenever the inner product of their units is ±`inner_product`.
+/// The inner product of classes is only defined up to sign, so `inner_product` and its negative give the same graph.
+pub fn dot_root_adjacency(inner_product: i64, style: &DotStyle) -> String {
+    let graph = root_class_graph(inner_product);
+    let labels: Vec<String> = (0..graph.vertex_count()).map(|c| c.to_string()).collect();
+    let edges = (0..graph.vertex_count()).flat_map(|i| {
+        graph
+            .neighbors(i)
+            .iter()
+            .filter(move |&&j| j > i)
+            .map(move |&j| (i, j))
+    });
+    write_graph(style, "c", &labels, edges)
+}
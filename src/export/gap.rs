@@ -0,0 +1,46 @@
+//! GAP list syntax. A vector is a list of integers and a matrix is a list of its rows, laid out one row per line
+//! as GAP prints them.
+
+use crate::octavian::Octavian;
+use crate::tables;
+
+/// Formats a vector of integers as a GAP list.
+fn gap_vector(v: &[i64; 8]) -> String {
+    let entries: Vec<String> = v.iter().map(|x| x.to_string()).collect();
+    format!("[ {} ]", entries.join(", "))
+}
+
+/// Formats a list of already formatted GAP values, one per line, indented to line up under the opening bracket.
+fn gap_list(items: impl IntoIterator<Item = String>, indent: usize) -> String {
+    let items: Vec<String> = items.into_iter().collect();
+    if items.is_empty() {
+        return "[  ]".to_string();
+    }
+    let separator = format!(",\n{}", " ".repeat(indent + 2));
+    format!("[ {} ]", items.join(&separator))
+}
+
+/// Returns the coefficients of the octavians as a GAP list of vectors.
+pub fn gap_octavian_list(octavians: &[Octavian<i64>]) -> String {
+    gap_list(octavians.iter().map(|x| gap_vector(&x.coefficients)), 0)
+}
+
+/// Returns the matrix as a GAP list of rows.
+pub fn gap_matrix(matrix: &[[i64; 8]; 8]) -> String {
+    gap_matrix_indented(matrix, 0)
+}
+
+fn gap_matrix_indented(matrix: &[[i64; 8]; 8], indent: usize) -> String {
+    gap_list(matrix.iter().map(gap_vector), indent)
+}
+
+/// Returns the left adjoint matrices of the basis vectors as a GAP list of 8 matrices,
+/// in the layout of `Octavian::OCTAVIAN_ADJOINT_MATRICES`.
+pub fn gap_adjoint_matrices() -> String {
+    gap_list(
+        tables::adjoint_matrices_i64()
+            .iter()
+            .map(|m| gap_matrix_indented(m, 2)),
+        0,
+    )
+}
@@ -0,0 +1,61 @@
+//! Magma statements. Matrices are written with `Matrix(Integers(), rows, columns, [...])`, listing the entries
+//! row by row, one row per line, and every function returns a complete statement assigning to the given name.
+
+use crate::octavian::Octavian;
+use crate::tables;
+
+/// Formats the rows as a Magma integer matrix with 8 columns.
+fn magma_matrix<'a>(rows: impl ExactSizeIterator<Item = &'a [i64; 8]>) -> String {
+    let n = rows.len();
+    let lines: Vec<String> = rows
+        .map(|row| {
+            let entries: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+            format!("  {}", entries.join(", "))
+        })
+        .collect();
+    format!("Matrix(Integers(), {}, 8, [\n{} ])", n, lines.join(",\n"))
+}
+
+/// Returns a statement assigning the E8 lattice, given by the Gram matrix of the coefficient basis, to `name`.
+pub fn magma_lattice(name: &str) -> String {
+    format!(
+        "{} := LatticeWithGram({});",
+        name,
+        magma_matrix(tables::gram_matrix_i64().iter())
+    )
+}
+
+/// Returns a statement assigning the coefficients of the octavians to `name`, as the rows of an integer matrix.
+/// The rows are coordinates in the basis of the lattice from `magma_lattice`.
+pub fn magma_vectors(name: &str, octavians: &[Octavian<i64>]) -> String {
+    format!(
+        "{} := {};",
+        name,
+        magma_matrix(octavians.iter().map(|x| &x.coefficients))
+    )
+}
+
+/// Returns a statement assigning the octavian integers to `name` as an algebra over the integers,
+/// given by the sparse structure constants <i, j, k, c> with b_i * b_j having coefficient c on b_k.
+pub fn magma_algebra(name: &str) -> String {
+    let mut constants = Vec::new();
+    for (i, matrix) in tables::adjoint_matrices_i64().iter().enumerate() {
+        for (k, row) in matrix.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                if c != 0 {
+                    constants.push((i + 1, j + 1, k + 1, c));
+                }
+            }
+        }
+    }
+    constants.sort_unstable();
+    let tuples: Vec<String> = constants
+        .iter()
+        .map(|(i, j, k, c)| format!("  <{}, {}, {}, {}>", i, j, k, c))
+        .collect();
+    format!(
+        "{} := Algebra<Integers(), 8 | [\n{} ]>;",
+        name,
+        tuples.join(",\n")
+    )
+}
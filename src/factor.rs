@@ -0,0 +1,249 @@
+//! Factorization of octavians into octavians of prime norm.
+//!
+//! A nonzero octavian a whose norm has the prime factors p1 ≤ p2 ≤ ... ≤ pk is a right-nested product
+//! π1 (π2 (... (π(k-1) πk))) with N(πi) = pi, found one left divisor at a time. The octavians are alternative,
+//! so x (conj(x) c) = N(x) c, and x of norm p left-divides c exactly when conj(x) c ≡ 0 mod p.
+//! When p divides N(c) but not every coefficient of c, those x form the lattice c O + p O, a copy of √p E8,
+//! and its vectors of norm p, its shortest vectors, are the left divisors of c of norm p.
+//! When p divides every coefficient of c, every octavian of norm p is a left divisor of c.
+
+use crate::error::AlcoError;
+use crate::octavian::Octavian;
+
+/// The bound on the prime factors of norms that `factor` splits. Norms are factored by trial division,
+/// and the shortest vectors are searched for with floating-point Gram–Schmidt data, both of which this keeps cheap and exact.
+pub const MAX_PRIME: i64 = 1 << 24;
+
+/// Returns the factors [π1, ..., πk] of `a` with a = π1 (π2 (... (π(k-1) πk))) and N(πi) = pi,
+/// where p1 ≤ ... ≤ pk are the prime factors of N(a) with multiplicity. A unit is its own single factor.
+/// Returns `AlcoError::Zero` for zero, which has no factorization, `AlcoError::Overflow` if the norm does not fit
+/// in i128, and `AlcoError::PrimeTooLarge` if the norm has a prime factor of `MAX_PRIME` or more.
+pub fn factor(a: &Octavian<i64>) -> Result<Vec<Octavian<i64>>, AlcoError> {
+    if *a == Octavian::zero() {
+        return Err(AlcoError::Zero);
+    }
+    let primes = prime_factors(a.norm_i128()?)?;
+    let mut rest = Octavian::new(a.coefficients.map(i128::from));
+    let mut factors = Vec::with_capacity(primes.len());
+    for p in primes {
+        let divisor = prime_left_divisor(&rest, p).ok_or(AlcoError::NotDivisible)?;
+        rest = rest
+            .checked_div_left(&divisor)
+            .ok_or(AlcoError::NotDivisible)?;
+        factors.push(divisor);
+    }
+    // What remains has norm 1, and is absorbed into the last factor without changing its norm.
+    match factors.last_mut() {
+        Some(last) => *last *= rest,
+        None => factors.push(rest),
+    }
+    factors.iter().map(|f| f.try_narrow()).collect()
+}
+
+/// Returns the prime factors of n > 0 in nondecreasing order, by trial division up to `MAX_PRIME`.
+/// Returns `AlcoError::PrimeTooLarge` if a prime factor is `MAX_PRIME` or more.
+fn prime_factors(mut n: i128) -> Result<Vec<i128>, AlcoError> {
+    let mut primes = Vec::new();
+    let mut d = 2;
+    while d * d <= n && d < i128::from(MAX_PRIME) {
+        while n % d == 0 {
+            primes.push(d);
+            n /= d;
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    if n >= i128::from(MAX_PRIME) {
+        return Err(AlcoError::PrimeTooLarge);
+    }
+    if n > 1 {
+        primes.push(n);
+    }
+    Ok(primes)
+}
+
+/// Returns a left divisor of `c` of norm `p`, for a prime p below `MAX_PRIME` dividing N(c).
+fn prime_left_divisor(c: &Octavian<i128>, p: i128) -> Option<Octavian<i128>> {
+    let c = if c.coefficients.iter().all(|x| x % p == 0) {
+        primitive_multiple_of_norm(p)
+    } else {
+        *c
+    };
+    let mut basis = divisor_lattice_basis(&c, p);
+    lll_reduce(&mut basis);
+    shortest_vector(&basis, p)
+}
+
+/// Returns an octavian w ≢ 0 mod p with p | N(w), as w = a + b e1 + e2 with a² + b² + 1 ≡ 0 mod p.
+fn primitive_multiple_of_norm(p: i128) -> Octavian<i128> {
+    let (a, b) = if p == 2 {
+        (1, 0)
+    } else if p % 4 == 1 {
+        // -1 is a square: with z a nonresidue, z^((p-1)/4) squares to -1.
+        let z = (2..p)
+            .find(|&z| mod_pow(z, (p - 1) / 2, p) == p - 1)
+            .unwrap_or(2);
+        (mod_pow(z, (p - 1) / 4, p), 0)
+    } else {
+        // Half of the values -1 - a² are squares, and for p ≡ 3 mod 4 a square r has the root r^((p+1)/4).
+        (0..p)
+            .map(|a| (a, (-1 - a * a).rem_euclid(p)))
+            .find(|&(_, r)| mod_pow(r, (p - 1) / 2, p) <= 1)
+            .map(|(a, r)| (a, mod_pow(r, (p + 1) / 4, p)))
+            .unwrap_or((0, 0))
+    };
+    let row = |i: usize| Octavian::new(Octavian::<i8>::OCTONION_BASIS[i].map(i128::from));
+    row(0).scale(a) + row(1).scale(b) + row(2)
+}
+
+/// Returns x^e mod m for 0 ≤ x < m < 2^24, by repeated squaring.
+fn mod_pow(mut x: i128, mut e: i128, m: i128) -> i128 {
+    let mut result = 1 % m;
+    while e > 0 {
+        if e % 2 == 1 {
+            result = result * x % m;
+        }
+        x = x * x % m;
+        e /= 2;
+    }
+    result
+}
+
+/// Returns a basis of the lattice c O + p O: the rows of the reduced echelon form over F_p of the products c b_i
+/// with the basis vectors b_i, together with p e_j for the columns j without a pivot.
+fn divisor_lattice_basis(c: &Octavian<i128>, p: i128) -> [[i128; 8]; 8] {
+    let mut rows =
+        Octavian::<i128>::basis_vectors().map(|b| (*c * b).coefficients.map(|x| x.rem_euclid(p)));
+    let mut free = Vec::new();
+    let mut rank = 0;
+    for col in 0..8 {
+        let Some(r) = (rank..8).find(|&r| rows[r][col] != 0) else {
+            free.push(col);
+            continue;
+        };
+        rows.swap(rank, r);
+        let inverse = mod_pow(rows[rank][col], p - 2, p);
+        rows[rank] = rows[rank].map(|x| x * inverse % p);
+        let pivot = rows[rank];
+        for (i, row) in rows.iter_mut().enumerate() {
+            let f = row[col];
+            if i != rank && f != 0 {
+                for (x, y) in row.iter_mut().zip(&pivot) {
+                    *x = (*x - f * y).rem_euclid(p);
+                }
+            }
+        }
+        rank += 1;
+    }
+    for (row, col) in rows[rank..].iter_mut().zip(free) {
+        *row = [0; 8];
+        row[col] = p;
+    }
+    rows
+}
+
+/// Returns the inner product of two vectors of lattice coordinates.
+fn inner(x: &[i128; 8], y: &[i128; 8]) -> i128 {
+    Octavian::new(*x).inner_product(&Octavian::new(*y))
+}
+
+/// Returns the Gram–Schmidt coefficients mu[i][j] and the squared lengths of the Gram–Schmidt vectors of the basis.
+fn gram_schmidt(basis: &[[i128; 8]; 8]) -> ([[f64; 8]; 8], [f64; 8]) {
+    let mut mu = [[0.0; 8]; 8];
+    let mut lengths = [0.0; 8];
+    for i in 0..8 {
+        for j in 0..=i {
+            let mut r = inner(&basis[i], &basis[j]) as f64;
+            for l in 0..j {
+                r -= mu[j][l] * mu[i][l] * lengths[l];
+            }
+            if j < i {
+                mu[i][j] = r / lengths[j];
+            } else {
+                lengths[i] = r;
+            }
+        }
+    }
+    (mu, lengths)
+}
+
+/// Reduces the basis in place by the LLL algorithm with δ = 0.99, keeping the basis vectors exact.
+fn lll_reduce(basis: &mut [[i128; 8]; 8]) {
+    let mut k = 1;
+    while k < 8 {
+        // Size reduction leaves the Gram–Schmidt vectors alone, so only row k of mu needs updating.
+        let (mut mu, lengths) = gram_schmidt(basis);
+        for j in (0..k).rev() {
+            let q = mu[k][j].round();
+            if q != 0.0 {
+                let bj = basis[j];
+                for (x, y) in basis[k].iter_mut().zip(&bj) {
+                    *x -= q as i128 * y;
+                }
+                let muj = mu[j];
+                for (x, y) in mu[k][..j].iter_mut().zip(&muj) {
+                    *x -= q * y;
+                }
+                mu[k][j] -= q;
+            }
+        }
+        if lengths[k] >= (0.99 - mu[k][k - 1] * mu[k][k - 1]) * lengths[k - 1] {
+            k += 1;
+        } else {
+            basis.swap(k, k - 1);
+            k = (k - 1).max(1);
+        }
+    }
+}
+
+/// Returns a lattice vector of norm exactly p, searching the vectors of norm at most p
+/// by Fincke–Pohst enumeration over the reduced basis.
+fn shortest_vector(basis: &[[i128; 8]; 8], p: i128) -> Option<Octavian<i128>> {
+    struct Search<'a> {
+        basis: &'a [[i128; 8]; 8],
+        mu: [[f64; 8]; 8],
+        lengths: [f64; 8],
+        bound: f64,
+        target: i128,
+        u: [i128; 8],
+    }
+
+    impl Search<'_> {
+        fn extend(&mut self, level: usize, used: f64) -> Option<[i128; 8]> {
+            let center = -(level + 1..8)
+                .map(|j| self.u[j] as f64 * self.mu[j][level])
+                .sum::<f64>();
+            let radius = ((self.bound - used) / self.lengths[level]).max(0.0).sqrt();
+            for x in (center - radius).ceil() as i128..=(center + radius).floor() as i128 {
+                self.u[level] = x;
+                let used = used + (x as f64 - center).powi(2) * self.lengths[level];
+                let found = if level == 0 {
+                    let mut v = [0; 8];
+                    for (ui, row) in self.u.iter().zip(self.basis) {
+                        for (vj, rj) in v.iter_mut().zip(row) {
+                            *vj += ui * rj;
+                        }
+                    }
+                    (inner(&v, &v) == 2 * self.target).then_some(v)
+                } else {
+                    self.extend(level - 1, used)
+                };
+                if found.is_some() {
+                    return found;
+                }
+            }
+            None
+        }
+    }
+
+    let (mu, lengths) = gram_schmidt(basis);
+    let mut search = Search {
+        basis,
+        mu,
+        lengths,
+        // Twice the norm, with slack for the rounding of the Gram–Schmidt data; candidates are checked exactly.
+        bound: 2.0 * p as f64 * (1.0 + 1e-9) + 1e-6,
+        target: p,
+        u: [0; 8],
+    };
+    search.extend(7, 0.0).map(Octavian::new)
+}
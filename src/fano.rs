@@ -0,0 +1,78 @@
+//! The multiplication of the standard octonion basis 1, e1, ..., e7 by the Fano plane, in the orientation of
+//! `Octavian::OCTONION_BASIS`, as a bridge to octonion code written in the textbook basis.
+//!
+//! Each triple [a, b, c] of `FANO_TRIPLES` means e_a e_b = e_c, and then also e_b e_c = e_a and e_c e_a = e_b,
+//! while reversing the order of a product changes its sign. Every pair of distinct imaginary units lies in exactly
+//! one triple, and e_i e_i = -1.
+
+use crate::definitions;
+use crate::octavian::Octavian;
+use core::ops::Neg;
+use num::rational::Ratio;
+use num_traits::Num;
+
+/// The lines of the Fano plane, oriented so that e_a e_b = e_c for each [a, b, c].
+pub const FANO_TRIPLES: [[usize; 3]; 7] = definitions::FANO_TRIPLES;
+
+/// The products of the standard basis, with `STANDARD_PRODUCTS[i][j] = (sign, k)` for e_i e_j = sign e_k.
+const STANDARD_PRODUCTS: [[(i8, usize); 8]; 8] = standard_products();
+
+/// Fills in the products of the standard basis from the unit, the squares and the lines of the Fano plane.
+const fn standard_products() -> [[(i8, usize); 8]; 8] {
+    let mut table = [[(1, 0); 8]; 8];
+    let mut i = 0;
+    while i < 8 {
+        table[0][i] = (1, i);
+        table[i][0] = (1, i);
+        if i > 0 {
+            table[i][i] = (-1, 0);
+        }
+        i += 1;
+    }
+    let mut line = 0;
+    while line < 7 {
+        let [a, b, c] = FANO_TRIPLES[line];
+        let shifts = [[a, b, c], [b, c, a], [c, a, b]];
+        let mut s = 0;
+        while s < 3 {
+            let [x, y, z] = shifts[s];
+            table[x][y] = (1, z);
+            table[y][x] = (-1, z);
+            s += 1;
+        }
+        line += 1;
+    }
+    table
+}
+
+/// Returns (sign, k) with e_i e_j = sign e_k, where index 0 stands for 1 and 1, ..., 7 for e1, ..., e7.
+/// Returns `None` if `i` or `j` is not below 8.
+pub fn mul_standard_basis(i: usize, j: usize) -> Option<(i8, usize)> {
+    STANDARD_PRODUCTS.get(i)?.get(j).copied()
+}
+
+/// Multiplies two octonions given by their coordinates in the standard basis, using `mul_standard_basis`.
+pub fn mul_standard<T>(x: &[T; 8], y: &[T; 8]) -> [T; 8]
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    let mut product = [T::zero(); 8];
+    for (&a, row) in x.iter().zip(&STANDARD_PRODUCTS) {
+        for (&b, &(sign, k)) in y.iter().zip(row) {
+            product[k] = if sign > 0 {
+                product[k] + a * b
+            } else {
+                product[k] - a * b
+            };
+        }
+    }
+    product
+}
+
+/// Multiplies two octavians by converting them to standard coordinates, multiplying with the Fano table,
+/// and converting back. The result equals `x * y`, which computes the product with the adjoint matrices instead.
+pub fn mul_via_fano(x: &Octavian<i64>, y: &Octavian<i64>) -> Octavian<i64> {
+    let product: [Ratio<i64>; 8] = mul_standard(&x.to_octonion_coords(), &y.to_octonion_coords());
+    // The octavians are closed under multiplication, so every lattice coordinate of the product is an integer.
+    Octavian::new(Octavian::<Ratio<i64>>::coordinates_of(&product).map(|c| c.to_integer()))
+}
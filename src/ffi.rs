@@ -0,0 +1,130 @@
+//! A C interface on `int64_t[8]` buffers of E8 lattice coordinates, behind the `ffi` feature.
+//! Every function catches panics at the boundary and reports failures through its `AlcoStatus` return value,
+//! writing to `out` only on success. The header `include/alco.h` is generated from this module by cbindgen.
+
+use crate::lattice;
+use crate::octavian::Octavian;
+use std::panic::{self, UnwindSafe};
+
+/// The result of a call through the C interface.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlcoStatus {
+    /// The call succeeded and wrote its result.
+    Ok = 0,
+    /// A pointer argument was null.
+    NullPointer = 1,
+    /// The result does not fit in `int64_t`.
+    Overflow = 2,
+    /// The input has no result, such as a point with a coordinate that is not finite.
+    Undefined = 3,
+    /// The computation panicked.
+    Panic = 4,
+}
+
+/// Reads 8 values from `p`, or returns `NullPointer` if it is null.
+///
+/// # Safety
+/// A non-null `p` must point to 8 readable values.
+unsafe fn read<T: Copy>(p: *const T) -> Result<[T; 8], AlcoStatus> {
+    if p.is_null() {
+        return Err(AlcoStatus::NullPointer);
+    }
+    Ok(p.cast::<[T; 8]>().read_unaligned())
+}
+
+/// Runs `f` and writes its result to `out`, converting a null `out`, an error or a panic into the status.
+///
+/// # Safety
+/// A non-null `out` must point to space for a `T`.
+unsafe fn run<T>(
+    out: *mut T,
+    f: impl FnOnce() -> Result<T, AlcoStatus> + UnwindSafe,
+) -> AlcoStatus {
+    if out.is_null() {
+        return AlcoStatus::NullPointer;
+    }
+    match panic::catch_unwind(f) {
+        Ok(Ok(value)) => {
+            out.write_unaligned(value);
+            AlcoStatus::Ok
+        }
+        Ok(Err(status)) => status,
+        Err(_) => AlcoStatus::Panic,
+    }
+}
+
+/// Narrows coefficients computed in `i128` to `i64`.
+fn narrow(x: Octavian<i128>) -> Result<[i64; 8], AlcoStatus> {
+    let mut coefficients = [0; 8];
+    for (c, &wide) in coefficients.iter_mut().zip(&x.coefficients) {
+        *c = i64::try_from(wide).map_err(|_| AlcoStatus::Overflow)?;
+    }
+    Ok(coefficients)
+}
+
+/// Writes the product a * b to `out`.
+/// The product is computed in 128-bit integers with checked arithmetic, and `Overflow` is returned
+/// if it does not fit in `int64_t` or a partial sum does not fit in 128 bits.
+///
+/// # Safety
+/// `a` and `b` must each point to 8 readable `int64_t`, and `out` to 8 writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn alco_mul(a: *const i64, b: *const i64, out: *mut i64) -> AlcoStatus {
+    let wide = |x: [i64; 8]| Octavian::new(x.map(i128::from));
+    match (read(a), read(b)) {
+        (Ok(a), Ok(b)) => run(out.cast::<[i64; 8]>(), || {
+            narrow(wide(a).checked_mul(&wide(b)).ok_or(AlcoStatus::Overflow)?)
+        }),
+        _ => AlcoStatus::NullPointer,
+    }
+}
+
+/// Writes the norm of a to `out`.
+/// The norm is computed in 128-bit integers with checked arithmetic, and `Overflow` is returned
+/// if it does not fit in `int64_t` or a partial sum does not fit in 128 bits.
+///
+/// # Safety
+/// `a` must point to 8 readable `int64_t`, and `out` to one writable `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn alco_norm(a: *const i64, out: *mut i64) -> AlcoStatus {
+    match read(a) {
+        Ok(a) => run(out, || {
+            let norm = Octavian::new(a)
+                .norm_i128()
+                .map_err(|_| AlcoStatus::Overflow)?;
+            i64::try_from(norm).map_err(|_| AlcoStatus::Overflow)
+        }),
+        Err(status) => status,
+    }
+}
+
+/// Writes the conjugate of a to `out`.
+///
+/// # Safety
+/// `a` must point to 8 readable `int64_t`, and `out` to 8 writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn alco_conj(a: *const i64, out: *mut i64) -> AlcoStatus {
+    match read(a) {
+        Ok(a) => run(out.cast::<[i64; 8]>(), || {
+            narrow(Octavian::new(a.map(i128::from)).conjugate())
+        }),
+        Err(status) => status,
+    }
+}
+
+/// Writes an octavian nearest to the point x, given in E8 lattice coordinates, to `out`.
+///
+/// # Safety
+/// `x` must point to 8 readable `double`, and `out` to 8 writable `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn alco_closest_lattice_point(x: *const f64, out: *mut i64) -> AlcoStatus {
+    match read(x) {
+        Ok(x) => run(out.cast::<[i64; 8]>(), || {
+            lattice::closest_octavian(&Octavian::new(x))
+                .map(|c| c.coefficients)
+                .ok_or(AlcoStatus::Undefined)
+        }),
+        Err(status) => status,
+    }
+}
@@ -0,0 +1,445 @@
+//! Octonions over finite fields, the octavian integers with scalars extended to F_q.
+//! The structure constants of the octavians are integers in the E8 basis, so reducing them modulo p gives an
+//! 8-dimensional algebra over F_p, and over every F_q with q = p^k. Its norm is the E8 quadratic form,
+//! which stays nondegenerate and multiplicative in every characteristic, including 2.
+//! Every octonion algebra over a finite field is split, so this is the split octonion algebra over F_q.
+
+use crate::octavian::Octavian;
+
+/// The largest field order supported, which keeps the logarithm tables small and q^8 within a `u128`.
+pub const MAX_FIELD_ORDER: u32 = 1 << 12;
+
+/// Tests whether `p` is prime by trial division.
+fn is_prime(p: u32) -> bool {
+    p >= 2
+        && (2..)
+            .take_while(|d| d * d <= p)
+            .all(|d| !p.is_multiple_of(d))
+}
+
+/// Returns the `k` base-`p` digits of `n`, least significant first.
+fn digits(mut n: u32, p: u32, k: u32) -> Vec<u32> {
+    (0..k)
+        .map(|_| {
+            let d = n % p;
+            n /= p;
+            d
+        })
+        .collect()
+}
+
+/// Returns the number whose base-`p` digits, least significant first, are `d`.
+fn encode(d: &[u32], p: u32) -> u32 {
+    d.iter().rev().fold(0, |n, &x| n * p + x)
+}
+
+/// Returns the codes of x^0, ..., x^(q-2) modulo the monic polynomial x^k + c_{k-1} x^(k-1) + ... + c_0 with
+/// `modulus` = [c_0, ..., c_{k-1}], or `None` unless x has multiplicative order q - 1.
+/// A polynomial passing this test is primitive, and in particular irreducible.
+fn powers_of_x(modulus: &[u32], p: u32, q: u32) -> Option<Vec<u32>> {
+    let k = modulus.len();
+    let mut power = vec![0; k];
+    power[0] = 1;
+    let mut exp = Vec::with_capacity(q as usize - 1);
+    loop {
+        let code = encode(&power, p);
+        if code == 1 && !exp.is_empty() {
+            break;
+        }
+        if code == 0 || exp.len() == q as usize - 1 {
+            return None;
+        }
+        exp.push(code);
+        // Multiply by x, replacing x^k by -(c_{k-1} x^(k-1) + ... + c_0).
+        let top = power[k - 1];
+        power.rotate_right(1);
+        power[0] = 0;
+        for (c, &m) in power.iter_mut().zip(modulus) {
+            *c = (*c + (p - m) * top) % p;
+        }
+    }
+    (exp.len() == q as usize - 1).then_some(exp)
+}
+
+/// The finite field F_q with q = p^k elements.
+/// Elements are encoded as integers in `0..q`: the base-p digits of the code, least significant first,
+/// are the coefficients of a polynomial in a root x of the defining polynomial.
+/// The codes `0..p` are the prime subfield, with 0 and 1 the zero and identity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiniteField {
+    p: u32,
+    k: u32,
+    q: u32,
+    modulus: Vec<u32>,
+    exp: Vec<u32>,
+    log: Vec<u32>,
+}
+
+impl FiniteField {
+    /// Create the field with p^k elements, defined by the first primitive monic polynomial of degree k
+    /// in the order of the codes of its lower coefficients.
+    /// Returns `None` unless p is prime, k is positive and p^k is at most `MAX_FIELD_ORDER`.
+    pub fn new(p: u32, k: u32) -> Option<Self> {
+        if !is_prime(p) || k == 0 {
+            return None;
+        }
+        let q = p.checked_pow(k).filter(|&q| q <= MAX_FIELD_ORDER)?;
+        (0..q).find_map(|low| {
+            let modulus = digits(low, p, k);
+            let exp = powers_of_x(&modulus, p, q)?;
+            let mut log = vec![0; q as usize];
+            for (i, &e) in exp.iter().enumerate() {
+                log[e as usize] = i as u32;
+            }
+            Some(FiniteField {
+                p,
+                k,
+                q,
+                modulus,
+                exp,
+                log,
+            })
+        })
+    }
+
+    /// Returns the characteristic p.
+    pub fn characteristic(&self) -> u32 {
+        self.p
+    }
+
+    /// Returns the degree k of the field over its prime subfield.
+    pub fn degree(&self) -> u32 {
+        self.k
+    }
+
+    /// Returns the number of elements q = p^k.
+    pub fn order(&self) -> u32 {
+        self.q
+    }
+
+    /// Returns the lower coefficients [c_0, ..., c_{k-1}] of the defining polynomial x^k + c_{k-1} x^(k-1) + ... + c_0.
+    pub fn modulus(&self) -> &[u32] {
+        &self.modulus
+    }
+
+    /// Returns x, the root of the defining polynomial, which generates the multiplicative group.
+    pub fn generator(&self) -> u32 {
+        self.exp[1 % self.exp.len()]
+    }
+
+    /// Returns the image of an integer in the prime subfield.
+    pub fn from_integer(&self, n: i64) -> u32 {
+        n.rem_euclid(i64::from(self.p)) as u32
+    }
+
+    /// Applies `f` to the base-p digits of `a` and `b`.
+    fn digitwise(&self, mut a: u32, mut b: u32, f: impl Fn(u32, u32) -> u32) -> u32 {
+        let mut code = 0;
+        let mut place = 1;
+        for _ in 0..self.k {
+            code += f(a % self.p, b % self.p) % self.p * place;
+            a /= self.p;
+            b /= self.p;
+            place *= self.p;
+        }
+        code
+    }
+
+    /// Returns a + b.
+    pub fn add(&self, a: u32, b: u32) -> u32 {
+        if self.p == 2 {
+            return a ^ b;
+        }
+        self.digitwise(a, b, |x, y| x + y)
+    }
+
+    /// Returns -a.
+    pub fn neg(&self, a: u32) -> u32 {
+        if self.p == 2 {
+            return a;
+        }
+        self.digitwise(a, 0, |x, _| self.p - x)
+    }
+
+    /// Returns a - b.
+    pub fn sub(&self, a: u32, b: u32) -> u32 {
+        self.add(a, self.neg(b))
+    }
+
+    /// Returns a * b, through the logarithm tables of the generator.
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        let e = (self.log[a as usize] + self.log[b as usize]) % (self.q - 1);
+        self.exp[e as usize]
+    }
+
+    /// Returns the multiplicative inverse of a, or `None` if a is zero.
+    pub fn inv(&self, a: u32) -> Option<u32> {
+        if a == 0 {
+            return None;
+        }
+        let e = (self.q - 1 - self.log[a as usize]) % (self.q - 1);
+        Some(self.exp[e as usize])
+    }
+}
+
+/// An octonion over a finite field, given by its E8 lattice coordinates as field element codes.
+/// The arithmetic is carried out by the `OctonionAlgebraFq` it belongs to.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct OctonionFq {
+    /// The coordinates in the basis of simple roots, as codes of elements of F_q.
+    pub coefficients: [u32; 8],
+}
+
+/// The split octonion algebra over F_q, the octavian integers with structure constants reduced modulo p.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OctonionAlgebraFq {
+    field: FiniteField,
+    adjoint: [[[u32; 8]; 8]; 8],
+    gram: [[u32; 8]; 8],
+}
+
+impl OctonionAlgebraFq {
+    /// Create the octonion algebra over the given field.
+    pub fn new(field: FiniteField) -> Self {
+        let reduce = |c: i8| field.from_integer(c.into());
+        let adjoint =
+            Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES.map(|m| m.map(|row| row.map(reduce)));
+        let gram = Octavian::<i8>::GRAM_MATRIX.map(|row| row.map(reduce));
+        OctonionAlgebraFq {
+            field,
+            adjoint,
+            gram,
+        }
+    }
+
+    /// Returns the field of scalars.
+    pub fn field(&self) -> &FiniteField {
+        &self.field
+    }
+
+    /// Returns the number of elements of the algebra, q^8.
+    pub fn order(&self) -> u128 {
+        u128::from(self.field.q).pow(8)
+    }
+
+    /// Returns the reduction of an octavian integer, a ring homomorphism onto the algebra over the prime subfield.
+    pub fn reduce(&self, x: &Octavian<i64>) -> OctonionFq {
+        OctonionFq {
+            coefficients: x.coefficients.map(|c| self.field.from_integer(c)),
+        }
+    }
+
+    /// The additive identity.
+    pub fn zero(&self) -> OctonionFq {
+        OctonionFq {
+            coefficients: [0; 8],
+        }
+    }
+
+    /// The multiplicative identity, the reduction of `Octavian::one()`.
+    pub fn one(&self) -> OctonionFq {
+        self.reduce(&Octavian::one())
+    }
+
+    /// Returns x + y.
+    pub fn add(&self, x: &OctonionFq, y: &OctonionFq) -> OctonionFq {
+        OctonionFq {
+            coefficients: core::array::from_fn(|i| {
+                self.field.add(x.coefficients[i], y.coefficients[i])
+            }),
+        }
+    }
+
+    /// Returns -x.
+    pub fn neg(&self, x: &OctonionFq) -> OctonionFq {
+        OctonionFq {
+            coefficients: x.coefficients.map(|c| self.field.neg(c)),
+        }
+    }
+
+    /// Returns x - y.
+    pub fn sub(&self, x: &OctonionFq, y: &OctonionFq) -> OctonionFq {
+        self.add(x, &self.neg(y))
+    }
+
+    /// Multiplies x by the scalar t.
+    pub fn scale(&self, x: &OctonionFq, t: u32) -> OctonionFq {
+        OctonionFq {
+            coefficients: x.coefficients.map(|c| self.field.mul(c, t)),
+        }
+    }
+
+    /// Returns the left adjoint matrix of x, entry [i][j] being the coefficient of y_j in the i-th coefficient of x * y.
+    pub fn left_adjoint_matrix(&self, x: &OctonionFq) -> [[u32; 8]; 8] {
+        let mut matrix = [[0; 8]; 8];
+        for (adjoint, &a) in self.adjoint.iter().zip(&x.coefficients) {
+            for (row, adjoint_row) in matrix.iter_mut().zip(adjoint) {
+                for (m, &c) in row.iter_mut().zip(adjoint_row) {
+                    *m = self.field.add(*m, self.field.mul(c, a));
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Returns x * y.
+    pub fn mul(&self, x: &OctonionFq, y: &OctonionFq) -> OctonionFq {
+        let matrix = self.left_adjoint_matrix(x);
+        OctonionFq {
+            coefficients: matrix.map(|row| {
+                row.iter()
+                    .zip(&y.coefficients)
+                    .fold(0, |c, (&m, &b)| self.field.add(c, self.field.mul(m, b)))
+            }),
+        }
+    }
+
+    /// Returns the trace of x, which is the negative of its last coordinate as for `Octavian::trace`.
+    pub fn trace(&self, x: &OctonionFq) -> u32 {
+        self.field.neg(x.coefficients[7])
+    }
+
+    /// Returns the conjugate of x, trace(x) one() - x.
+    pub fn conjugate(&self, x: &OctonionFq) -> OctonionFq {
+        self.sub(&self.scale(&self.one(), self.trace(x)), x)
+    }
+
+    /// Returns the norm of x, the E8 quadratic form: the sum of the squared coordinates
+    /// plus the Gram matrix entry times x_i x_j for each edge i < j of the Dynkin diagram.
+    /// This avoids halving the inner product, so it is the norm in characteristic 2 as well.
+    pub fn norm(&self, x: &OctonionFq) -> u32 {
+        let f = &self.field;
+        let mut norm = 0;
+        for i in 0..8 {
+            let a = x.coefficients[i];
+            norm = f.add(norm, f.mul(a, a));
+            for j in i + 1..8 {
+                let product = f.mul(a, x.coefficients[j]);
+                norm = f.add(norm, f.mul(self.gram[i][j], product));
+            }
+        }
+        norm
+    }
+
+    /// Returns the inverse conjugate(x) / norm(x), or `None` if x has norm zero and so is a zero divisor.
+    pub fn inverse(&self, x: &OctonionFq) -> Option<OctonionFq> {
+        let n = self.field.inv(self.norm(x))?;
+        Some(self.scale(&self.conjugate(x), n))
+    }
+
+    /// Returns an iterator over all q^8 elements, ordered by their coordinates read as base-q digits,
+    /// the first coordinate least significant.
+    pub fn elements(&self) -> impl Iterator<Item = OctonionFq> + '_ {
+        let q = u128::from(self.field.q);
+        (0..self.order()).map(move |mut n| {
+            let mut coefficients = [0; 8];
+            for c in coefficients.iter_mut() {
+                *c = (n % q) as u32;
+                n /= q;
+            }
+            OctonionFq { coefficients }
+        })
+    }
+
+    /// Returns the number of invertible elements, those of nonzero norm.
+    /// The elements of each norm are counted by dynamic programming over the E8 Dynkin diagram, a tree,
+    /// in O(q³) field operations per edge rather than by visiting all q^8 elements.
+    pub fn unit_group_order(&self) -> u128 {
+        let zeros: u128 = self
+            .subtree_norm_counts(0, None)
+            .iter()
+            .map(|counts| counts[0])
+            .sum();
+        self.order() - zeros
+    }
+
+    /// Entry [a][s] counts the assignments of the coordinates in the subtree of the Dynkin diagram at `v`
+    /// with x_v = a whose part of the norm is s: the squares of those coordinates and the terms of the edges among them.
+    fn subtree_norm_counts(&self, v: usize, parent: Option<usize>) -> Vec<Vec<u128>> {
+        let f = &self.field;
+        let q = f.q as usize;
+        let mut counts: Vec<Vec<u128>> = (0..f.q)
+            .map(|a| {
+                let mut c = vec![0; q];
+                c[f.mul(a, a) as usize] = 1;
+                c
+            })
+            .collect();
+        for w in 0..8 {
+            let g = self.gram[v][w];
+            if w == v || g == 0 || Some(w) == parent {
+                continue;
+            }
+            let child = self.subtree_norm_counts(w, Some(v));
+            for (a, c) in counts.iter_mut().enumerate() {
+                // The child's part of the norm with the edge term g a b, summed over the values b of x_w.
+                let mut below = vec![0; q];
+                for (b, child_counts) in child.iter().enumerate() {
+                    let edge = f.mul(g, f.mul(a as u32, b as u32));
+                    for (s, &n) in child_counts.iter().enumerate() {
+                        below[f.add(s as u32, edge) as usize] += n;
+                    }
+                }
+                let mut sum = vec![0; q];
+                for (s, &m) in c.iter().enumerate().filter(|(_, &m)| m != 0) {
+                    for (t, &n) in below.iter().enumerate() {
+                        sum[f.add(s as u32, t as u32) as usize] += m * n;
+                    }
+                }
+                *c = sum;
+            }
+        }
+        counts
+    }
+
+    /// Returns a basis of the right annihilator {y : x * y = 0}, the kernel of the left adjoint matrix of x.
+    /// It is the whole algebra for x = 0, zero for invertible x, and 4-dimensional for every other x.
+    pub fn right_annihilator(&self, x: &OctonionFq) -> Vec<OctonionFq> {
+        let f = &self.field;
+        let mut matrix = self.left_adjoint_matrix(x);
+        // Row reduce, recording the pivot column of each row.
+        let mut pivots = Vec::new();
+        for col in 0..8 {
+            let row = pivots.len();
+            let Some(r) = (row..8).find(|&r| matrix[r][col] != 0) else {
+                continue;
+            };
+            matrix.swap(row, r);
+            let scale = f.inv(matrix[row][col]).expect("the pivot is nonzero");
+            matrix[row] = matrix[row].map(|a| f.mul(a, scale));
+            let pivot_row = matrix[row];
+            for (r, other) in matrix.iter_mut().enumerate() {
+                let c = other[col];
+                if r != row && c != 0 {
+                    for (a, &b) in other.iter_mut().zip(&pivot_row) {
+                        *a = f.sub(*a, f.mul(c, b));
+                    }
+                }
+            }
+            pivots.push(col);
+        }
+        (0..8)
+            .filter(|c| !pivots.contains(c))
+            .map(|free| {
+                let mut coefficients = [0; 8];
+                coefficients[free] = 1;
+                for (row, &col) in pivots.iter().enumerate() {
+                    coefficients[col] = f.neg(matrix[row][free]);
+                }
+                OctonionFq { coefficients }
+            })
+            .collect()
+    }
+
+    /// Returns the first nonzero element x of norm zero in the order of `elements`, with conjugate(x),
+    /// so that x * conjugate(x) = norm(x) one() = 0. Since the algebra is split, such an x always exists.
+    pub fn find_zero_divisor(&self) -> Option<(OctonionFq, OctonionFq)> {
+        self.elements()
+            .skip(1)
+            .find(|x| self.norm(x) == 0)
+            .map(|x| (x, self.conjugate(&x)))
+    }
+}
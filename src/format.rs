@@ -0,0 +1,300 @@
+//! Human-readable rendering of octavians with integer, rational, and floating-point coefficients.
+//!
+//! `Display` writes the 8 E8 lattice coordinates separated by commas, which `FromStr` parses back for integers,
+//! as it does the sums of `Notation::Sum` and `Notation::Fancy` in the fraction style.
+//! A precision such as `{:.3}` limits the digits of float coefficients and of rationals written as decimals,
+//! and the alternate flag `{:#}` writes rationals as decimals instead of `p/q`.
+//! Integer coefficients are always written in full. `to_string_with` gives control over every choice,
+//! including writing the octavian as a sum of basis symbols, in ASCII or with Unicode signs and subscripts.
+
+use crate::octavian::{from_i8, Coefficient, Octavian};
+use core::fmt;
+use core::ops::Neg;
+use num::rational::Ratio;
+use num::Integer;
+use num_traits::{FromPrimitive, Num};
+
+/// The coordinates in which an octavian is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatBasis {
+    /// The E8 lattice coordinates, labelled `b0`, ..., `b7`.
+    Lattice,
+    /// The coordinates in the standard octonion basis 1, e1, ..., e7, which may be half-integers.
+    Octonion,
+    /// The octonion coordinates, with the imaginary units named i, j, k, l, il, jl, kl after the Fano-plane relations.
+    OctonionNamed,
+}
+
+/// Whether an octavian is written as a list of coordinates or as a sum of basis symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// The coordinates, laid out as `suppress_zeros` and `separator` say.
+    Coordinates,
+    /// A signed sum of the nonzero terms in ASCII, such as `-2b0 - 3b1 + b7` or `1/2 - (1/2)e1`.
+    /// `suppress_zeros` and `separator` are ignored, and the zero octavian is written `0`.
+    Sum,
+    /// The sum of `Sum` with Unicode minus signs and subscripted symbols, such as `−2b₀ − 3b₁ + b₇`.
+    Fancy,
+}
+
+/// How coefficients that are not integers are written, when they are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RationalStyle {
+    /// As a reduced fraction `p/q`.
+    Fraction,
+    /// As a decimal, with `precision` digits after the point or, without one, at most 6 with trailing zeros removed.
+    Decimal,
+}
+
+/// The options of `Octavian::to_string_with`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub basis: FormatBasis,
+    pub notation: Notation,
+    pub style: RationalStyle,
+    /// The number of digits after the point for float coefficients and decimal rationals.
+    pub precision: Option<usize>,
+    /// Writes only the nonzero coordinates, each with its basis label, such as `2*b0` or `-e3`,
+    /// so that `" + "` as the separator gives a sum. The zero octavian is written `0`.
+    pub suppress_zeros: bool,
+    /// Written between consecutive coordinates or terms.
+    pub separator: String,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            basis: FormatBasis::Lattice,
+            notation: Notation::Coordinates,
+            style: RationalStyle::Fraction,
+            precision: None,
+            suppress_zeros: false,
+            separator: ", ".to_string(),
+        }
+    }
+}
+
+/// Coefficient types that an octavian can be written with.
+pub trait FormatCoefficient {
+    /// Writes the coefficient divided by `denominator`, which is 1 for lattice coordinates and 2 for octonion coordinates.
+    fn format_over(&self, denominator: u8, options: &FormatOptions) -> String;
+}
+
+fn format_float(x: impl fmt::Display, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, x),
+        None => x.to_string(),
+    }
+}
+
+/// Writes the exact decimal expansion of n/d, rounded half away from zero.
+fn format_decimal(n: i128, d: i128, precision: Option<usize>) -> String {
+    let digits = precision.unwrap_or(6);
+    let scale = u32::try_from(digits)
+        .ok()
+        .and_then(|digits| 10u128.checked_pow(digits));
+    let (magnitude, d) = (n.unsigned_abs(), d.unsigned_abs());
+    let Some((scale, scaled)) =
+        scale.and_then(|scale| Some((scale, magnitude.checked_mul(scale)?)))
+    else {
+        return format_float(n as f64 / d as f64, precision);
+    };
+    let rounded = (scaled + d / 2) / d;
+    let sign = if n < 0 && rounded != 0 { "-" } else { "" };
+    // A precision of 0 leaves no fraction at all, where the padded remainder would still print a 0.
+    let mut fraction = if digits == 0 {
+        String::new()
+    } else {
+        format!("{:0width$}", rounded % scale, width = digits)
+    };
+    if precision.is_none() {
+        fraction.truncate(fraction.trim_end_matches('0').len());
+    }
+    if fraction.is_empty() {
+        format!("{}{}", sign, rounded / scale)
+    } else {
+        format!("{}{}.{}", sign, rounded / scale, fraction)
+    }
+}
+
+/// Writes n / (d * denominator) as the options say.
+fn format_rational(n: i128, d: i128, denominator: u8, options: &FormatOptions) -> String {
+    let Some(d) = d.checked_mul(i128::from(denominator)) else {
+        return format_float(
+            n as f64 / d as f64 / f64::from(denominator),
+            options.precision,
+        );
+    };
+    let g = n.gcd(&d);
+    let (n, d) = if d < 0 {
+        (-n / g, -d / g)
+    } else {
+        (n / g, d / g)
+    };
+    match options.style {
+        RationalStyle::Fraction if d == 1 => n.to_string(),
+        RationalStyle::Fraction => format!("{}/{}", n, d),
+        RationalStyle::Decimal => format_decimal(n, d, options.precision),
+    }
+}
+
+macro_rules! format_integers {
+    ($($t:ty),*) => {
+        $(
+            impl FormatCoefficient for $t {
+                fn format_over(&self, denominator: u8, options: &FormatOptions) -> String {
+                    if denominator == 1 {
+                        self.to_string()
+                    } else {
+                        format_rational(i128::from(*self), 1, denominator, options)
+                    }
+                }
+            }
+
+            impl FormatCoefficient for Ratio<$t> {
+                fn format_over(&self, denominator: u8, options: &FormatOptions) -> String {
+                    format_rational(i128::from(*self.numer()), i128::from(*self.denom()), denominator, options)
+                }
+            }
+        )*
+    };
+}
+
+format_integers!(i8, i16, i32, i64, i128);
+
+macro_rules! format_floats {
+    ($($t:ty),*) => {
+        $(
+            impl FormatCoefficient for $t {
+                fn format_over(&self, denominator: u8, options: &FormatOptions) -> String {
+                    format_float(*self / <$t>::from(denominator), options.precision)
+                }
+            }
+        )*
+    };
+}
+
+format_floats!(f32, f64);
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + FormatCoefficient + Coefficient,
+{
+    /// Writes the octavian as the options say.
+    pub fn to_string_with(&self, options: &FormatOptions) -> String {
+        // Each octonion coordinate is half the inner product with the orthonormal basis element.
+        let octonion_coordinates = || {
+            Octavian::<T>::OCTONION_BASIS
+                .map(|row| self.inner_product(&Octavian::new(row.map(from_i8))))
+        };
+        let (coordinates, denominator, labels) = match (options.basis, options.notation) {
+            (FormatBasis::Lattice, Notation::Fancy) => (
+                self.coefficients,
+                1,
+                ["b₀", "b₁", "b₂", "b₃", "b₄", "b₅", "b₆", "b₇"],
+            ),
+            (FormatBasis::Lattice, _) => (
+                self.coefficients,
+                1,
+                ["b0", "b1", "b2", "b3", "b4", "b5", "b6", "b7"],
+            ),
+            (FormatBasis::Octonion, Notation::Fancy) => (
+                octonion_coordinates(),
+                2,
+                ["", "e₁", "e₂", "e₃", "e₄", "e₅", "e₆", "e₇"],
+            ),
+            (FormatBasis::Octonion, _) => (
+                octonion_coordinates(),
+                2,
+                ["", "e1", "e2", "e3", "e4", "e5", "e6", "e7"],
+            ),
+            (FormatBasis::OctonionNamed, _) => (
+                octonion_coordinates(),
+                2,
+                ["", "i", "j", "k", "l", "il", "jl", "kl"],
+            ),
+        };
+        let terms = coordinates
+            .iter()
+            .zip(labels)
+            .filter(|(c, _)| !c.is_zero())
+            .map(|(c, label)| (c.format_over(denominator, options), label));
+        match options.notation {
+            Notation::Coordinates if !options.suppress_zeros => {
+                let fields: Vec<String> = coordinates
+                    .iter()
+                    .map(|c| c.format_over(denominator, options))
+                    .collect();
+                fields.join(&options.separator)
+            }
+            Notation::Coordinates => {
+                let terms: Vec<String> = terms
+                    .map(|(c, label)| match (c.as_str(), label) {
+                        (_, "") => c,
+                        ("1", _) => label.to_string(),
+                        ("-1", _) => format!("-{}", label),
+                        _ => format!("{}*{}", c, label),
+                    })
+                    .collect();
+                if terms.is_empty() {
+                    "0".to_string()
+                } else {
+                    terms.join(&options.separator)
+                }
+            }
+            Notation::Sum | Notation::Fancy => {
+                let minus = if options.notation == Notation::Fancy {
+                    "\u{2212}"
+                } else {
+                    "-"
+                };
+                let mut sum = String::new();
+                for (c, label) in terms {
+                    let (negative, magnitude) = match c.strip_prefix('-') {
+                        Some(magnitude) => (true, magnitude),
+                        None => (false, c.as_str()),
+                    };
+                    match (sum.is_empty(), negative) {
+                        (true, true) => sum.push_str(minus),
+                        (true, false) => {}
+                        (false, true) => sum.push_str(&format!(" {} ", minus)),
+                        (false, false) => sum.push_str(" + "),
+                    }
+                    match (magnitude, label) {
+                        (_, "") => sum.push_str(magnitude),
+                        ("1", _) => sum.push_str(label),
+                        // A fraction is bracketed so that its denominator is not read as multiplying the symbol.
+                        _ if magnitude.contains('/') => {
+                            sum.push_str(&format!("({}){}", magnitude, label))
+                        }
+                        _ => sum.push_str(&format!("{}{}", magnitude, label)),
+                    }
+                }
+                if sum.is_empty() {
+                    "0".to_string()
+                } else {
+                    sum
+                }
+            }
+        }
+    }
+}
+
+/// Writes the E8 lattice coordinates separated by commas, honouring the precision and alternate flags.
+impl<T> fmt::Display for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + FormatCoefficient + Coefficient,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = FormatOptions {
+            style: if f.alternate() {
+                RationalStyle::Decimal
+            } else {
+                RationalStyle::Fraction
+            },
+            precision: f.precision(),
+            ..FormatOptions::default()
+        };
+        f.write_str(&self.to_string_with(&options))
+    }
+}
@@ -0,0 +1,273 @@
+//! Closed intervals of `f64` with outward rounding, as coefficients for rigorous floating-point computations.
+//!
+//! Each operation rounds its lower bound down and its upper bound up, so `Octavian<Interval>` products, norms and
+//! inner products enclose the exact values for every choice of points in the operand intervals.
+//! The direction of the rounding error is found exactly, from the error of a sum and the residual of a product or
+//! quotient given by a fused multiply-add, so exact results stay exact and point intervals track `f64` arithmetic.
+//! With the `tables` feature, `lattice::closest_octavian_candidates` decodes interval targets.
+
+use crate::octavian::{sealed, Coefficient};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use num_traits::{FromPrimitive, Num, One, ParseFloatError, Zero};
+
+/// Below this magnitude the residual of a product or quotient may underflow, so inexact results are widened on both sides.
+const TINY: f64 = f64::MIN_POSITIVE * (1u64 << 53) as f64;
+
+/// A closed interval [lo, hi] of real numbers with `f64` endpoints, possibly infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+/// Returns the floats next to `p` on either side, which enclose any value whose rounding to nearest is `p`.
+fn widen(p: f64) -> Interval {
+    Interval {
+        lo: p.next_down(),
+        hi: p.next_up(),
+    }
+}
+
+/// Returns the floats just below and above the exact value `p + err`, where `p` is its rounding to nearest.
+fn enclose(p: f64, err: f64) -> Interval {
+    if p.is_nan() {
+        Interval { lo: p, hi: p }
+    } else if p == f64::INFINITY {
+        Interval {
+            lo: f64::MAX,
+            hi: p,
+        }
+    } else if p == f64::NEG_INFINITY {
+        Interval {
+            lo: p,
+            hi: f64::MIN,
+        }
+    } else if err > 0.0 {
+        Interval {
+            lo: p,
+            hi: p.next_up(),
+        }
+    } else if err < 0.0 {
+        Interval {
+            lo: p.next_down(),
+            hi: p,
+        }
+    } else {
+        Interval::point(p)
+    }
+}
+
+/// Encloses a + b, with the rounding error of the sum found by Knuth's TwoSum.
+fn enclose_sum(a: f64, b: f64) -> Interval {
+    let s = a + b;
+    let b_part = s - a;
+    let err = (a - (s - b_part)) + (b - b_part);
+    enclose(s, err)
+}
+
+/// Encloses a b, with the rounding error given by a fused multiply-add.
+fn enclose_product(a: f64, b: f64) -> Interval {
+    let p = a * b;
+    if p.abs() < TINY && a != 0.0 && b != 0.0 {
+        return widen(p);
+    }
+    enclose(p, a.mul_add(b, -p))
+}
+
+/// Encloses a / b, with the sign of the rounding error given by the residual a - q b.
+fn enclose_quotient(a: f64, b: f64) -> Interval {
+    let q = a / b;
+    if q.abs() < TINY && a != 0.0 {
+        return widen(q);
+    }
+    let residual = (-q).mul_add(b, a);
+    enclose(q, residual * b.signum())
+}
+
+/// Returns the smallest interval containing the enclosures of the four endpoint combinations.
+fn hull_of(a: Interval, b: Interval, f: impl Fn(f64, f64) -> Interval) -> Interval {
+    [f(a.lo, b.lo), f(a.lo, b.hi), f(a.hi, b.lo), f(a.hi, b.hi)]
+        .into_iter()
+        .reduce(|x, y| x.hull(&y))
+        .expect("four endpoint combinations")
+}
+
+impl Interval {
+    /// Create the interval [lo, hi], or return `None` if an endpoint is NaN or `lo > hi`.
+    pub fn new(lo: f64, hi: f64) -> Option<Self> {
+        (lo <= hi).then_some(Interval { lo, hi })
+    }
+
+    /// Returns the interval [x, x] containing only `x`.
+    pub const fn point(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    /// Returns the lower endpoint.
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the upper endpoint.
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Tests whether `x` lies in the interval.
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Tests whether the interval contains only one number.
+    pub fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Returns the midpoint, rounded to nearest.
+    pub fn midpoint(&self) -> f64 {
+        self.lo / 2.0 + self.hi / 2.0
+    }
+
+    /// Returns hi - lo, rounded up.
+    pub fn width(&self) -> f64 {
+        enclose_sum(self.hi, -self.lo).hi
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Interval {
+            lo: enclose_sum(self.lo, rhs.lo).lo,
+            hi: enclose_sum(self.hi, rhs.hi).hi,
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        hull_of(self, rhs, enclose_product)
+    }
+}
+
+/// Divides by an interval not containing 0. Division by an interval containing 0 returns the whole real line.
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(0.0) {
+            return Interval {
+                lo: f64::NEG_INFINITY,
+                hi: f64::INFINITY,
+            };
+        }
+        hull_of(self, rhs, enclose_quotient)
+    }
+}
+
+/// Encloses the remainders x % y of `f64`, which have the sign of x and magnitude below |y|, over the two intervals.
+impl Rem for Interval {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        if self.is_point() && rhs.is_point() {
+            // The remainder of floats is exact.
+            return Interval::point(self.lo % rhs.lo);
+        }
+        let bound = rhs.lo.abs().max(rhs.hi.abs());
+        Interval {
+            lo: if self.lo >= 0.0 { 0.0 } else { -bound },
+            hi: if self.hi <= 0.0 { 0.0 } else { bound },
+        }
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Self {
+        Interval::point(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Self {
+        Interval::point(1.0)
+    }
+}
+
+/// Parses a float as the point interval at the nearest `f64`.
+/// Decimal fractions are generally not floats, so enclose them by dividing integers instead.
+impl Num for Interval {
+    type FromStrRadixErr = ParseFloatError;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Interval::point)
+    }
+}
+
+/// Converts integers to the point interval when they are floats, and to the enclosing floats otherwise.
+impl FromPrimitive for Interval {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_i128(i128::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_i128(i128::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        let p = n as f64;
+        // The integers that round to 2^127 lie below it, and 2^127 is out of range of i128.
+        let err = if p >= 2f64.powi(127) {
+            -1.0
+        } else {
+            (n - p as i128) as f64
+        };
+        Some(enclose(p, err))
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_f64(f64::from(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        (!n.is_nan()).then_some(Interval::point(n))
+    }
+}
+
+impl sealed::Sealed for Interval {}
+
+impl Coefficient for Interval {}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.lo, self.hi)
+    }
+}
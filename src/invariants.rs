@@ -0,0 +1,131 @@
+//! Algebraic identities of the octavian integers, checked on a pair of elements with overflow-checked arithmetic.
+//! The fuzz targets in `fuzz/` feed arbitrary pairs through `check_invariants`, so every computation here is done
+//! in `i128` with checked operations: an identity whose evaluation would overflow is skipped rather than wrapped.
+
+use crate::octavian::{Octavian, STRUCTURE_CONSTANTS};
+
+type Coefficients = [i128; 8];
+
+/// The bound on the coefficients below which `Octavian<i64>` products cannot overflow in intermediate sums,
+/// so that the library multiplication is compared with the checked reference.
+const LIBRARY_MUL_BOUND: u64 = 1 << 20;
+
+fn checked_add(x: &Coefficients, y: &Coefficients) -> Option<Coefficients> {
+    let mut sum = [0; 8];
+    for (s, (&a, &b)) in sum.iter_mut().zip(x.iter().zip(y)) {
+        *s = a.checked_add(b)?;
+    }
+    Some(sum)
+}
+
+fn checked_sub(x: &Coefficients, y: &Coefficients) -> Option<Coefficients> {
+    let mut difference = [0; 8];
+    for (d, (&a, &b)) in difference.iter_mut().zip(x.iter().zip(y)) {
+        *d = a.checked_sub(b)?;
+    }
+    Some(difference)
+}
+
+/// Returns the product of two octavians by running over the structure constants, or `None` on overflow.
+pub(crate) fn checked_mul(x: &Coefficients, y: &Coefficients) -> Option<Coefficients> {
+    let mut product = [0i128; 8];
+    for &(i, j, k, c) in &STRUCTURE_CONSTANTS {
+        let term = i128::from(c)
+            .checked_mul(x[k as usize])?
+            .checked_mul(y[j as usize])?;
+        product[i as usize] = product[i as usize].checked_add(term)?;
+    }
+    Some(product)
+}
+
+/// Returns the norm, half the inner product given by the Gram matrix, or `None` on overflow.
+fn checked_norm(x: &Coefficients) -> Option<i128> {
+    let mut sum = 0i128;
+    for (i, row) in Octavian::<i8>::GRAM_MATRIX.iter().enumerate() {
+        for (j, &g) in row.iter().enumerate() {
+            if g != 0 {
+                sum = sum.checked_add(i128::from(g).checked_mul(x[i])?.checked_mul(x[j])?)?;
+            }
+        }
+    }
+    Some(sum / 2)
+}
+
+/// Returns the conjugate trace(x) one() - x, or `None` on overflow.
+fn checked_conjugate(x: &Coefficients) -> Option<Coefficients> {
+    let trace = x[7].checked_neg()?;
+    let mut scaled = [0; 8];
+    for (s, &c) in scaled.iter_mut().zip(&Octavian::<i128>::one().coefficients) {
+        *s = c.checked_mul(trace)?;
+    }
+    checked_sub(&scaled, x)
+}
+
+/// Checks that a and b satisfy the identities of the octavian integers:
+/// addition and subtraction are inverse, the norm is multiplicative, conjugation is an involutive anti-automorphism,
+/// and for small coefficients the library product agrees with the structure constants.
+/// Returns a description of the first identity that fails.
+pub fn check_invariants(a: &Octavian<i64>, b: &Octavian<i64>) -> Result<(), String> {
+    check_invariants_with(a, b, checked_mul)
+}
+
+/// Checks the identities of `check_invariants` for the given checked multiplication.
+pub(crate) fn check_invariants_with(
+    a: &Octavian<i64>,
+    b: &Octavian<i64>,
+    mul: impl Fn(&Coefficients, &Coefficients) -> Option<Coefficients>,
+) -> Result<(), String> {
+    let x = a.coefficients.map(i128::from);
+    let y = b.coefficients.map(i128::from);
+
+    // The coefficients come from i64, so sums and differences fit in i128.
+    let sum = checked_add(&x, &y).expect("sums of i64 fit in i128");
+    if checked_sub(&sum, &y) != Some(x) {
+        return Err(format!("(a + b) - b != a for a = {:?}, b = {:?}", a, b));
+    }
+
+    if let Some(conjugate) = checked_conjugate(&x) {
+        if checked_conjugate(&conjugate) != Some(x) {
+            return Err(format!("conj(conj(a)) != a for a = {:?}", a));
+        }
+    }
+
+    let Some(product) = mul(&x, &y) else {
+        return Ok(());
+    };
+    if let (Some(n), Some(na), Some(nb)) =
+        (checked_norm(&product), checked_norm(&x), checked_norm(&y))
+    {
+        if na.checked_mul(nb).is_some_and(|m| m != n) {
+            return Err(format!(
+                "N(ab) = {} but N(a) N(b) = {} * {} for a = {:?}, b = {:?}",
+                n, na, nb, a, b
+            ));
+        }
+    }
+
+    let conjugates = (
+        checked_conjugate(&product),
+        checked_conjugate(&y).zip(checked_conjugate(&x)),
+    );
+    if let (Some(left), Some((cy, cx))) = conjugates {
+        if mul(&cy, &cx).is_some_and(|right| right != left) {
+            return Err(format!(
+                "conj(ab) != conj(b) conj(a) for a = {:?}, b = {:?}",
+                a, b
+            ));
+        }
+    }
+
+    let small = |c: &i64| c.unsigned_abs() < LIBRARY_MUL_BOUND;
+    if a.coefficients.iter().all(small) && b.coefficients.iter().all(small) {
+        let library = (*a * *b).coefficients.map(i128::from);
+        if library != product {
+            return Err(format!(
+                "a * b = {:?} but the structure constants give {:?} for a = {:?}, b = {:?}",
+                library, product, a, b
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,374 @@
+//! Reading and writing lists of octavians, either as CSV with one octavian per line as its 8 E8 lattice coordinates,
+//! or in a compact binary format of raw little-endian coefficients behind a small versioned header.
+
+use crate::octavian::{Coefficient, Octavian};
+use core::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// The column names of the header line.
+const HEADER: [&str; 8] = ["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7"];
+
+/// Options for `write_csv_with`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Whether to start with a header line naming the columns.
+    pub header: bool,
+    /// Whether to add a ninth column with the norm of each octavian, which `read_csv` ignores.
+    pub norm_column: bool,
+}
+
+/// The reason a line of CSV could not be read.
+#[derive(Debug)]
+pub enum CsvErrorCause {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The line has this many columns instead of 8, or 9 with a norm column.
+    ColumnCount(usize),
+    /// The field is not an `i64`.
+    InvalidInteger(String),
+}
+
+impl PartialEq for CsvErrorCause {
+    /// Compares I/O failures by their kind, since `io::Error` itself has no equality.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CsvErrorCause::Io(a), CsvErrorCause::Io(b)) => a.kind() == b.kind(),
+            (CsvErrorCause::ColumnCount(a), CsvErrorCause::ColumnCount(b)) => a == b,
+            (CsvErrorCause::InvalidInteger(a), CsvErrorCause::InvalidInteger(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CsvErrorCause {}
+
+/// An error reading CSV, with the 1-based line and column where it occurred.
+/// It converts into `AlcoError::Csv`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CsvError {
+    pub line: usize,
+    pub column: usize,
+    pub cause: CsvErrorCause,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.cause {
+            CsvErrorCause::Io(e) => write!(f, "{}", e),
+            CsvErrorCause::ColumnCount(n) => write!(f, "expected 8 columns, found {}", n),
+            CsvErrorCause::InvalidInteger(s) => write!(f, "{:?} is not an integer", s),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.cause {
+            CsvErrorCause::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the octavians as CSV without a header or norm column.
+pub fn write_csv<W: Write>(w: W, octavians: &[Octavian<i64>]) -> io::Result<()> {
+    write_csv_with(w, octavians, CsvOptions::default())
+}
+
+/// Writes the octavians as CSV, one per line, with the header and norm column chosen by `options`.
+/// The norms are evaluated in `i128`; one that does not fit is reported as an `InvalidData` error.
+pub fn write_csv_with<W: Write>(
+    mut w: W,
+    octavians: &[Octavian<i64>],
+    options: CsvOptions,
+) -> io::Result<()> {
+    if options.header {
+        write!(w, "{}", HEADER.join(","))?;
+        if options.norm_column {
+            write!(w, ",norm")?;
+        }
+        writeln!(w)?;
+    }
+    for x in octavians {
+        let fields: Vec<String> = x.coefficients.iter().map(|c| c.to_string()).collect();
+        write!(w, "{}", fields.join(","))?;
+        if options.norm_column {
+            let norm = x
+                .norm_i128()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            write!(w, ",{}", norm)?;
+        }
+        writeln!(w)?;
+    }
+    w.flush()
+}
+
+/// Reads octavians written by `write_csv_with`. A header line, a norm column, blank lines, whitespace around fields,
+/// and CRLF line endings are all accepted; the norm column is not checked.
+pub fn read_csv<R: Read>(r: R) -> Result<Vec<Octavian<i64>>, CsvError> {
+    let mut octavians = Vec::new();
+    for (i, line) in BufReader::new(r).lines().enumerate() {
+        let number = i + 1;
+        let line = line.map_err(|e| CsvError {
+            line: number,
+            column: 1,
+            cause: CsvErrorCause::Io(e),
+        })?;
+        let fields: Vec<&str> = line.trim().split(',').map(str::trim).collect();
+        if fields == [""] || (octavians.is_empty() && fields[0] == HEADER[0]) {
+            continue;
+        }
+        if fields.len() != 8 && fields.len() != 9 {
+            return Err(CsvError {
+                line: number,
+                column: fields.len().min(8) + 1,
+                cause: CsvErrorCause::ColumnCount(fields.len()),
+            });
+        }
+        let mut coefficients = [0; 8];
+        for (j, (c, field)) in coefficients.iter_mut().zip(&fields).enumerate() {
+            *c = field.parse().map_err(|_| CsvError {
+                line: number,
+                column: j + 1,
+                cause: CsvErrorCause::InvalidInteger(field.to_string()),
+            })?;
+        }
+        octavians.push(Octavian::new(coefficients));
+    }
+    Ok(octavians)
+}
+
+/// The first bytes of every binary file.
+pub const BINARY_MAGIC: [u8; 4] = *b"OCTV";
+
+/// The version of the binary format written by `write_binary`.
+pub const BINARY_VERSION: u8 = 1;
+
+/// The length of the binary header: the magic bytes, the version, the coefficient type tag,
+/// two reserved zero bytes, and the element count as a little-endian `u64`.
+const BINARY_HEADER_LEN: usize = 16;
+
+/// A coefficient type that can be written in the binary format, identified in the header by its tag.
+pub trait BinaryCoefficient: Coefficient {
+    /// The tag recorded in the header.
+    const TAG: u8;
+    /// The number of bytes of each coefficient.
+    const SIZE: usize;
+    /// Appends the little-endian bytes of the coefficient.
+    fn write_le(self, out: &mut Vec<u8>);
+    /// Reads a coefficient from exactly `SIZE` little-endian bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! binary_coefficient {
+    ($t:ty, $tag:expr) => {
+        impl BinaryCoefficient for $t {
+            const TAG: u8 = $tag;
+            const SIZE: usize = size_of::<$t>();
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+            fn read_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().expect("a coefficient has SIZE bytes"))
+            }
+        }
+    };
+}
+
+binary_coefficient!(i8, 1);
+binary_coefficient!(i16, 2);
+binary_coefficient!(i32, 3);
+binary_coefficient!(i64, 4);
+binary_coefficient!(i128, 5);
+
+/// Returns the size in bytes of the coefficient type with the given tag.
+fn tag_size(tag: u8) -> Option<usize> {
+    match tag {
+        1..=5 => Some(1 << (tag - 1)),
+        _ => None,
+    }
+}
+
+/// Sign-extends little-endian bytes of a signed integer to an `i128`.
+fn widen_le(bytes: &[u8]) -> i128 {
+    let fill = if bytes.last().is_some_and(|b| b & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut wide = [fill; 16];
+    wide[..bytes.len()].copy_from_slice(bytes);
+    i128::from_le_bytes(wide)
+}
+
+/// The reason a binary file could not be read. It converts into `AlcoError::Binary`.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The file does not start with `BINARY_MAGIC`.
+    BadMagic,
+    /// The file has a version this crate cannot read.
+    UnsupportedVersion(u8),
+    /// The header names an unknown coefficient type.
+    UnknownType(u8),
+    /// The file holds coefficients of another type, and widening was not allowed or would not fit.
+    TypeMismatch { found: u8, expected: u8 },
+    /// The file ends before the number of elements given in the header.
+    Truncated { expected: u64, found: u64 },
+    /// The file continues past the elements given in the header.
+    TrailingData,
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Io(e) => write!(f, "{}", e),
+            BinaryError::BadMagic => write!(f, "not an octavian binary file"),
+            BinaryError::UnsupportedVersion(v) => write!(f, "unsupported version {}", v),
+            BinaryError::UnknownType(t) => write!(f, "unknown coefficient type tag {}", t),
+            BinaryError::TypeMismatch { found, expected } => write!(
+                f,
+                "the file has coefficient type tag {}, expected {}",
+                found, expected
+            ),
+            BinaryError::Truncated { expected, found } => {
+                write!(f, "the file ends after {} of {} elements", found, expected)
+            }
+            BinaryError::TrailingData => write!(f, "unexpected data after the last element"),
+        }
+    }
+}
+
+impl PartialEq for BinaryError {
+    /// Compares I/O failures by their kind, since `io::Error` itself has no equality.
+    fn eq(&self, other: &Self) -> bool {
+        use BinaryError::*;
+        match (self, other) {
+            (Io(a), Io(b)) => a.kind() == b.kind(),
+            (BadMagic, BadMagic) | (TrailingData, TrailingData) => true,
+            (UnsupportedVersion(a), UnsupportedVersion(b)) | (UnknownType(a), UnknownType(b)) => {
+                a == b
+            }
+            (
+                TypeMismatch {
+                    found: a,
+                    expected: b,
+                },
+                TypeMismatch {
+                    found: c,
+                    expected: d,
+                },
+            ) => (a, b) == (c, d),
+            (
+                Truncated {
+                    expected: a,
+                    found: b,
+                },
+                Truncated {
+                    expected: c,
+                    found: d,
+                },
+            ) => (a, b) == (c, d),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for BinaryError {}
+
+impl std::error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BinaryError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the octavians in the binary format: the header, then the 8 coefficients of each octavian in little-endian order.
+pub fn write_binary<W: Write, T: BinaryCoefficient>(
+    mut w: W,
+    octavians: &[Octavian<T>],
+) -> io::Result<()> {
+    let mut header = Vec::with_capacity(BINARY_HEADER_LEN);
+    header.extend_from_slice(&BINARY_MAGIC);
+    header.extend_from_slice(&[BINARY_VERSION, T::TAG, 0, 0]);
+    header.extend_from_slice(&(octavians.len() as u64).to_le_bytes());
+    w.write_all(&header)?;
+    let mut buffer = Vec::with_capacity(8 * T::SIZE);
+    for x in octavians {
+        buffer.clear();
+        for c in x.coefficients {
+            c.write_le(&mut buffer);
+        }
+        w.write_all(&buffer)?;
+    }
+    w.flush()
+}
+
+/// Reads octavians written by `write_binary` with the same coefficient type.
+pub fn read_binary<R: Read, T: BinaryCoefficient>(r: R) -> Result<Vec<Octavian<T>>, BinaryError> {
+    read_binary_impl(r, false)
+}
+
+/// Reads octavians written by `write_binary` with the same or a narrower coefficient type, widening each coefficient.
+pub fn read_binary_widening<R: Read, T: BinaryCoefficient>(
+    r: R,
+) -> Result<Vec<Octavian<T>>, BinaryError> {
+    read_binary_impl(r, true)
+}
+
+fn read_binary_impl<R: Read, T: BinaryCoefficient>(
+    mut r: R,
+    widen: bool,
+) -> Result<Vec<Octavian<T>>, BinaryError> {
+    let mut header = [0u8; BINARY_HEADER_LEN];
+    r.read_exact(&mut header).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => BinaryError::BadMagic,
+        _ => BinaryError::Io(e),
+    })?;
+    if header[..4] != BINARY_MAGIC {
+        return Err(BinaryError::BadMagic);
+    }
+    if header[4] != BINARY_VERSION {
+        return Err(BinaryError::UnsupportedVersion(header[4]));
+    }
+    let tag = header[5];
+    let size = tag_size(tag).ok_or(BinaryError::UnknownType(tag))?;
+    if tag != T::TAG && !(widen && size <= T::SIZE) {
+        return Err(BinaryError::TypeMismatch {
+            found: tag,
+            expected: T::TAG,
+        });
+    }
+    let count = u64::from_le_bytes(header[8..].try_into().unwrap());
+    // The count may be corrupted, so only trust it as far as the data actually read.
+    let mut octavians = Vec::with_capacity(count.min(1 << 16) as usize);
+    let mut element = vec![0u8; 8 * size];
+    for found in 0..count {
+        r.read_exact(&mut element).map_err(|e| match e.kind() {
+            io::ErrorKind::UnexpectedEof => BinaryError::Truncated {
+                expected: count,
+                found,
+            },
+            _ => BinaryError::Io(e),
+        })?;
+        let mut chunks = element.chunks_exact(size);
+        let coefficients = core::array::from_fn(|_| {
+            let bytes = chunks.next().unwrap();
+            if tag == T::TAG {
+                T::read_le(bytes)
+            } else {
+                T::from_i128(widen_le(bytes)).expect("a narrower coefficient fits after widening")
+            }
+        });
+        octavians.push(Octavian::new(coefficients));
+    }
+    match r.read(&mut [0u8]) {
+        Ok(0) => Ok(octavians),
+        Ok(_) => Err(BinaryError::TrailingData),
+        Err(e) => Err(BinaryError::Io(e)),
+    }
+}
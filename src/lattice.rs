@@ -0,0 +1,488 @@
+#[cfg(all(feature = "tables", feature = "interval"))]
+use crate::interval::Interval;
+use crate::octavian::Octavian;
+use crate::progress::{Enumeration, Progress};
+use num::rational::Ratio;
+#[cfg(all(feature = "tables", feature = "interval"))]
+use num_traits::FromPrimitive;
+#[cfg(feature = "par")]
+use rayon::prelude::*;
+
+/// Returns the Hermite normal form of the Z-span of the given integer vectors, omitting zero rows.
+/// Each row has a positive leading entry strictly to the right of the leading entry of the row above,
+/// and the entries above each leading entry are reduced into `0..pivot`.
+pub fn hermite_normal_form(vectors: &[[i64; 8]]) -> Vec<[i64; 8]> {
+    let mut rows: Vec<[i64; 8]> = vectors.to_vec();
+    let mut pivot_row = 0;
+    for col in 0..8 {
+        // Euclid's algorithm on the column, using row operations, until a single non-zero entry remains.
+        while let Some(min) = (pivot_row..rows.len())
+            .filter(|&r| rows[r][col] != 0)
+            .min_by_key(|&r| rows[r][col].abs())
+        {
+            rows.swap(pivot_row, min);
+            let pivot = rows[pivot_row];
+            let mut done = true;
+            for row in rows.iter_mut().skip(pivot_row + 1) {
+                let q = row[col] / pivot[col];
+                if q != 0 {
+                    for (x, &p) in row.iter_mut().zip(&pivot) {
+                        *x -= q * p;
+                    }
+                }
+                done &= row[col] == 0;
+            }
+            if done {
+                break;
+            }
+        }
+        if pivot_row == rows.len() || rows[pivot_row][col] == 0 {
+            continue;
+        }
+        if rows[pivot_row][col] < 0 {
+            for x in rows[pivot_row].iter_mut() {
+                *x = -*x;
+            }
+        }
+        let pivot = rows[pivot_row];
+        for row in rows.iter_mut().take(pivot_row) {
+            let q = row[col].div_euclid(pivot[col]);
+            if q != 0 {
+                for (x, &p) in row.iter_mut().zip(&pivot) {
+                    *x -= q * p;
+                }
+            }
+        }
+        pivot_row += 1;
+    }
+    rows.truncate(pivot_row);
+    rows
+}
+
+/// Returns the determinant of an integer matrix, computed with the fraction-free Bareiss algorithm.
+pub fn determinant(matrix: &[[i64; 8]; 8]) -> i128 {
+    let mut a = matrix.map(|row| row.map(i128::from));
+    let mut sign = 1;
+    let mut previous = 1i128;
+    for k in 0..8 {
+        let Some(pivot) = (k..8).find(|&r| a[r][k] != 0) else {
+            return 0;
+        };
+        if pivot != k {
+            a.swap(pivot, k);
+            sign = -sign;
+        }
+        for i in k + 1..8 {
+            for j in k + 1..8 {
+                a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]) / previous;
+            }
+        }
+        previous = a[k][k];
+    }
+    sign * a[7][7]
+}
+
+/// An 8 × 8 integer matrix, acting on row vectors of E8 lattice coordinates.
+pub type Matrix = [[i64; 8]; 8];
+
+/// An 8 × 8 integer matrix under reduction to Smith normal form, with unimodular factors u and v
+/// kept so that u · d · v is the original matrix after every elementary operation on d.
+struct SmithReduction {
+    u: Matrix,
+    d: Matrix,
+    v: Matrix,
+}
+
+impl SmithReduction {
+    /// Adds q times row `from` of d to row `to`, and compensates in the columns of u.
+    fn add_row(&mut self, to: usize, from: usize, q: i64) {
+        let source = self.d[from];
+        for (x, &y) in self.d[to].iter_mut().zip(&source) {
+            *x += q * y;
+        }
+        for row in self.u.iter_mut() {
+            row[from] -= q * row[to];
+        }
+    }
+
+    /// Adds q times column `from` of d to column `to`, and compensates in the rows of v.
+    fn add_column(&mut self, to: usize, from: usize, q: i64) {
+        for row in self.d.iter_mut() {
+            row[to] += q * row[from];
+        }
+        let target = self.v[to];
+        for (x, &y) in self.v[from].iter_mut().zip(&target) {
+            *x -= q * y;
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        self.d.swap(a, b);
+        for row in self.u.iter_mut() {
+            row.swap(a, b);
+        }
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) {
+        for row in self.d.iter_mut() {
+            row.swap(a, b);
+        }
+        self.v.swap(a, b);
+    }
+
+    fn negate_row(&mut self, r: usize) {
+        for x in self.d[r].iter_mut() {
+            *x = -*x;
+        }
+        for row in self.u.iter_mut() {
+            row[r] = -row[r];
+        }
+    }
+}
+
+/// Returns the integer nearest to a / b, so that the remainder has at most half the absolute value of b.
+fn nearest_quotient(a: i64, b: i64) -> i64 {
+    let (q, r) = (a.div_euclid(b), a.rem_euclid(b));
+    if r > b.abs() - r {
+        q + b.signum()
+    } else {
+        q
+    }
+}
+
+/// Returns the Smith normal form of an integer matrix as (U, D, V) with M = U · D · V,
+/// where U and V are unimodular and D is diagonal with non-negative entries d₀ | d₁ | ... | d₇,
+/// the elementary divisors of M, zero entries last.
+/// The entries of D are bounded by those of M, but U and V can have much larger entries, and as in
+/// `hermite_normal_form` no check is made for overflow.
+pub fn smith_normal_form(m: &Matrix) -> (Matrix, Matrix, Matrix) {
+    let identity: Matrix = core::array::from_fn(|i| core::array::from_fn(|j| i64::from(i == j)));
+    let mut s = SmithReduction {
+        u: identity,
+        d: *m,
+        v: identity,
+    };
+    for t in 0..8 {
+        loop {
+            // Move an entry of least absolute value in the remaining block to the pivot.
+            let Some((r, c)) = (t..8)
+                .flat_map(|r| (t..8).map(move |c| (r, c)))
+                .filter(|&(r, c)| s.d[r][c] != 0)
+                .min_by_key(|&(r, c)| s.d[r][c].abs())
+            else {
+                return (s.u, s.d, s.v);
+            };
+            s.swap_rows(t, r);
+            s.swap_columns(t, c);
+            let pivot = s.d[t][t];
+            for r in t + 1..8 {
+                s.add_row(r, t, -nearest_quotient(s.d[r][t], pivot));
+            }
+            for c in t + 1..8 {
+                s.add_column(c, t, -nearest_quotient(s.d[t][c], pivot));
+            }
+            if (t + 1..8).any(|i| s.d[i][t] != 0 || s.d[t][i] != 0) {
+                // A remainder smaller than the pivot is left, and becomes the next pivot.
+                continue;
+            }
+            // The pivot must divide the rest of the block, and otherwise a row holding a non-multiple is added to its row.
+            match (t + 1..8).find(|&r| s.d[r][t + 1..].iter().any(|&x| x % pivot != 0)) {
+                Some(r) => s.add_row(t, r, 1),
+                None => break,
+            }
+        }
+        if s.d[t][t] < 0 {
+            s.negate_row(t);
+        }
+    }
+    (s.u, s.d, s.v)
+}
+
+/// Returns the elementary divisors d₀ | d₁ | ... | d₇ of the sublattice of E8 spanned by the generators,
+/// or `None` if they do not span a sublattice of full rank.
+/// The quotient E8 / L is then the product of the cyclic groups Z / dᵢ.
+pub fn elementary_divisors(generators: &[Octavian<i64>]) -> Option<[i64; 8]> {
+    let rows: Vec<[i64; 8]> = generators.iter().map(|x| x.coefficients).collect();
+    // The Hermite normal form is a square basis of the span exactly when it has full rank.
+    let basis: [[i64; 8]; 8] = hermite_normal_form(&rows).try_into().ok()?;
+    let (_, d, _) = smith_normal_form(&basis);
+    Some(core::array::from_fn(|i| d[i][i]))
+}
+
+/// Returns the index in E8 of the sublattice spanned by the generators, the product of its elementary divisors,
+/// or `None` if they do not span a sublattice of full rank or the index does not fit in a `u64`.
+pub fn sublattice_index(generators: &[Octavian<i64>]) -> Option<u64> {
+    elementary_divisors(generators)?
+        .iter()
+        .try_fold(1u64, |index, &d| index.checked_mul(d as u64))
+}
+
+/// The matrix converting doubled octonion coordinates into doubled E8 lattice coordinates.
+/// Entry [i][j] is the inner product of the i-th octonion basis vector with the j-th dual basis vector,
+/// so that the E8 lattice coordinates of v/2 are (v·M)/2.
+fn halving_matrix() -> [[i64; 8]; 8] {
+    let dual = Octavian::<Ratio<i64>>::dual_basis();
+    Octavian::<i64>::OCTONION_BASIS.map(|row| {
+        let e = Octavian::new(row.map(|x| Ratio::from(i64::from(x))));
+        dual.map(|d| d.inner_product(&e).to_integer())
+    })
+}
+
+/// Visits every octavian v/2 such that the integer vector v has first coordinate `first`
+/// and squares summing to exactly `total`, or to at most `total` if `exact` is false.
+/// The visitor receives the octavian together with the sum of squares of v, which is four times its norm.
+///
+/// The octonion basis is orthonormal, so the Fincke–Pohst bounds reduce to |v_i|² ≤ the remaining budget,
+/// and on a sphere the last coordinate is determined by the others up to sign.
+/// The doubled lattice coordinates v·M are accumulated along the recursion, so each leaf only checks their parity.
+fn enumerate_with_first(
+    first: i64,
+    total: i64,
+    exact: bool,
+    visit: &mut impl FnMut(Octavian<i64>, i64),
+) {
+    struct Search<'a, F> {
+        matrix: [[i64; 8]; 8],
+        total: i64,
+        exact: bool,
+        visit: &'a mut F,
+    }
+
+    impl<F: FnMut(Octavian<i64>, i64)> Search<'_, F> {
+        fn leaf(&mut self, twice: [i64; 8], remaining: i64) {
+            if twice.iter().all(|t| t % 2 == 0) {
+                (self.visit)(Octavian::new(twice.map(|t| t / 2)), self.total - remaining);
+            }
+        }
+
+        fn extend(&mut self, depth: usize, twice: [i64; 8], remaining: i64) {
+            let bound = remaining.isqrt();
+            let row = self.matrix[depth];
+            let step = |x: i64| core::array::from_fn(|j| twice[j] + x * row[j]);
+            if depth == 7 && self.exact {
+                if bound * bound == remaining {
+                    self.leaf(step(bound), 0);
+                    if bound != 0 {
+                        self.leaf(step(-bound), 0);
+                    }
+                }
+                return;
+            }
+            for x in -bound..=bound {
+                let next: [i64; 8] = step(x);
+                if depth == 7 {
+                    self.leaf(next, remaining - x * x);
+                } else {
+                    self.extend(depth + 1, next, remaining - x * x);
+                }
+            }
+        }
+    }
+
+    let remaining = total - first * first;
+    if remaining < 0 {
+        return;
+    }
+    let matrix = halving_matrix();
+    let twice = matrix[0].map(|m| first * m);
+    Search {
+        matrix,
+        total,
+        exact,
+        visit,
+    }
+    .extend(1, twice, remaining);
+}
+
+/// Returns the octavians of norm `n` whose doubled first octonion coordinate is `first`.
+fn norm_shell_with_first(n: i64, first: i64) -> Vec<Octavian<i64>> {
+    let mut shell = Vec::new();
+    enumerate_with_first(first, 4 * n, true, &mut |x, _| shell.push(x));
+    shell
+}
+
+/// Adds the number of octavians of each norm up to `up_to` with doubled first octonion coordinate `first` to `counts`.
+fn count_theta_with_first(up_to: i64, first: i64, counts: &mut [u64]) {
+    enumerate_with_first(first, 4 * up_to, false, &mut |_, squares| {
+        counts[(squares / 4) as usize] += 1;
+    });
+}
+
+/// Returns the octavians of norm `n` in increasing order of their coefficients.
+/// Twice an octavian has integer coordinates in the standard octonion basis with squares summing to 4n,
+/// so those integer vectors are enumerated and the ones that halve to octavians are kept.
+pub fn norm_shell(n: i64) -> Vec<Octavian<i64>> {
+    if n < 0 {
+        return Vec::new();
+    }
+    let bound = (4 * n).isqrt();
+    let mut shell: Vec<Octavian<i64>> = (-bound..=bound)
+        .flat_map(|first| norm_shell_with_first(n, first))
+        .collect();
+    shell.sort_unstable_by_key(|x| x.coefficients);
+    shell
+}
+
+/// Returns the octavians of norm `n` like `norm_shell`, splitting the enumeration on its first coordinate across rayon tasks.
+/// The per-task results are concatenated and sorted, so the output is identical to `norm_shell`.
+#[cfg(feature = "par")]
+pub fn norm_shell_par(n: i64) -> Vec<Octavian<i64>> {
+    if n < 0 {
+        return Vec::new();
+    }
+    let bound = (4 * n).isqrt();
+    let mut shell: Vec<Octavian<i64>> = (-bound..=bound)
+        .into_par_iter()
+        .flat_map_iter(|first| norm_shell_with_first(n, first))
+        .collect();
+    shell.sort_unstable_by_key(|x| x.coefficients);
+    shell
+}
+
+/// Returns the octavians of norm at most `n` in increasing order of their coefficients.
+pub fn enumerate_norm_at_most(n: i64) -> Vec<Octavian<i64>> {
+    enumerate_norm_at_most_with(n, &()).elements
+}
+
+/// Returns the octavians of norm at most `n` like `enumerate_norm_at_most`, reporting the number found
+/// after each value of the first octonion coordinate and stopping early if `progress` breaks.
+/// The elements found before stopping are still sorted.
+pub fn enumerate_norm_at_most_with(n: i64, progress: &impl Progress) -> Enumeration<Octavian<i64>> {
+    let mut elements = Vec::new();
+    let mut complete = true;
+    let bound = (4 * n.max(0)).isqrt();
+    if n >= 0 {
+        for first in -bound..=bound {
+            enumerate_with_first(first, 4 * n, false, &mut |x, _| elements.push(x));
+            if progress.report(elements.len() as u64, None).is_break() && first < bound {
+                complete = false;
+                break;
+            }
+        }
+    }
+    elements.sort_unstable_by_key(|x| x.coefficients);
+    Enumeration { elements, complete }
+}
+
+/// Returns the coefficients of the theta series of E8 up to `q^up_to`, the numbers of octavians of norm 0, 1, ..., up_to.
+pub fn theta_coefficients(up_to: usize) -> Vec<u64> {
+    let total = 4 * up_to as i64;
+    let mut counts = vec![0; up_to + 1];
+    for first in -total.isqrt()..=total.isqrt() {
+        count_theta_with_first(up_to as i64, first, &mut counts);
+    }
+    counts
+}
+
+/// Returns the theta coefficients like `theta_coefficients`, with one count buffer per rayon task summed at the end.
+#[cfg(feature = "par")]
+pub fn theta_coefficients_par(up_to: usize) -> Vec<u64> {
+    let total = 4 * up_to as i64;
+    (-total.isqrt()..=total.isqrt())
+        .into_par_iter()
+        .map(|first| {
+            let mut counts = vec![0; up_to + 1];
+            count_theta_with_first(up_to as i64, first, &mut counts);
+            counts
+        })
+        .reduce(
+            || vec![0; up_to + 1],
+            |a, b| a.iter().zip(&b).map(|(x, y)| x + y).collect(),
+        )
+}
+
+/// Returns an octavian nearest to the point `x` of E8 ⊗ R, given in E8 lattice coordinates,
+/// or `None` if a coordinate is not finite or does not fit in an `i64`.
+/// The Voronoi cell of E8 is cut out by the 240 roots alone, so starting from the rounded coordinates and moving
+/// by a root while that strictly decreases the distance ends at a nearest lattice point, within norm 1/2 of `x`.
+#[cfg(feature = "tables")]
+pub fn closest_octavian(x: &Octavian<f64>) -> Option<Octavian<i64>> {
+    const LIMIT: f64 = 9.2e18;
+    if x.coefficients
+        .iter()
+        .any(|c| !c.is_finite() || c.abs() > LIMIT)
+    {
+        return None;
+    }
+    let mut closest = Octavian::new(x.coefficients.map(|c| c.round() as i64));
+    loop {
+        let difference = *x - Octavian::new(closest.coefficients.map(|c| c as f64));
+        // Moving by a root r changes the norm of the difference by 1 - <difference, r>.
+        let (best, product) = Octavian::UNITS
+            .iter()
+            .map(|u| {
+                let r = Octavian::new(u.coefficients.map(f64::from));
+                (u, difference.inner_product(&r))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("there are 240 units");
+        if product <= 1.0 + 1e-9 {
+            return Some(closest);
+        }
+        closest += Octavian::new(best.coefficients.map(i64::from));
+    }
+}
+
+/// Returns, in increasing order of their coefficients, octavians including every octavian nearest to some point
+/// of the box `x` of E8 ⊗ R, whose coordinates in E8 lattice coordinates are intervals.
+/// Returns `None` if an endpoint is not finite or does not fit in an `i64`, or if the exact inner product of
+/// a candidate with itself overflows an `i128`.
+///
+/// With c = `closest_octavian` of the midpoint and D an upper bound on the distance from the box to c, evaluated in
+/// interval arithmetic, the nearest octavian p to a point of the box is within D of it and so within 2D of c.
+/// Those candidates are kept when their distance to the box can be at most D,
+/// and when no other candidate is strictly closer on the whole box, which is linear in the point.
+/// For a point interval the result is the octavians nearest to it, up to the rounding of the enclosures,
+/// so it is usually `closest_octavian` alone. The work grows with the width of the box.
+#[cfg(all(feature = "tables", feature = "interval"))]
+pub fn closest_octavian_candidates(x: &Octavian<Interval>) -> Option<Vec<Octavian<i64>>> {
+    const LIMIT: f64 = 9.2e18;
+    if x.coefficients
+        .iter()
+        .any(|c| !(c.lo().abs() <= LIMIT && c.hi().abs() <= LIMIT))
+    {
+        return None;
+    }
+    let to_interval = |p: &Octavian<i64>| {
+        Octavian::new(
+            p.coefficients
+                .map(|c| Interval::from_i64(c).expect("an i64 converts")),
+        )
+    };
+    let midpoint = Octavian::new(x.coefficients.map(|c| c.midpoint()));
+    let center = closest_octavian(&midpoint)?;
+    let squared_distance = |p: &Octavian<i64>| {
+        let difference = *x - to_interval(p);
+        difference.inner_product(&difference)
+    };
+    let bound = squared_distance(&center).hi();
+    // The candidates p satisfy <p - c, p - c> <= 4 D², so N(p - c) <= 2 D².
+    let near: Vec<Octavian<i64>> = enumerate_norm_at_most((2.0 * bound).floor() as i64)
+        .into_iter()
+        .map(|v| center + v)
+        .filter(|p| squared_distance(p).lo() <= bound)
+        .collect();
+    // <x - p, x - p> - <x - q, x - q> = 2 <x, q - p> + <p, p> - <q, q>, positive when q is strictly closer.
+    // The inner products <p, p> and their differences are exact, and give `None` if they do not fit in i128.
+    let norms = near
+        .iter()
+        .map(|p| p.checked_inner_product_in::<i128>(p))
+        .collect::<Option<Vec<i128>>>()?;
+    let mut candidates = Vec::new();
+    for (p, &p_norm) in near.iter().zip(&norms) {
+        let mut kept = true;
+        for (q, &q_norm) in near.iter().zip(&norms) {
+            let excess = Interval::point(2.0) * x.inner_product(&to_interval(&(*q - *p)))
+                + Interval::from_i128(p_norm.checked_sub(q_norm)?).expect("an i128 converts");
+            if excess.lo() > 0.0 {
+                kept = false;
+                break;
+            }
+        }
+        if kept {
+            candidates.push(*p);
+        }
+    }
+    candidates.sort_unstable_by_key(|p| p.coefficients);
+    Some(candidates)
+}
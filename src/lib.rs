@@ -1,4 +1,63 @@
+#[cfg(feature = "ndarray")]
+pub mod arrays;
+pub mod basis;
+pub mod batch;
+#[cfg(feature = "tables")]
+pub mod cayley;
+pub mod cayley_dickson;
+pub mod closure;
+pub mod compact;
+pub mod compare;
+pub mod dedup;
+mod definitions;
+#[cfg(feature = "dual")]
+pub mod dual;
+pub mod error;
+pub mod export;
+pub mod factor;
+pub mod fano;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod finite_field;
+pub mod format;
+#[cfg(feature = "interval")]
+pub mod interval;
+pub mod invariants;
+pub mod io;
+pub mod lattice;
+#[cfg(feature = "nalgebra")]
+pub mod linalg;
 pub mod octavian;
+pub mod octonion;
+#[cfg(feature = "tables")]
+pub mod polytope;
+pub mod prelude;
+pub mod pretty;
+pub mod progress;
+pub mod quaternion;
+#[cfg(feature = "serde")]
+pub mod repr;
+#[cfg(feature = "tables")]
+pub mod root_graph;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod slice;
+pub mod subalgebra;
+#[cfg(feature = "symbolic")]
+pub mod symbolic;
+pub mod tables;
+#[cfg(feature = "tables")]
+pub mod units;
+pub mod verify;
+pub mod view;
+#[cfg(feature = "tables")]
+pub mod walk;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "tables")]
+pub mod weyl;
+
+pub use octavian::Octavian;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,113 @@
+//! Conversions between octavians and `nalgebra` vectors and matrices, so that the adjoint and Gram matrices
+//! can be handed to nalgebra's decompositions directly, and between quaternions and `nalgebra` quaternions,
+//! so that existing quaternion pipelines can be carried into a quaternion subalgebra of the octonions.
+
+use crate::octavian::{self, Coefficient, Octavian};
+use crate::quaternion::{HurwitzQuaternion, QuaternionEmbedding};
+use crate::tables;
+use core::ops::Neg;
+use nalgebra::{Quaternion, SMatrix, SVector, Scalar, UnitQuaternion};
+use num_traits::{FromPrimitive, Num};
+
+impl<T> From<Octavian<T>> for SVector<T, 8>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Scalar,
+{
+    fn from(x: Octavian<T>) -> Self {
+        SVector::from(x.coefficients)
+    }
+}
+
+impl<T> From<SVector<T, 8>> for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Scalar + Coefficient,
+{
+    fn from(v: SVector<T, 8>) -> Self {
+        Octavian::new(v.into())
+    }
+}
+
+/// Converts a nested array, indexed by row then column, into a matrix.
+fn to_matrix<T: Scalar + Copy>(m: &[[T; 8]; 8]) -> SMatrix<T, 8, 8> {
+    SMatrix::from_fn(|i, j| m[i][j])
+}
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Scalar + Coefficient,
+{
+    /// Returns the left adjoint matrix as an nalgebra matrix, so that `apply_matrix(&a.left_adjoint_matrix_na(), &b)` is a * b.
+    pub fn left_adjoint_matrix_na(&self) -> SMatrix<T, 8, 8> {
+        to_matrix(&self.left_adjoint_matrix())
+    }
+
+    /// Returns the Gram matrix of the E8 lattice coordinates as an nalgebra matrix.
+    pub fn gram_matrix_na() -> SMatrix<T, 8, 8> {
+        to_matrix(&tables::gram_matrix_typed())
+    }
+}
+
+/// Multiplies the coefficient vector of an octavian by a matrix on the left.
+pub fn apply_matrix<T>(m: &SMatrix<T, 8, 8>, x: &Octavian<T>) -> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Scalar + Coefficient,
+{
+    let rows = core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)]));
+    Octavian::new(octavian::apply_matrix(&rows, &x.coefficients))
+}
+
+impl From<HurwitzQuaternion<f64>> for Quaternion<f64> {
+    fn from(q: HurwitzQuaternion<f64>) -> Self {
+        let [w, i, j, k] = q.coefficients;
+        Quaternion::new(w, i, j, k)
+    }
+}
+
+impl From<Quaternion<f64>> for HurwitzQuaternion<f64> {
+    fn from(q: Quaternion<f64>) -> Self {
+        HurwitzQuaternion::new([q.w, q.i, q.j, q.k])
+    }
+}
+
+/// Returns the image of a quaternion in the quaternion subalgebra given by the embedding.
+pub fn octavian_from_quaternion(
+    q: &Quaternion<f64>,
+    embedding: &QuaternionEmbedding,
+) -> Octavian<f64> {
+    let mut coefficients = [0.0; 8];
+    for (b, c) in embedding.basis().iter().zip([q.w, q.i, q.j, q.k]) {
+        for (x, &y) in coefficients.iter_mut().zip(&b.coefficients) {
+            *x += c * y as f64;
+        }
+    }
+    Octavian::new(coefficients)
+}
+
+/// Returns the image of a unit quaternion, which is an octonion of norm 1 in the quaternion subalgebra.
+pub fn octavian_from_unit_quaternion(
+    q: &UnitQuaternion<f64>,
+    embedding: &QuaternionEmbedding,
+) -> Octavian<f64> {
+    octavian_from_quaternion(q.quaternion(), embedding)
+}
+
+/// Returns the orthogonal projection of an octonion onto the quaternion subalgebra, in quaternion coordinates.
+pub fn quaternion_from_octavian(
+    x: &Octavian<f64>,
+    embedding: &QuaternionEmbedding,
+) -> Quaternion<f64> {
+    // The basis is orthonormal with respect to the octonion norm, which is half the E8 inner product.
+    let [w, i, j, k] = embedding
+        .basis()
+        .map(|b| x.inner_product(&Octavian::new(b.coefficients.map(|c| c as f64))) / 2.0);
+    Quaternion::new(w, i, j, k)
+}
+
+/// Returns the projection of an octonion onto the quaternion subalgebra, normalized to a unit quaternion,
+/// or `None` if the projection vanishes.
+pub fn unit_quaternion_from_octavian(
+    x: &Octavian<f64>,
+    embedding: &QuaternionEmbedding,
+) -> Option<UnitQuaternion<f64>> {
+    UnitQuaternion::try_new(quaternion_from_octavian(x, embedding), 0.0)
+}
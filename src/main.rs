@@ -1,3 +1,270 @@
-fn main() {
-    println!("This is the alco-rs crate, which includes an implementation of Octavians (the 8-dimensional unital normed division algebra).");
-}
\ No newline at end of file
+//! A small command-line interface to the octavian integers.
+//! Elements are written as their 8 E8 lattice coordinates separated by commas, such as `1,0,0,0,0,0,0,0`,
+//! or as a sum of basis terms in octonion notation, such as `1/2 + (1/2)e1 + (1/2)e2 + (1/2)e3`, which `FromStr` parses.
+
+use alco_rs::closure::multiplicative_closure;
+#[cfg(feature = "tables")]
+use alco_rs::export::gap;
+use alco_rs::factor::factor;
+use alco_rs::octavian::Octavian;
+use alco_rs::{io, lattice};
+use std::io::{BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+This is the alco-rs crate, which includes an implementation of Octavians (the 8-dimensional unital normed division algebra).
+
+Usage: alco-rs <command> [arguments]
+
+Elements are given as 8 comma-separated E8 lattice coordinates, such as 1,0,0,0,0,0,0,0,
+or as a sum in the octonion basis 1, e1, ..., e7 or the lattice basis b0, ..., b7,
+such as 1+e1, 1/2+(1/2)e1+(1/2)e2+(1/2)e3 or 2b3+b7.
+
+Commands:
+  mul A B                          print the product A * B
+  norm A                           print the norm of A
+  conj A                           print the conjugate of A
+  factor A                         print prime octavians whose product, nested to the right, is A
+  units [--format csv|gap]         print the 240 units
+  shell N                          print the octavians of norm N
+  closure --generators FILE [--cap N]
+                                   print the multiplicative closure of the elements in a CSV file
+  help                             print this message
+
+Batch mode:
+  alco-rs --stdin [--output plain|csv]
+      reads one command with its arguments per line of standard input and prints each result on standard output,
+      with coordinates separated by spaces (plain, the default) or commas (csv). Blank lines are skipped,
+      and a line that fails is reported on standard error with its line number without stopping the batch.";
+
+/// The default number of elements after which `closure` stops.
+const DEFAULT_CAP: usize = 100_000;
+
+/// Parses an element from its comma-separated coordinates or a sum in octonion notation.
+fn parse_element(s: &str) -> Result<Octavian<i64>, String> {
+    s.parse()
+        .map_err(|e| format!("could not parse element {:?}: {}", s, e))
+}
+
+/// Widens an element to 128-bit coordinates, in which the CLI computes products and conjugates.
+fn widen(x: Octavian<i64>) -> Octavian<i128> {
+    Octavian::new(x.coefficients.map(i128::from))
+}
+
+/// Narrows a result back to 64-bit coordinates, reporting the command that overflowed.
+fn narrow(command: &str, x: Octavian<i128>) -> Result<Octavian<i64>, String> {
+    let mut coefficients = [0; 8];
+    for (c, &w) in coefficients.iter_mut().zip(&x.coefficients) {
+        *c = i64::try_from(w).map_err(|_| format!("the {} does not fit in 64 bits", command))?;
+    }
+    Ok(Octavian::new(coefficients))
+}
+
+/// Formats an element as its coordinates joined by `separator`.
+fn format_element(x: &Octavian<i64>, separator: &str) -> String {
+    let fields: Vec<String> = x.coefficients.iter().map(|c| c.to_string()).collect();
+    fields.join(separator)
+}
+
+/// Formats a list of elements one per line, with their coordinates joined by `separator`.
+fn format_list(elements: &[Octavian<i64>], separator: &str) -> String {
+    let lines: Vec<String> = elements
+        .iter()
+        .map(|x| format_element(x, separator))
+        .collect();
+    lines.join("\n")
+}
+
+/// Returns the arguments after the command, checking that there are exactly `n` of them.
+fn expect_args<'a>(command: &str, args: &'a [String], n: usize) -> Result<&'a [String], String> {
+    if args.len() != n {
+        return Err(format!(
+            "{} expects {} argument{}, found {}",
+            command,
+            n,
+            if n == 1 { "" } else { "s" },
+            args.len()
+        ));
+    }
+    Ok(args)
+}
+
+/// Splits `--name value` options from the arguments, rejecting unknown options and missing values.
+fn parse_options<'a>(
+    command: &str,
+    args: &'a [String],
+    names: &[&str],
+) -> Result<Vec<(&'a str, &'a str)>, String> {
+    let mut options = Vec::new();
+    let mut rest = args.iter();
+    while let Some(name) = rest.next() {
+        if !names.contains(&name.as_str()) {
+            return Err(format!("{} does not accept {:?}", command, name));
+        }
+        let value = rest
+            .next()
+            .ok_or_else(|| format!("{} needs a value after {}", command, name))?;
+        options.push((name.as_str(), value.as_str()));
+    }
+    Ok(options)
+}
+
+/// Runs a command and returns its output, with coordinates joined by `separator`, or an error message.
+fn run(args: &[String], separator: &str) -> Result<String, String> {
+    let Some((command, args)) = args.split_first() else {
+        return Ok(USAGE.to_string());
+    };
+    match command.as_str() {
+        "mul" => {
+            let args = expect_args(command, args, 2)?;
+            let (a, b) = (parse_element(&args[0])?, parse_element(&args[1])?);
+            let product = widen(a)
+                .checked_mul(&widen(b))
+                .ok_or("the product does not fit in 128 bits")?;
+            Ok(format_element(&narrow("product", product)?, separator))
+        }
+        "norm" => {
+            let args = expect_args(command, args, 1)?;
+            let norm = parse_element(&args[0])?
+                .norm_i128()
+                .map_err(|e| format!("could not compute the norm: {}", e))?;
+            Ok(norm.to_string())
+        }
+        "conj" => {
+            let args = expect_args(command, args, 1)?;
+            // Coordinates below 2^64 keep the trace and conjugate far inside 128 bits.
+            let conjugate = widen(parse_element(&args[0])?).conjugate();
+            Ok(format_element(&narrow("conjugate", conjugate)?, separator))
+        }
+        "factor" => {
+            let args = expect_args(command, args, 1)?;
+            let factors = factor(&parse_element(&args[0])?)
+                .map_err(|e| format!("could not factor: {}", e))?;
+            Ok(format_list(&factors, separator))
+        }
+        #[cfg(feature = "tables")]
+        "units" => {
+            let units: Vec<Octavian<i64>> = Octavian::UNITS
+                .iter()
+                .map(|u| Octavian::new(u.coefficients.map(i64::from)))
+                .collect();
+            let mut format = "csv";
+            for (_, value) in parse_options(command, args, &["--format"])? {
+                format = value;
+            }
+            match format {
+                "csv" => Ok(format_list(&units, separator)),
+                "gap" => Ok(gap::gap_octavian_list(&units)),
+                _ => Err(format!("unknown format {:?}, expected csv or gap", format)),
+            }
+        }
+        "shell" => {
+            let args = expect_args(command, args, 1)?;
+            let n: i64 = args[0]
+                .parse()
+                .map_err(|_| format!("could not parse norm {:?}: not an integer", args[0]))?;
+            Ok(format_list(&lattice::norm_shell(n), separator))
+        }
+        "closure" => {
+            let mut file = None;
+            let mut cap = DEFAULT_CAP;
+            for (name, value) in parse_options(command, args, &["--generators", "--cap"])? {
+                match name {
+                    "--generators" => file = Some(value),
+                    _ => {
+                        cap = value
+                            .parse()
+                            .map_err(|_| format!("could not parse cap {:?}", value))?
+                    }
+                }
+            }
+            let file = file.ok_or("closure needs --generators FILE")?;
+            let contents =
+                std::fs::read(file).map_err(|e| format!("could not read {}: {}", file, e))?;
+            let generators = io::read_csv(contents.as_slice())
+                .map_err(|e| format!("could not read {}: {}", file, e))?;
+            let closure = multiplicative_closure(&generators, cap);
+            if !closure.stabilized {
+                return Err(format!(
+                    "the closure has more than {} elements; raise --cap to compute it",
+                    cap
+                ));
+            }
+            Ok(format_list(&closure.elements, separator))
+        }
+        "help" | "--help" | "-h" => Ok(USAGE.to_string()),
+        #[cfg(not(feature = "tables"))]
+        "units" => Err("the units command needs the tables feature".to_string()),
+        _ => Err(format!("unknown command {:?}; run help for usage", command)),
+    }
+}
+
+/// Runs the commands read from standard input one line at a time, streaming the results to standard output.
+/// Returns whether every line succeeded, or an error if the options or the streams themselves fail.
+fn run_stdin(args: &[String]) -> Result<bool, String> {
+    let mut separator = " ";
+    for (_, value) in parse_options("--stdin", args, &["--output"])? {
+        separator = match value {
+            "plain" => " ",
+            "csv" => ",",
+            _ => return Err(format!("unknown output {:?}, expected plain or csv", value)),
+        };
+    }
+    // A panic on one line is reported like any other error below, so the default message is silenced.
+    std::panic::set_hook(Box::new(|_| {}));
+    let stdout = std::io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut succeeded = true;
+    for (i, line) in std::io::stdin().lock().lines().enumerate() {
+        let line = line.map_err(|e| format!("could not read line {}: {}", i + 1, e))?;
+        let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+        if args.is_empty() {
+            continue;
+        }
+        let result = std::panic::catch_unwind(|| run(&args, separator)).unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|m| m.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("internal error: {}", message))
+        });
+        match result {
+            Ok(output) => writeln!(out, "{}", output),
+            Err(message) => {
+                succeeded = false;
+                // Flush first, so that the error appears after the results of the earlier lines.
+                out.flush()
+                    .and_then(|_| writeln!(std::io::stderr(), "error: line {}: {}", i + 1, message))
+            }
+        }
+        .map_err(|e| format!("could not write output: {}", e))?;
+    }
+    out.flush()
+        .map_err(|e| format!("could not write output: {}", e))?;
+    Ok(succeeded)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|a| a == "--stdin") {
+        return match run_stdin(&args[1..]) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(message) => {
+                eprintln!("error: {}", message);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    match run(&args, ",") {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
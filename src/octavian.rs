@@ -1,10 +1,142 @@
 // use num::integer;
-use core::ops::{Add, Mul, Neg, Sub};
-use num_traits::{FromPrimitive, Num};
+use crate::definitions;
+use crate::error::AlcoError;
+use crate::tables;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use num::complex::Complex;
+use num::rational::Ratio;
+use num_traits::{CheckedAdd, CheckedMul, FromPrimitive, Inv, Num, One, ToPrimitive, Zero};
 use std::fmt::Debug;
+use std::sync::OnceLock;
+
+/// Converts a small table entry into the coefficient type.
+/// Every signed numeric type can represent the entries of the constant tables, so this never fails in practice.
+pub(crate) fn from_i8<T: FromPrimitive>(x: i8) -> T {
+    T::from_i8(x).expect("the coefficient type cannot represent a small integer")
+}
+
+/// Keeps `Coefficient` from being implemented outside the crate.
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// A coefficient type of `Octavian`, carrying the behaviour that differs between coefficient types.
+/// The primitive integer and floating-point types implement it through `impl_coefficient!`; the other coefficient
+/// types of the crate, such as `Ratio`, take the generic defaults. The trait is sealed.
+pub trait Coefficient: FromPrimitive + Num + Copy + Neg<Output = Self> + sealed::Sealed {
+    /// Whether `with_adjoint_matrices` and `with_gram_matrix` read a table converted once and cached, rather than
+    /// converting it on every call.
+    const CACHED_TABLES: bool = false;
+
+    /// Runs `f` on the left adjoint matrices converted to `Self`, for `tables::with_adjoint_matrices`.
+    fn with_adjoint_matrices<R>(f: impl FnOnce(&tables::AdjointMatrices<Self>) -> R) -> R {
+        f(&tables::convert_adjoint_matrices())
+    }
+
+    /// Runs `f` on the Gram matrix converted to `Self`, for `tables::with_gram_matrix`.
+    fn with_gram_matrix<R>(f: impl FnOnce(&tables::GramMatrix<Self>) -> R) -> R {
+        f(&tables::convert_gram_matrix())
+    }
+
+    /// Returns the multiplicative identity, converting the coefficients of `one()` into `Self`.
+    fn one_octavian() -> Octavian<Self> {
+        Octavian::new(ONE_COEFFICIENTS.map(from_i8))
+    }
+
+    /// Returns the quotient of `checked_div_left` or, if `left` is false, `checked_div_right`.
+    fn checked_div_octavian(
+        x: &Octavian<Self>,
+        divisor: &Octavian<Self>,
+        left: bool,
+    ) -> Option<Octavian<Self>> {
+        x.div_by_conjugate(divisor, left)
+    }
+
+    /// Tests whether `x * y` can take the vectorized dense path: `Self` has a kernel in `simd`,
+    /// and the factors are small enough that no partial sum overflows.
+    #[cfg(feature = "simd")]
+    fn simd_mul_fits(_x: &[Self; 8], _y: &[Self; 8]) -> bool {
+        false
+    }
+
+    /// Adds two coefficient vectors with the kernel, or returns `None` if there is none or the sum could overflow.
+    #[cfg(feature = "simd")]
+    fn simd_add(_x: &[Self; 8], _y: &[Self; 8]) -> Option<[Self; 8]> {
+        None
+    }
+
+    /// Multiplies a coefficient vector by a matrix with the kernel, like `simd_add`.
+    #[cfg(feature = "simd")]
+    fn simd_matvec(_m: &[[Self; 8]; 8], _x: &[Self; 8]) -> Option<[Self; 8]> {
+        None
+    }
+
+    /// Returns the E8 inner product of two coefficient vectors with the kernel, like `simd_add`.
+    #[cfg(feature = "simd")]
+    fn simd_inner_product(_x: &[Self; 8], _y: &[Self; 8]) -> Option<Self> {
+        None
+    }
+
+    /// Writes the product `x * y` into `out`, for `Octavian::mul_into`.
+    fn mul_octavian_into(x: &Octavian<Self>, y: &Octavian<Self>, out: &mut Octavian<Self>) {
+        x.mul_by_path_into(y, out);
+    }
+}
+
+impl<T> sealed::Sealed for Ratio<T> {}
+
+impl<T> Coefficient for Ratio<T> where Ratio<T>: FromPrimitive + Num + Copy + Neg<Output = Ratio<T>> {}
+
+/// Returns the inner product of two coefficient vectors, so that borrowed views can share the owned implementation.
+pub(crate) fn inner_product_of<T: Coefficient>(x: &[T; 8], y: &[T; 8]) -> T {
+    #[cfg(feature = "simd")]
+    if let Some(p) = T::simd_inner_product(x, y) {
+        return p;
+    }
+    // From the Gram Matrix and Dynkin diagram for E8, addition and subtraction alternate to reduce chance of overflow.
+    x[0] * y[0] + x[0] * y[0] - x[0] * y[2] - x[2] * y[0] + x[1] * y[1] + x[1] * y[1]
+        - x[1] * y[3]
+        - x[3] * y[1]
+        + x[2] * y[2]
+        + x[2] * y[2]
+        - x[2] * y[3]
+        - x[3] * y[2]
+        + x[3] * y[3]
+        + x[3] * y[3]
+        - x[3] * y[4]
+        - x[4] * y[3]
+        + x[4] * y[4]
+        + x[4] * y[4]
+        - x[4] * y[5]
+        - x[5] * y[4]
+        + x[5] * y[5]
+        + x[5] * y[5]
+        - x[5] * y[6]
+        - x[6] * y[5]
+        + x[6] * y[6]
+        + x[6] * y[6]
+        - x[6] * y[7]
+        - x[7] * y[6]
+        + x[7] * y[7]
+        + x[7] * y[7]
+}
+
+/// The coefficients of `one()`.
+const ONE_COEFFICIENTS: [i8; 8] = [-2, -3, -4, -6, -5, -4, -3, -2];
+
+/// The tables generated by `build.rs`.
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+}
 
 /// The octavian integers are defined in Conway and Smith's book, [On Quaternions and Octonions](https://www.routledge.com/On-Quaternions-and-Octonions/Conway-Smith/p/book/9781568811345), and elsewhere.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Octavian<T>
 where
     T: FromPrimitive + Num + Copy + Neg<Output = T>,
@@ -15,13 +147,11 @@ where
 
 impl<T> Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     /// Create a new `Octavian`.
     pub const fn new(coefficients: [T; 8]) -> Self {
-        Octavian {
-            coefficients,
-        }
+        Octavian { coefficients }
     }
 
     /// Returns the trace of an octavian.
@@ -32,40 +162,89 @@ where
 
     /// Returns the inner product of two octavians.
     pub fn inner_product(&self, rhs: &Octavian<T>) -> T {
-        let x = self.coefficients;
-        let y = rhs.coefficients;
-        // From the Gram Matrix and Dynkin diagram for E8, addition and subtraction alternate to reduce chance of overflow.
-        x[0] * y[0] + x[0] * y[0] - x[0] * y[2] - x[2] * y[0] + x[1] * y[1] + x[1] * y[1]
-            - x[1] * y[3]
-            - x[3] * y[1]
-            + x[2] * y[2]
-            + x[2] * y[2]
-            - x[2] * y[3]
-            - x[3] * y[2]
-            + x[3] * y[3]
-            + x[3] * y[3]
-            - x[3] * y[4]
-            - x[4] * y[3]
-            + x[4] * y[4]
-            + x[4] * y[4]
-            - x[4] * y[5]
-            - x[5] * y[4]
-            + x[5] * y[5]
-            + x[5] * y[5]
-            - x[5] * y[6]
-            - x[6] * y[5]
-            + x[6] * y[6]
-            + x[6] * y[6]
-            - x[6] * y[7]
-            - x[7] * y[6]
-            + x[7] * y[7]
-            + x[7] * y[7]
-    }
-
-    /// Returns the norm of an octavian scaled to the E8 lattice.
-    /// Accordingly the norm is always an even number.  
+        inner_product_of(&self.coefficients, &rhs.coefficients)
+    }
+
+    /// Returns the norm of an octavian, half its inner product with itself.
+    /// The E8 lattice is even, so the halving is exact for integer coefficients, and the units have norm 1.
     pub fn norm(&self) -> T {
-        self.inner_product(self) / 2.into()
+        self.inner_product(self) / from_i8(2)
+    }
+
+    /// Returns the inner product of two octavians, evaluated in the wider type `W`,
+    /// so that it cannot overflow when the coefficients fit in `T` but the quadratic form does not.
+    /// A `W` that is still too narrow overflows like the primitive integers; `checked_inner_product_in` reports it instead.
+    pub fn inner_product_in<W>(&self, rhs: &Octavian<T>) -> W
+    where
+        W: Num + FromPrimitive + From<T>,
+    {
+        let mut sum = W::zero();
+        for (i, row) in Self::GRAM_MATRIX.iter().enumerate() {
+            for (j, &g) in row.iter().enumerate() {
+                if g != 0 {
+                    sum = sum
+                        + from_i8::<W>(g)
+                            * W::from(self.coefficients[i])
+                            * W::from(rhs.coefficients[j]);
+                }
+            }
+        }
+        sum
+    }
+
+    /// Returns the norm of an octavian like `norm`, evaluated in the wider type `W`.
+    pub fn norm_in<W>(&self) -> W
+    where
+        W: Num + FromPrimitive + From<T>,
+    {
+        self.inner_product_in::<W>(self) / from_i8(2)
+    }
+
+    /// Returns the inner product of two octavians like `inner_product_in`,
+    /// or `None` if a partial sum does not fit in `W`.
+    pub fn checked_inner_product_in<W>(&self, rhs: &Octavian<T>) -> Option<W>
+    where
+        W: Num + FromPrimitive + From<T> + CheckedAdd + CheckedMul,
+    {
+        let mut sum = W::zero();
+        for (i, row) in Self::GRAM_MATRIX.iter().enumerate() {
+            for (j, &g) in row.iter().enumerate() {
+                if g != 0 {
+                    let term = from_i8::<W>(g)
+                        .checked_mul(&W::from(self.coefficients[i]))?
+                        .checked_mul(&W::from(rhs.coefficients[j]))?;
+                    sum = sum.checked_add(&term)?;
+                }
+            }
+        }
+        Some(sum)
+    }
+
+    /// Returns the norm of an octavian like `norm_in`, or `None` if a partial sum of the inner product does not fit in `W`.
+    pub fn checked_norm_in<W>(&self) -> Option<W>
+    where
+        W: Num + FromPrimitive + From<T> + CheckedAdd + CheckedMul,
+    {
+        Some(self.checked_inner_product_in::<W>(self)? / from_i8(2))
+    }
+
+    /// Returns the coefficients [norm(x), -trace(x), 1] of the polynomial t² - trace(x) t + norm(x), lowest degree first,
+    /// which x satisfies as an element of the octonions.
+    pub fn char_poly(&self) -> [T; 3] {
+        [self.norm(), -self.trace(), T::one()]
+    }
+
+    /// Returns the symmetric bilinear form N(x + y) - N(x) - N(y) obtained by polarizing the norm.
+    /// Since the norm is half the inner product, it equals `inner_product`, and `trace_form` as well.
+    pub fn bilinear(&self, other: &Octavian<T>) -> T {
+        (*self + *other).norm() - self.norm() - other.norm()
+    }
+
+    /// Returns trace(x conj(y)), the trace form.
+    /// The trace is twice the real part, so this is twice the real part of x conj(y),
+    /// which equals `bilinear` and `inner_product`: all three forms coincide, and N(x) is half of each on (x, x).
+    pub fn trace_form(&self, other: &Octavian<T>) -> T {
+        (*self * other.conjugate()).trace()
     }
 
     /// Multiplies `self` by the scalar `t`.
@@ -74,13 +253,14 @@ where
     }
 
     /// The constant multiplicative identity `Octavian`.
+    /// For the primitive numeric types this is the constant `ONE`, and other types convert its coefficients.
     pub fn one() -> Self {
-        Self::new([2i8, 3, 4, 6, 5, 4, 3, 2].map(|x| -T::from(x)))
+        T::one_octavian()
     }
 
     /// The constant multiplicative identity `Octavian`.
     pub fn zero() -> Self {
-        Self::new([0i8, 0, 0, 0, 0, 0, 0, 0].map(|x| T::from(x)))
+        Self::new([0i8, 0, 0, 0, 0, 0, 0, 0].map(from_i8))
     }
 
     /// Conjugation of an octavian.
@@ -100,395 +280,494 @@ where
     /// Computes the left adjoint matrix of an `Octavian` element in the basis given by the coefficients.
     pub fn left_adjoint_matrix(&self) -> [[T; 8]; 8] {
         // Get the typed adjoint matrices.
-        let adj_matrices = Self::OCTAVIAN_ADJOINT_MATRICES;
+        tables::with_adjoint_entries(|adjoint: tables::AdjointEntries<T>| {
+            // Initialize a zero matrix.
+            let mut result = [[T::zero(); 8]; 8];
 
-        // Initialize a zero matrix.
-        let mut result = [[T::zero(); 8]; 8];
+            // Iterate over the adjoint matrices and coefficients.
+            for (k, &coeff) in self.coefficients.iter().enumerate() {
+                for (i, row) in result.iter_mut().enumerate() {
+                    for (j, entry) in row.iter_mut().enumerate() {
+                        *entry = *entry + adjoint.get(k, i, j) * coeff;
+                    }
+                }
+            }
 
-        // Iterate over the adjoint matrices and coefficients.
-        for (matrix, &coeff) in adj_matrices.iter().zip(&self.coefficients) {
-            for (i, row) in matrix.iter().enumerate() {
-                for (j, &value) in row.iter().enumerate() {
-                    result[i][j] = result[i][j] + T::from(value) * coeff;
+            result
+        })
+    }
+
+    /// Returns the operator y ↦ self * y, with the left adjoint matrix computed once for repeated use.
+    pub fn left_multiplier(&self) -> LeftMultiplier<T> {
+        LeftMultiplier {
+            matrix: self.left_adjoint_matrix(),
+        }
+    }
+
+    /// Computes the right adjoint matrix of an `Octavian` element, the matrix of x ↦ x * self in the basis given by the coefficients.
+    /// The octavians are not associative, so it differs from the left adjoint matrix, and is derived from the same constant tables.
+    pub fn right_adjoint_matrix(&self) -> [[T; 8]; 8] {
+        // Entry [i][k] collects the coefficient of x_k in the i-th coefficient of x * self.
+        let mut matrix = [[T::zero(); 8]; 8];
+        tables::with_adjoint_entries(|adjoint: tables::AdjointEntries<T>| {
+            for k in 0..8 {
+                for (i, row) in matrix.iter_mut().enumerate() {
+                    for (j, &y) in self.coefficients.iter().enumerate() {
+                        row[k] = row[k] + adjoint.get(k, i, j) * y;
+                    }
                 }
             }
+        });
+        matrix
+    }
+
+    /// Returns the operator x ↦ x * self, with its matrix computed once for repeated use.
+    pub fn right_multiplier(&self) -> RightMultiplier<T> {
+        RightMultiplier {
+            matrix: self.right_adjoint_matrix(),
+        }
+    }
+}
+
+/// Multiplies a coefficient vector by a matrix on the left.
+pub(crate) fn apply_matrix<T: Coefficient>(matrix: &[[T; 8]; 8], x: &[T; 8]) -> [T; 8] {
+    let mut coefficients = [T::zero(); 8];
+    apply_matrix_into(matrix, x, &mut coefficients);
+    coefficients
+}
+
+/// Multiplies a coefficient vector by a matrix on the left, writing the result into `out`.
+fn apply_matrix_into<T: Coefficient>(matrix: &[[T; 8]; 8], x: &[T; 8], out: &mut [T; 8]) {
+    #[cfg(feature = "simd")]
+    if let Some(y) = T::simd_matvec(matrix, x) {
+        *out = y;
+        return;
+    }
+    for (c, row) in out.iter_mut().zip(matrix) {
+        *c = T::zero();
+        for (&m, &y) in row.iter().zip(x) {
+            *c = *c + m * y;
         }
+    }
+}
+
+/// Left multiplication by a fixed octavian, y ↦ x * y.
+/// Multiplying with `*` rebuilds the left adjoint matrix of x on every product, which this operator stores instead,
+/// so it is the faster choice when one element multiplies many others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeftMultiplier<T> {
+    matrix: [[T; 8]; 8],
+}
 
-        result
+impl<T> LeftMultiplier<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Returns the product of the fixed octavian with `rhs`.
+    pub fn apply(&self, rhs: &Octavian<T>) -> Octavian<T> {
+        Octavian::new(apply_matrix(&self.matrix, &rhs.coefficients))
+    }
+}
+
+/// Right multiplication by a fixed octavian, x ↦ x * y, the mirror image of `LeftMultiplier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RightMultiplier<T> {
+    matrix: [[T; 8]; 8],
+}
+
+impl<T> RightMultiplier<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Returns the product of `lhs` with the fixed octavian.
+    pub fn apply(&self, lhs: &Octavian<T>) -> Octavian<T> {
+        Octavian::new(apply_matrix(&self.matrix, &lhs.coefficients))
     }
 }
 
 impl<T> Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     /// Defines the inner product between the basis vectors.
-    pub const GRAM_MATRIX: [[i8; 8]; 8] = [
-        [2, 0, -1, 0, 0, 0, 0, 0],
-        [0, 2, 0, -1, 0, 0, 0, 0],
-        [-1, 0, 2, -1, 0, 0, 0, 0],
-        [0, -1, -1, 2, -1, 0, 0, 0],
-        [0, 0, 0, -1, 2, -1, 0, 0],
-        [0, 0, 0, 0, -1, 2, -1, 0],
-        [0, 0, 0, 0, 0, -1, 2, -1],
-        [0, 0, 0, 0, 0, 0, -1, 2],
-    ];
-
-    pub const OCTAVIAN_ADJOINT_MATRICES: [[[i8; 8]; 8]; 8] = [
-        [
-            [2, -1, -1, 0, 1, 0, -1, 0],
-            [3, -1, -1, 0, 1, 0, -2, 1],
-            [4, -2, -2, 0, 2, 0, -2, 1],
-            [6, -2, -3, 0, 3, -1, -3, 2],
-            [5, -1, -3, 0, 2, 0, -3, 2],
-            [4, -1, -3, 1, 1, 0, -2, 1],
-            [3, 0, -2, 0, 1, 0, -1, 0],
-            [2, 0, -1, 0, 0, 0, 0, 0],
-        ],
-        [
-            [1, 2, -2, 0, 0, 0, 0, 0],
-            [1, 3, -2, 0, -1, 1, -1, 0],
-            [2, 4, -3, 0, -1, 1, -1, 0],
-            [2, 6, -4, 0, -2, 2, -2, 1],
-            [1, 5, -3, 0, -2, 2, -1, 0],
-            [1, 4, -2, 0, -2, 1, 0, 0],
-            [0, 3, -1, 0, -1, 0, 0, 0],
-            [0, 2, 0, -1, 0, 0, 0, 0],
-        ],
-        [
-            [-1, 2, 2, -2, 0, 0, 0, 0],
-            [-2, 2, 3, -3, 0, 1, 0, 0],
-            [-2, 3, 4, -4, 0, 1, 0, -1],
-            [-3, 4, 6, -6, 0, 2, 0, -1],
-            [-2, 3, 5, -5, 0, 1, 1, -1],
-            [-1, 2, 4, -4, 0, 1, 0, 0],
-            [-1, 1, 3, -2, -1, 1, 0, 0],
-            [-1, 0, 2, -1, 0, 0, 0, 0],
-        ],
-        [
-            [0, -2, 0, 2, -2, 1, 0, 0],
-            [0, -3, 0, 3, -2, 0, 1, -1],
-            [0, -4, 0, 4, -3, 0, 1, 0],
-            [0, -6, 0, 6, -4, 0, 1, -1],
-            [0, -5, 0, 5, -3, 0, 0, 0],
-            [-1, -4, 0, 4, -2, 0, 0, 0],
-            [0, -3, -1, 3, -1, 0, 0, 0],
-            [0, -1, -1, 2, -1, 0, 0, 0],
-        ],
-        [
-            [-1, 0, 0, 0, 2, -2, 0, 0],
-            [-1, 1, 0, -1, 3, -3, 0, 1],
-            [-2, 1, 0, -1, 4, -3, -1, 1],
-            [-3, 2, 0, -2, 6, -5, 0, 1],
-            [-2, 2, 0, -2, 5, -4, 0, 0],
-            [-1, 2, 0, -2, 4, -3, 0, 0],
-            [-1, 1, 1, -2, 3, -2, 0, 0],
-            [0, 0, 0, -1, 2, -1, 0, 0],
-        ],
-        [
-            [0, 0, 0, -1, 0, 2, 0, -1],
-            [0, -1, -1, 0, 0, 3, -1, -1],
-            [0, -1, -1, 0, -1, 4, 0, -2],
-            [1, -2, -2, 0, -1, 6, -1, -2],
-            [0, -2, -1, 0, -1, 5, -1, -1],
-            [0, -1, -1, 0, -1, 4, -1, -1],
-            [0, 0, -1, 0, -1, 3, -1, 0],
-            [0, 0, 0, 0, -1, 2, -1, 0],
-        ],
-        [
-            [1, 0, 0, 0, 0, -2, 2, 0],
-            [2, 1, 0, -1, 0, -2, 3, -1],
-            [2, 1, 0, -1, 1, -4, 4, -1],
-            [3, 2, 0, -1, 0, -5, 6, -2],
-            [3, 1, -1, 0, 0, -4, 5, -2],
-            [2, 0, 0, 0, 0, -3, 4, -2],
-            [1, 0, 0, 0, 0, -2, 3, -2],
-            [0, 0, 0, 0, 0, -1, 2, -1],
-        ],
-        [
-            [-1, 0, 0, 0, 0, 1, -2, 2],
-            [-1, -1, 0, 1, -1, 1, -2, 3],
-            [-1, 0, 0, 0, -1, 2, -3, 4],
-            [-2, -1, 1, 0, -1, 2, -4, 6],
-            [-2, 0, 1, 0, -1, 1, -3, 5],
-            [-1, 0, 0, 0, 0, 0, -2, 4],
-            [0, 0, 0, 0, 0, 0, -2, 3],
-            [0, 0, 0, 0, 0, 0, -1, 1],
-        ],
-    ];
-
-    pub const OCTAVIAN_UNITS_COEFFICIENTS: [[i8; 8]; 240] = [
-        [-2, -3, -4, -6, -5, -4, -3, -2],
-        [-2, -3, -4, -6, -5, -4, -3, -1],
-        [-2, -3, -4, -6, -5, -4, -2, -1],
-        [-2, -3, -4, -6, -5, -3, -2, -1],
-        [-2, -3, -4, -6, -4, -3, -2, -1],
-        [-2, -3, -4, -5, -4, -3, -2, -1],
-        [-2, -3, -3, -5, -4, -3, -2, -1],
-        [-2, -2, -4, -5, -4, -3, -2, -1],
-        [-2, -2, -3, -5, -4, -3, -2, -1],
-        [-2, -2, -3, -4, -4, -3, -2, -1],
-        [-2, -2, -3, -4, -3, -3, -2, -1],
-        [-2, -2, -3, -4, -3, -2, -2, -1],
-        [-2, -2, -3, -4, -3, -2, -1, -1],
-        [-2, -2, -3, -4, -3, -2, -1, 0],
-        [-1, -3, -3, -5, -4, -3, -2, -1],
-        [-1, -2, -3, -5, -4, -3, -2, -1],
-        [-1, -2, -3, -4, -4, -3, -2, -1],
-        [-1, -2, -3, -4, -3, -3, -2, -1],
-        [-1, -2, -3, -4, -3, -2, -2, -1],
-        [-1, -2, -3, -4, -3, -2, -1, -1],
-        [-1, -2, -3, -4, -3, -2, -1, 0],
-        [-1, -2, -2, -4, -4, -3, -2, -1],
-        [-1, -2, -2, -4, -3, -3, -2, -1],
-        [-1, -2, -2, -4, -3, -2, -2, -1],
-        [-1, -2, -2, -4, -3, -2, -1, -1],
-        [-1, -2, -2, -4, -3, -2, -1, 0],
-        [-1, -2, -2, -3, -3, -3, -2, -1],
-        [-1, -2, -2, -3, -3, -2, -2, -1],
-        [-1, -2, -2, -3, -3, -2, -1, -1],
-        [-1, -2, -2, -3, -3, -2, -1, 0],
-        [-1, -2, -2, -3, -2, -2, -2, -1],
-        [-1, -2, -2, -3, -2, -2, -1, -1],
-        [-1, -2, -2, -3, -2, -2, -1, 0],
-        [-1, -2, -2, -3, -2, -1, -1, -1],
-        [-1, -2, -2, -3, -2, -1, -1, 0],
-        [-1, -2, -2, -3, -2, -1, 0, 0],
-        [-1, -1, -2, -3, -3, -3, -2, -1],
-        [-1, -1, -2, -3, -3, -2, -2, -1],
-        [-1, -1, -2, -3, -3, -2, -1, -1],
-        [-1, -1, -2, -3, -3, -2, -1, 0],
-        [-1, -1, -2, -3, -2, -2, -2, -1],
-        [-1, -1, -2, -3, -2, -2, -1, -1],
-        [-1, -1, -2, -3, -2, -2, -1, 0],
-        [-1, -1, -2, -3, -2, -1, -1, -1],
-        [-1, -1, -2, -3, -2, -1, -1, 0],
-        [-1, -1, -2, -3, -2, -1, 0, 0],
-        [-1, -1, -2, -2, -2, -2, -2, -1],
-        [-1, -1, -2, -2, -2, -2, -1, -1],
-        [-1, -1, -2, -2, -2, -2, -1, 0],
-        [-1, -1, -2, -2, -2, -1, -1, -1],
-        [-1, -1, -2, -2, -2, -1, -1, 0],
-        [-1, -1, -2, -2, -2, -1, 0, 0],
-        [-1, -1, -2, -2, -1, -1, -1, -1],
-        [-1, -1, -2, -2, -1, -1, -1, 0],
-        [-1, -1, -2, -2, -1, -1, 0, 0],
-        [-1, -1, -2, -2, -1, 0, 0, 0],
-        [-1, -1, -1, -2, -2, -2, -2, -1],
-        [-1, -1, -1, -2, -2, -2, -1, -1],
-        [-1, -1, -1, -2, -2, -2, -1, 0],
-        [-1, -1, -1, -2, -2, -1, -1, -1],
-        [-1, -1, -1, -2, -2, -1, -1, 0],
-        [-1, -1, -1, -2, -2, -1, 0, 0],
-        [-1, -1, -1, -2, -1, -1, -1, -1],
-        [-1, -1, -1, -2, -1, -1, -1, 0],
-        [-1, -1, -1, -2, -1, -1, 0, 0],
-        [-1, -1, -1, -2, -1, 0, 0, 0],
-        [-1, -1, -1, -1, -1, -1, -1, -1],
-        [-1, -1, -1, -1, -1, -1, -1, 0],
-        [-1, -1, -1, -1, -1, -1, 0, 0],
-        [-1, -1, -1, -1, -1, 0, 0, 0],
-        [-1, -1, -1, -1, 0, 0, 0, 0],
-        [-1, 0, -1, -1, -1, -1, -1, -1],
-        [-1, 0, -1, -1, -1, -1, -1, 0],
-        [-1, 0, -1, -1, -1, -1, 0, 0],
-        [-1, 0, -1, -1, -1, 0, 0, 0],
-        [-1, 0, -1, -1, 0, 0, 0, 0],
-        [-1, 0, -1, 0, 0, 0, 0, 0],
-        [-1, 0, 0, 0, 0, 0, 0, 0],
-        [0, -1, -1, -2, -2, -2, -2, -1],
-        [0, -1, -1, -2, -2, -2, -1, -1],
-        [0, -1, -1, -2, -2, -2, -1, 0],
-        [0, -1, -1, -2, -2, -1, -1, -1],
-        [0, -1, -1, -2, -2, -1, -1, 0],
-        [0, -1, -1, -2, -2, -1, 0, 0],
-        [0, -1, -1, -2, -1, -1, -1, -1],
-        [0, -1, -1, -2, -1, -1, -1, 0],
-        [0, -1, -1, -2, -1, -1, 0, 0],
-        [0, -1, -1, -2, -1, 0, 0, 0],
-        [0, -1, -1, -1, -1, -1, -1, -1],
-        [0, -1, -1, -1, -1, -1, -1, 0],
-        [0, -1, -1, -1, -1, -1, 0, 0],
-        [0, -1, -1, -1, -1, 0, 0, 0],
-        [0, -1, -1, -1, 0, 0, 0, 0],
-        [0, -1, 0, -1, -1, -1, -1, -1],
-        [0, -1, 0, -1, -1, -1, -1, 0],
-        [0, -1, 0, -1, -1, -1, 0, 0],
-        [0, -1, 0, -1, -1, 0, 0, 0],
-        [0, -1, 0, -1, 0, 0, 0, 0],
-        [0, -1, 0, 0, 0, 0, 0, 0],
-        [0, 0, -1, -1, -1, -1, -1, -1],
-        [0, 0, -1, -1, -1, -1, -1, 0],
-        [0, 0, -1, -1, -1, -1, 0, 0],
-        [0, 0, -1, -1, -1, 0, 0, 0],
-        [0, 0, -1, -1, 0, 0, 0, 0],
-        [0, 0, -1, 0, 0, 0, 0, 0],
-        [0, 0, 0, -1, -1, -1, -1, -1],
-        [0, 0, 0, -1, -1, -1, -1, 0],
-        [0, 0, 0, -1, -1, -1, 0, 0],
-        [0, 0, 0, -1, -1, 0, 0, 0],
-        [0, 0, 0, -1, 0, 0, 0, 0],
-        [0, 0, 0, 0, -1, -1, -1, -1],
-        [0, 0, 0, 0, -1, -1, -1, 0],
-        [0, 0, 0, 0, -1, -1, 0, 0],
-        [0, 0, 0, 0, -1, 0, 0, 0],
-        [0, 0, 0, 0, 0, -1, -1, -1],
-        [0, 0, 0, 0, 0, -1, -1, 0],
-        [0, 0, 0, 0, 0, -1, 0, 0],
-        [0, 0, 0, 0, 0, 0, -1, -1],
-        [0, 0, 0, 0, 0, 0, -1, 0],
-        [0, 0, 0, 0, 0, 0, 0, -1],
-        [0, 0, 0, 0, 0, 0, 0, 1],
-        [0, 0, 0, 0, 0, 0, 1, 0],
-        [0, 0, 0, 0, 0, 0, 1, 1],
-        [0, 0, 0, 0, 0, 1, 0, 0],
-        [0, 0, 0, 0, 0, 1, 1, 0],
-        [0, 0, 0, 0, 0, 1, 1, 1],
-        [0, 0, 0, 0, 1, 0, 0, 0],
-        [0, 0, 0, 0, 1, 1, 0, 0],
-        [0, 0, 0, 0, 1, 1, 1, 0],
-        [0, 0, 0, 0, 1, 1, 1, 1],
-        [0, 0, 0, 1, 0, 0, 0, 0],
-        [0, 0, 0, 1, 1, 0, 0, 0],
-        [0, 0, 0, 1, 1, 1, 0, 0],
-        [0, 0, 0, 1, 1, 1, 1, 0],
-        [0, 0, 0, 1, 1, 1, 1, 1],
-        [0, 0, 1, 0, 0, 0, 0, 0],
-        [0, 0, 1, 1, 0, 0, 0, 0],
-        [0, 0, 1, 1, 1, 0, 0, 0],
-        [0, 0, 1, 1, 1, 1, 0, 0],
-        [0, 0, 1, 1, 1, 1, 1, 0],
-        [0, 0, 1, 1, 1, 1, 1, 1],
-        [0, 1, 0, 0, 0, 0, 0, 0],
-        [0, 1, 0, 1, 0, 0, 0, 0],
-        [0, 1, 0, 1, 1, 0, 0, 0],
-        [0, 1, 0, 1, 1, 1, 0, 0],
-        [0, 1, 0, 1, 1, 1, 1, 0],
-        [0, 1, 0, 1, 1, 1, 1, 1],
-        [0, 1, 1, 1, 0, 0, 0, 0],
-        [0, 1, 1, 1, 1, 0, 0, 0],
-        [0, 1, 1, 1, 1, 1, 0, 0],
-        [0, 1, 1, 1, 1, 1, 1, 0],
-        [0, 1, 1, 1, 1, 1, 1, 1],
-        [0, 1, 1, 2, 1, 0, 0, 0],
-        [0, 1, 1, 2, 1, 1, 0, 0],
-        [0, 1, 1, 2, 1, 1, 1, 0],
-        [0, 1, 1, 2, 1, 1, 1, 1],
-        [0, 1, 1, 2, 2, 1, 0, 0],
-        [0, 1, 1, 2, 2, 1, 1, 0],
-        [0, 1, 1, 2, 2, 1, 1, 1],
-        [0, 1, 1, 2, 2, 2, 1, 0],
-        [0, 1, 1, 2, 2, 2, 1, 1],
-        [0, 1, 1, 2, 2, 2, 2, 1],
-        [1, 0, 0, 0, 0, 0, 0, 0],
-        [1, 0, 1, 0, 0, 0, 0, 0],
-        [1, 0, 1, 1, 0, 0, 0, 0],
-        [1, 0, 1, 1, 1, 0, 0, 0],
-        [1, 0, 1, 1, 1, 1, 0, 0],
-        [1, 0, 1, 1, 1, 1, 1, 0],
-        [1, 0, 1, 1, 1, 1, 1, 1],
-        [1, 1, 1, 1, 0, 0, 0, 0],
-        [1, 1, 1, 1, 1, 0, 0, 0],
-        [1, 1, 1, 1, 1, 1, 0, 0],
-        [1, 1, 1, 1, 1, 1, 1, 0],
-        [1, 1, 1, 1, 1, 1, 1, 1],
-        [1, 1, 1, 2, 1, 0, 0, 0],
-        [1, 1, 1, 2, 1, 1, 0, 0],
-        [1, 1, 1, 2, 1, 1, 1, 0],
-        [1, 1, 1, 2, 1, 1, 1, 1],
-        [1, 1, 1, 2, 2, 1, 0, 0],
-        [1, 1, 1, 2, 2, 1, 1, 0],
-        [1, 1, 1, 2, 2, 1, 1, 1],
-        [1, 1, 1, 2, 2, 2, 1, 0],
-        [1, 1, 1, 2, 2, 2, 1, 1],
-        [1, 1, 1, 2, 2, 2, 2, 1],
-        [1, 1, 2, 2, 1, 0, 0, 0],
-        [1, 1, 2, 2, 1, 1, 0, 0],
-        [1, 1, 2, 2, 1, 1, 1, 0],
-        [1, 1, 2, 2, 1, 1, 1, 1],
-        [1, 1, 2, 2, 2, 1, 0, 0],
-        [1, 1, 2, 2, 2, 1, 1, 0],
-        [1, 1, 2, 2, 2, 1, 1, 1],
-        [1, 1, 2, 2, 2, 2, 1, 0],
-        [1, 1, 2, 2, 2, 2, 1, 1],
-        [1, 1, 2, 2, 2, 2, 2, 1],
-        [1, 1, 2, 3, 2, 1, 0, 0],
-        [1, 1, 2, 3, 2, 1, 1, 0],
-        [1, 1, 2, 3, 2, 1, 1, 1],
-        [1, 1, 2, 3, 2, 2, 1, 0],
-        [1, 1, 2, 3, 2, 2, 1, 1],
-        [1, 1, 2, 3, 2, 2, 2, 1],
-        [1, 1, 2, 3, 3, 2, 1, 0],
-        [1, 1, 2, 3, 3, 2, 1, 1],
-        [1, 1, 2, 3, 3, 2, 2, 1],
-        [1, 1, 2, 3, 3, 3, 2, 1],
-        [1, 2, 2, 3, 2, 1, 0, 0],
-        [1, 2, 2, 3, 2, 1, 1, 0],
-        [1, 2, 2, 3, 2, 1, 1, 1],
-        [1, 2, 2, 3, 2, 2, 1, 0],
-        [1, 2, 2, 3, 2, 2, 1, 1],
-        [1, 2, 2, 3, 2, 2, 2, 1],
-        [1, 2, 2, 3, 3, 2, 1, 0],
-        [1, 2, 2, 3, 3, 2, 1, 1],
-        [1, 2, 2, 3, 3, 2, 2, 1],
-        [1, 2, 2, 3, 3, 3, 2, 1],
-        [1, 2, 2, 4, 3, 2, 1, 0],
-        [1, 2, 2, 4, 3, 2, 1, 1],
-        [1, 2, 2, 4, 3, 2, 2, 1],
-        [1, 2, 2, 4, 3, 3, 2, 1],
-        [1, 2, 2, 4, 4, 3, 2, 1],
-        [1, 2, 3, 4, 3, 2, 1, 0],
-        [1, 2, 3, 4, 3, 2, 1, 1],
-        [1, 2, 3, 4, 3, 2, 2, 1],
-        [1, 2, 3, 4, 3, 3, 2, 1],
-        [1, 2, 3, 4, 4, 3, 2, 1],
-        [1, 2, 3, 5, 4, 3, 2, 1],
-        [1, 3, 3, 5, 4, 3, 2, 1],
-        [2, 2, 3, 4, 3, 2, 1, 0],
-        [2, 2, 3, 4, 3, 2, 1, 1],
-        [2, 2, 3, 4, 3, 2, 2, 1],
-        [2, 2, 3, 4, 3, 3, 2, 1],
-        [2, 2, 3, 4, 4, 3, 2, 1],
-        [2, 2, 3, 5, 4, 3, 2, 1],
-        [2, 2, 4, 5, 4, 3, 2, 1],
-        [2, 3, 3, 5, 4, 3, 2, 1],
-        [2, 3, 4, 5, 4, 3, 2, 1],
-        [2, 3, 4, 6, 4, 3, 2, 1],
-        [2, 3, 4, 6, 5, 3, 2, 1],
-        [2, 3, 4, 6, 5, 4, 2, 1],
-        [2, 3, 4, 6, 5, 4, 3, 1],
-        [2, 3, 4, 6, 5, 4, 3, 2],
-    ];
+    pub const GRAM_MATRIX: [[i8; 8]; 8] = definitions::GRAM_MATRIX;
+
+    /// The norm of `one()`, shared by exactly the 240 unit octavians among the octavian integers.
+    pub const UNIT_NORM: i8 = 1;
+
+    /// The left adjoint matrices of the basis vectors: entry `[k][i][j]` is the i-th coefficient of b_k * b_j.
+    /// Generated by `build.rs` from the octonion basis and the Fano-plane table.
+    pub const OCTAVIAN_ADJOINT_MATRICES: [[[i8; 8]; 8]; 8] = generated::OCTAVIAN_ADJOINT_MATRICES;
+
+    /// The coefficients of the 240 unit octavians, the E8 roots, in increasing order.
+    /// Generated by `build.rs` as the orbit of the basis vectors under the simple reflections.
+    #[cfg(feature = "tables")]
+    pub const OCTAVIAN_UNITS_COEFFICIENTS: [[i8; 8]; 240] = generated::OCTAVIAN_UNITS_COEFFICIENTS;
 
     /// The unit octavians as an array in a canonical order.
+    #[cfg(feature = "tables")]
     pub fn unit_vectors() -> [Self; 240] {
-        Octavian::<T>::OCTAVIAN_UNITS_COEFFICIENTS
-            .map(|coeffs| Octavian::new(coeffs.map(|x| x.into())))
+        Octavian::<T>::OCTAVIAN_UNITS_COEFFICIENTS.map(|coeffs| Octavian::new(coeffs.map(from_i8)))
     }
 
     /// The standard basis vectors for the octavian integers.
     pub fn basis_vectors() -> [Self; 8] {
         [
-            Octavian::new([1i8, 0, 0, 0, 0, 0, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 1, 0, 0, 0, 0, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 1, 0, 0, 0, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 0, 1, 0, 0, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 0, 0, 1, 0, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 0, 0, 0, 1, 0, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 0, 0, 0, 0, 1, 0].map(|x| x.into())),
-            Octavian::new([0i8, 0, 0, 0, 0, 0, 0, 1].map(|x| x.into())),
+            Octavian::new([1i8, 0, 0, 0, 0, 0, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 1, 0, 0, 0, 0, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 1, 0, 0, 0, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 0, 1, 0, 0, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 0, 0, 1, 0, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 0, 0, 0, 1, 0, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 0, 0, 0, 0, 1, 0].map(from_i8)),
+            Octavian::new([0i8, 0, 0, 0, 0, 0, 0, 1].map(from_i8)),
         ]
     }
 }
 
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Divides every coefficient by the scalar `t`, provided each division is exact.
+    /// Returns `AlcoError::DivisionByZero` if `t` is zero and `AlcoError::NotDivisible` if some coefficient leaves a remainder.
+    pub fn div_exact(&self, t: T) -> Result<Self, AlcoError> {
+        if t.is_zero() {
+            return Err(AlcoError::DivisionByZero);
+        }
+        if self.coefficients.iter().any(|&x| !(x % t).is_zero()) {
+            return Err(AlcoError::NotDivisible);
+        }
+        Ok(Self::new(self.coefficients.map(|x| x / t)))
+    }
+
+    /// Returns the quotient q with divisor * q = self, computed as conj(divisor) * self / N(divisor),
+    /// or `None` if the divisor is zero or the quotient does not have coefficients in `T`.
+    /// The octavians are alternative, so the inverse conj(b) / N(b) of b satisfies b (b⁻¹ a) = a despite nonassociativity.
+    /// Integer quotients are tested by exact remainders, while other coefficient types divide by the norm directly,
+    /// as `checked_inv` does. `Octavian<i8>` divides in `i32`, so that it only fails when the quotient does not fit in i8.
+    pub fn checked_div_left(&self, divisor: &Self) -> Option<Self> {
+        T::checked_div_octavian(self, divisor, true)
+    }
+
+    /// Returns the quotient q with q * divisor = self, computed as self * conj(divisor) / N(divisor),
+    /// or `None` if the divisor is zero or the quotient does not have coefficients in `T`, like `checked_div_left`.
+    pub fn checked_div_right(&self, divisor: &Self) -> Option<Self> {
+        T::checked_div_octavian(self, divisor, false)
+    }
+
+    /// Divides by `divisor` on the left or on the right through the product with its conjugate,
+    /// the default of `Coefficient::checked_div_octavian`.
+    fn div_by_conjugate(&self, divisor: &Self, left: bool) -> Option<Self> {
+        let norm = divisor.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        let product = if left {
+            divisor.conjugate() * *self
+        } else {
+            *self * divisor.conjugate()
+        };
+        if is_integral::<T>() {
+            product.div_exact(norm).ok()
+        } else {
+            Some(Self::new(product.coefficients.map(|x| x / norm)))
+        }
+    }
+
+    /// Returns the inverse conj(self) / N(self), the two-sided inverse, or `None` if it does not have coefficients in `T`.
+    /// For integer coefficients only the units are invertible, and their inverse is the conjugate.
+    /// Other coefficient types divide by the norm directly and only fail for norm 0.
+    pub fn checked_inv(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_zero() {
+            return None;
+        }
+        if is_integral::<T>() {
+            self.conjugate().div_exact(norm).ok()
+        } else {
+            Some(Self::new(self.conjugate().coefficients.map(|x| x / norm)))
+        }
+    }
+
+    /// Tests whether the norm equals `UNIT_NORM`. For integer coefficients these are exactly the 240 unit octavians,
+    /// and for floating-point coefficients the points of the unit sphere.
+    /// A coefficient of a vector of norm 1 is its inner product with a dual basis vector, at most √60 < 8 in absolute value,
+    /// so larger coefficients are rejected first and the norm of integer coefficients is then evaluated in `i64`,
+    /// where it cannot overflow.
+    pub fn is_unit(&self) -> bool
+    where
+        T: PartialOrd + ToPrimitive,
+    {
+        let bound: T = from_i8(8);
+        if self.coefficients.iter().any(|&c| c > bound || c < -bound) {
+            return false;
+        }
+        if is_integral::<T>() {
+            let mut small = [0i64; 8];
+            for (s, c) in small.iter_mut().zip(&self.coefficients) {
+                match c.to_i64() {
+                    Some(c) => *s = c,
+                    None => return false,
+                }
+            }
+            Octavian::new(small).norm() == i64::from(Self::UNIT_NORM)
+        } else {
+            self.norm() == from_i8(Self::UNIT_NORM)
+        }
+    }
+
+    /// Converts the coefficients into another type, such as a narrower integer type.
+    /// Returns `AlcoError::Overflow` if some coefficient does not fit.
+    pub fn try_narrow<U>(&self) -> Result<Octavian<U>, AlcoError>
+    where
+        U: FromPrimitive + Num + Copy + Neg<Output = U> + TryFrom<T> + Coefficient,
+    {
+        let mut coefficients = [U::zero(); 8];
+        for (c, &x) in coefficients.iter_mut().zip(&self.coefficients) {
+            *c = U::try_from(x).map_err(|_| AlcoError::Overflow)?;
+        }
+        Ok(Octavian::new(coefficients))
+    }
+
+    /// Creates an octavian from an iterator over exactly 8 coefficients, without collecting them first.
+    /// Returns `AlcoError::WrongLength` if the iterator yields fewer, and `AlcoError::TooManyCoefficients` if it yields a ninth,
+    /// without reading further, so that an endless iterator is rejected too.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, AlcoError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut coefficients = [T::zero(); 8];
+        for (found, c) in coefficients.iter_mut().enumerate() {
+            *c = iter
+                .next()
+                .ok_or(AlcoError::WrongLength { expected: 8, found })?;
+        }
+        match iter.next() {
+            None => Ok(Octavian::new(coefficients)),
+            Some(_) => Err(AlcoError::TooManyCoefficients { expected: 8 }),
+        }
+    }
+
+    /// Returns the coefficients, consuming the octavian.
+    pub fn into_coefficients(self) -> [T; 8] {
+        self.coefficients
+    }
+
+    /// Returns an iterator over references to the coefficients.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.coefficients.iter()
+    }
+}
+
+/// Creates an octavian from a slice of its 8 coefficients.
+impl<T> TryFrom<&[T]> for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    type Error = AlcoError;
+
+    fn try_from(coefficients: &[T]) -> Result<Self, AlcoError> {
+        let coefficients = coefficients
+            .try_into()
+            .map_err(|_| AlcoError::WrongLength {
+                expected: 8,
+                found: coefficients.len(),
+            })?;
+        Ok(Octavian::new(coefficients))
+    }
+}
+
+/// A basis element named in a term of a sum, such as `b3`, `e5` or `il`.
+#[derive(Clone, Copy)]
+enum Symbol {
+    /// The E8 lattice basis element with this index.
+    Lattice(usize),
+    /// The element of the standard octonion basis with this index, where 0 is the real unit.
+    Octonion(usize),
+}
+
+/// Splits a term such as `3b1`, `(1/2)e4` or `2*kl` into its coefficient and the basis element it multiplies.
+/// A term without a symbol, such as `1/2`, is real.
+fn split_symbol(term: &str) -> (&str, Symbol) {
+    if let [.., letter @ (b'b' | b'e'), digit @ b'0'..=b'7'] = term.as_bytes() {
+        let index = usize::from(digit - b'0');
+        let symbol = if *letter == b'b' {
+            Symbol::Lattice(index)
+        } else {
+            Symbol::Octonion(index)
+        };
+        return (&term[..term.len() - 2], symbol);
+    }
+    // The names of `FormatBasis::OctonionNamed`, with il, jl and kl tried before l.
+    for (name, index) in [
+        ("il", 5),
+        ("jl", 6),
+        ("kl", 7),
+        ("i", 1),
+        ("j", 2),
+        ("k", 3),
+        ("l", 4),
+    ] {
+        if let Some(coefficient) = term.strip_suffix(name) {
+            return (coefficient, Symbol::Octonion(index));
+        }
+    }
+    (term, Symbol::Octonion(0))
+}
+
+/// Returns the length of the first term of a sum, which ends at the first sign outside parentheses
+/// that is not the exponent sign of a float such as `1e-3`.
+fn term_length(s: &str) -> usize {
+    let mut depth = 0;
+    let mut previous = None;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '+' | '-' if depth == 0 && i > 0 && !matches!(previous, Some('e' | 'E')) => return i,
+            _ => {}
+        }
+        previous = Some(c);
+    }
+    s.len()
+}
+
+/// Parses an octavian written as a sum of terms, as `to_string_with` writes it with `Notation::Sum` or `Notation::Fancy`.
+/// Coefficients that are integers or fractions are summed exactly in 128 bits, so that half-integer octonion coordinates
+/// combine into integer lattice coordinates; any other coefficient, such as a float, is parsed as `T`.
+fn parse_sum<T>(s: &str) -> Result<Octavian<T>, AlcoError>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + FromStr + Coefficient,
+{
+    let s: String = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '\u{2212}' => '-',
+            _ => "₀₁₂₃₄₅₆₇₈₉"
+                .chars()
+                .position(|d| d == c)
+                .map_or(c, |d| char::from(b'0' + d as u8)),
+        })
+        .collect();
+    let mut exact = [Ratio::<i128>::zero(); 8];
+    let mut inexact = [T::zero(); 8];
+    let mut rest = s.as_str();
+    loop {
+        // `suppress_zeros` with a `" + "` separator writes a negative term after a plus sign, as in `3*b0 + -b1`.
+        let term = rest.trim_start_matches(['+', '-']);
+        let negative = rest[..rest.len() - term.len()].matches('-').count() % 2 == 1;
+        let (term, next) = term.split_at(term_length(term));
+        if term.is_empty() {
+            return Err(AlcoError::Parse(s));
+        }
+        let (coefficient, symbol) = split_symbol(term);
+        let coefficient = coefficient.strip_suffix('*').unwrap_or(coefficient);
+        let coefficient = coefficient
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .unwrap_or(coefficient);
+        let element = match symbol {
+            Symbol::Lattice(i) => core::array::from_fn(|j| i8::from(i == j)),
+            Symbol::Octonion(i) => definitions::OCTONION_BASIS[i],
+        };
+        let parsed = if coefficient.is_empty() {
+            Ok(Ratio::one())
+        } else {
+            coefficient.parse::<Ratio<i128>>()
+        };
+        match parsed {
+            Ok(c) => {
+                let c = if negative {
+                    c.checked_mul(&Ratio::from(-1))
+                } else {
+                    Some(c)
+                };
+                for (x, e) in exact.iter_mut().zip(element) {
+                    *x = c
+                        .and_then(|c| c.checked_mul(&Ratio::from(i128::from(e))))
+                        .and_then(|y| x.checked_add(&y))
+                        .ok_or(AlcoError::Overflow)?;
+                }
+            }
+            Err(_) => {
+                let c: T = coefficient
+                    .parse()
+                    .map_err(|_| AlcoError::Parse(coefficient.to_string()))?;
+                let c = if negative { -c } else { c };
+                for (x, e) in inexact.iter_mut().zip(element) {
+                    *x = *x + c * from_i8(e);
+                }
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        rest = next;
+    }
+    let mut coefficients = inexact;
+    for (c, x) in coefficients.iter_mut().zip(exact) {
+        let x = if x.is_integer() {
+            T::from_i128(x.to_integer())
+        } else if is_integral::<T>() {
+            return Err(AlcoError::NotIntegral);
+        } else {
+            T::from_i128(*x.numer())
+                .zip(T::from_i128(*x.denom()))
+                .map(|(n, d)| n / d)
+        };
+        *c = *c + x.ok_or(AlcoError::Overflow)?;
+    }
+    Ok(Octavian::new(coefficients))
+}
+
+/// Parses an octavian from its 8 E8 lattice coordinates separated by commas, such as `1, 0, 0, 0, 0, 0, 0, 0`,
+/// or, without commas, from a sum of terms in the lattice basis b0, ..., b7, the octonion basis 1, e1, ..., e7,
+/// or the named units i, j, k, l, il, jl, kl, such as `-2b0 - 3b1 + b7`, `1/2 - (1/2)e1` or `i + 2*kl`.
+/// This reads back every sum written by `to_string_with` in the fraction style. A number directly before `e` and a digit
+/// is read as a coefficient of the basis element, so `2e3` is twice e3 rather than the float 2000.
+/// Returns `AlcoError::NotIntegral` if an integer type is asked for an octonion that is not an octavian integer.
+impl<T> FromStr for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + FromStr + Coefficient,
+{
+    type Err = AlcoError;
+
+    fn from_str(s: &str) -> Result<Self, AlcoError> {
+        if !s.contains(',') {
+            return parse_sum(s);
+        }
+        let fields: Vec<&str> = s.split(',').map(str::trim).collect();
+        if fields.len() != 8 {
+            return Err(AlcoError::WrongLength {
+                expected: 8,
+                found: fields.len(),
+            });
+        }
+        let mut coefficients = [T::zero(); 8];
+        for (c, field) in coefficients.iter_mut().zip(fields) {
+            *c = field
+                .parse()
+                .map_err(|_| AlcoError::Parse(field.to_string()))?;
+        }
+        Ok(Octavian::new(coefficients))
+    }
+}
+
 /// Implements addition for `Octavian` elements, which is just the sum of the coefficients.
 impl<T: Add<Output = T>> Add for Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(x) = T::simd_add(&self.coefficients, &other.coefficients) {
+            return Octavian::new(x);
+        }
         let mut x = self.coefficients;
         let y = other.coefficients;
         for i in 0..8 {
@@ -501,7 +780,7 @@ where
 /// Implements subtraction for `Octavian` elements, which is just the difference of the coefficients.
 impl<T: Sub<Output = T>> Sub for Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     type Output = Self;
 
@@ -518,7 +797,7 @@ where
 /// Implements negation for `Octavian` elements, which is just the negative of the coefficients.
 impl<T: Neg<Output = T>> Neg for Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     type Output = Self;
 
@@ -534,10 +813,63 @@ where
     }
 }
 
+/// Implements `Zero` for `Octavian` elements, so that they can be summed by generic numeric code.
+/// `zero()` is the inherent function, and an octavian is zero exactly when all its coefficients are.
+impl<T> Zero for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn zero() -> Self {
+        Octavian::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(T::is_zero)
+    }
+
+    fn set_zero(&mut self) {
+        for c in &mut self.coefficients {
+            c.set_zero();
+        }
+    }
+}
+
+/// Implements `One` for `Octavian` elements, so that they can be multiplied by generic numeric code.
+/// `one()` is the inherent function.
+impl<T> One for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn one() -> Self {
+        Octavian::one()
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Octavian::one()
+    }
+}
+
+/// Implements the inverse for `Octavian` elements with `checked_inv`.
+///
+/// # Panics
+///
+/// Panics if the element has no inverse in the coefficient type: zero, or, for integer coefficients, any non-unit.
+impl<T> Inv for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        self.checked_inv()
+            .expect("the octavian has no inverse with coefficients in its type")
+    }
+}
+
 /// Implement right scalar multiplication on an `Octavian<T>` where `T` is the scalar.
 impl<T: Mul<Output = T>> Mul<T> for Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     type Output = Self;
     fn mul(self, rhs: T) -> Self {
@@ -545,21 +877,764 @@ where
     }
 }
 
-/// Implements multiplication for `Octavian` elements.
-impl<T: Mul<Output = T>> Mul for Octavian<T>
+/// Implement right scalar division on an `Octavian<T>`, dividing each coefficient by the scalar.
+/// Integer coefficients are truncated as `T` divides them; use `div_exact` to require exact quotients.
+impl<T: Div<Output = T>> Div<T> for Octavian<T>
 where
-    T: FromPrimitive + Num + Copy + Neg<Output = T> + From<i8>,
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
 {
     type Output = Self;
-    fn mul(self, other: Self) -> Self::Output {
-        // Compute the left adjoint matrix of `self`.
-        let left_matrix = self.left_adjoint_matrix();
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.coefficients.map(|x| x / rhs))
+    }
+}
+
+/// Implements in-place addition with `add_assign_ref`.
+impl<T> AddAssign for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.add_assign_ref(&rhs);
+    }
+}
+
+/// Implements in-place subtraction with `sub_assign_ref`.
+impl<T> SubAssign for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.sub_assign_ref(&rhs);
+    }
+}
+
+/// Implements in-place multiplication on the right, x *= y meaning x = x * y.
+impl<T> MulAssign for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Implements in-place scalar multiplication with `scale_in_place`.
+impl<T> MulAssign<T> for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.scale_in_place(rhs);
+    }
+}
+
+/// Implements in-place scalar division, truncating integer coefficients like `Div<T>`.
+impl<T> DivAssign<T> for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for x in self.coefficients.iter_mut() {
+            *x = *x / rhs;
+        }
+    }
+}
+
+/// Counts the nonzero entries of the adjoint matrices.
+const fn count_structure_constants() -> usize {
+    let adjoint = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    let mut count = 0;
+    let mut k = 0;
+    while k < 8 {
+        let mut i = 0;
+        while i < 8 {
+            let mut j = 0;
+            while j < 8 {
+                if adjoint[k][i][j] != 0 {
+                    count += 1;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        k += 1;
+    }
+    count
+}
+
+/// The number of nonzero structure constants of the octavian integers in the E8 basis.
+pub const STRUCTURE_CONSTANT_COUNT: usize = count_structure_constants();
+
+/// The nonzero structure constants as entries `(i, j, k, c)`, meaning that the i-th coefficient of x * y gains c x_k y_j.
+/// Equivalently, c is the entry `[k][i][j]` of `OCTAVIAN_ADJOINT_MATRICES`. The entries are sorted by (i, j, k).
+pub const STRUCTURE_CONSTANTS: [(u8, u8, u8, i8); STRUCTURE_CONSTANT_COUNT] = {
+    let adjoint = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    let mut entries = [(0u8, 0u8, 0u8, 0i8); STRUCTURE_CONSTANT_COUNT];
+    let mut n = 0;
+    let mut i = 0;
+    while i < 8 {
+        let mut j = 0;
+        while j < 8 {
+            let mut k = 0;
+            while k < 8 {
+                let c = adjoint[k][i][j];
+                if c != 0 {
+                    entries[n] = (i as u8, j as u8, k as u8, c);
+                    n += 1;
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    entries
+};
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Multiplies two octavians by running over the nonzero structure constants only.
+    /// Each entry of the left adjoint matrix is accumulated before it meets `other`, in the same order as the dense path,
+    /// so the intermediate values and the result are exactly those of `left_multiplier().apply(other)`.
+    pub fn mul_sparse(&self, other: &Self) -> Self {
         let mut coefficients = [T::zero(); 8];
-        for i in 0..8 {
-            for j in 0..8 {
-                coefficients[i] = coefficients[i] + left_matrix[i][j] * other.coefficients[j];
+        tables::with_adjoint_entries(|adjoint: tables::AdjointEntries<T>| {
+            let mut entries = STRUCTURE_CONSTANTS.iter().peekable();
+            while let Some(&&(i, j, _, _)) = entries.peek() {
+                let mut l = T::zero();
+                while let Some(&(_, _, k, _)) = entries.next_if(|e| e.0 == i && e.1 == j) {
+                    l = l + adjoint.get(k as usize, i as usize, j as usize)
+                        * self.coefficients[k as usize];
+                }
+                coefficients[i as usize] =
+                    coefficients[i as usize] + l * other.coefficients[j as usize];
             }
+        });
+        Self::new(coefficients)
+    }
+
+    /// Multiplies two octavians over the nonzero structure constants with checked arithmetic,
+    /// returning `None` if a product or partial sum overflows `T` rather than panicking or wrapping.
+    /// For `Octavian<i8>`, whose partial sums often overflow when the product fits, `checked_mul_i8` is exact.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self>
+    where
+        T: CheckedAdd + CheckedMul,
+    {
+        let mut coefficients = [T::zero(); 8];
+        for &(i, j, k, c) in &STRUCTURE_CONSTANTS {
+            let term = from_i8::<T>(c)
+                .checked_mul(&self.coefficients[k as usize])?
+                .checked_mul(&other.coefficients[j as usize])?;
+            coefficients[i as usize] = coefficients[i as usize].checked_add(&term)?;
+        }
+        Some(Self::new(coefficients))
+    }
+}
+
+impl Octavian<i64> {
+    /// Returns the norm of an octavian with `i64` coefficients, evaluated in `i128`.
+    /// Returns `AlcoError::Overflow` if the inner product does not fit in an i128,
+    /// which needs coefficients beyond about 2^61 in absolute value.
+    pub fn norm_i128(&self) -> Result<i128, AlcoError> {
+        self.checked_norm_in().ok_or(AlcoError::Overflow)
+    }
+
+    /// Adds two octavians in a constant expression.
+    pub const fn const_add(a: Self, b: Self) -> Self {
+        let mut coefficients = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            coefficients[i] = a.coefficients[i] + b.coefficients[i];
+            i += 1;
+        }
+        Self::new(coefficients)
+    }
+
+    /// Negates an octavian in a constant expression.
+    pub const fn const_neg(a: Self) -> Self {
+        let mut coefficients = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            coefficients[i] = -a.coefficients[i];
+            i += 1;
         }
         Self::new(coefficients)
     }
+
+    /// Multiplies two octavians in a constant expression, running over `STRUCTURE_CONSTANTS`.
+    /// The result equals `a * b`, so products can be written as constants:
+    /// `const OMEGA_SQUARED: Octavian<i64> = Octavian::const_mul(OMEGA, OMEGA);`.
+    pub const fn const_mul(a: Self, b: Self) -> Self {
+        let mut coefficients = [0; 8];
+        let mut n = 0;
+        while n < STRUCTURE_CONSTANT_COUNT {
+            let (i, j, k, c) = STRUCTURE_CONSTANTS[n];
+            coefficients[i as usize] +=
+                c as i64 * a.coefficients[k as usize] * b.coefficients[j as usize];
+            n += 1;
+        }
+        Self::new(coefficients)
+    }
+
+    /// Returns the monic minimal polynomial of the left adjoint matrix, lowest degree first, computed exactly by
+    /// finding the first power of the matrix that is a rational combination of the lower ones.
+    /// For x not a multiple of `one()` this is `char_poly`, t² - trace(x) t + norm(x), since x(xy) = x²y makes the
+    /// matrix satisfy it and no linear polynomial annihilates it; for x = s one() it is t - s.
+    /// Anything else would mean the adjoint tables are wrong. Panics if a coefficient does not fit in an `i64`.
+    pub fn left_adjoint_min_poly(&self) -> Vec<Ratio<i64>> {
+        let l = self.left_adjoint_matrix().map(|row| row.map(i128::from));
+        let mut power: [[i128; 8]; 8] =
+            core::array::from_fn(|i| core::array::from_fn(|j| i128::from(i == j)));
+        // Each reduced power is kept with its pivot and its expression as a combination of the powers of the matrix.
+        type Reduced = (usize, Vec<Ratio<i128>>, Vec<Ratio<i128>>);
+        let mut echelon: Vec<Reduced> = Vec::new();
+        for k in 0..=8 {
+            let mut row: Vec<Ratio<i128>> =
+                power.iter().flatten().map(|&x| Ratio::from(x)).collect();
+            let mut combination = vec![Ratio::from(0); k + 1];
+            combination[k] = Ratio::from(1);
+            for (pivot, reduced, expression) in &echelon {
+                if !row[*pivot].is_zero() {
+                    let factor = row[*pivot] / reduced[*pivot];
+                    for (x, &y) in row.iter_mut().zip(reduced) {
+                        *x -= factor * y;
+                    }
+                    for (x, &y) in combination.iter_mut().zip(expression) {
+                        *x -= factor * y;
+                    }
+                }
+            }
+            match row.iter().position(|x| !x.is_zero()) {
+                Some(pivot) => echelon.push((pivot, row, combination)),
+                None => {
+                    return combination
+                        .iter()
+                        .map(|c| {
+                            let narrow =
+                                |x: &i128| i64::try_from(*x).expect("the coefficient fits in i64");
+                            Ratio::new(narrow(c.numer()), narrow(c.denom()))
+                        })
+                        .collect();
+                }
+            }
+            power = core::array::from_fn(|i| {
+                core::array::from_fn(|j| (0..8).map(|m| power[i][m] * l[m][j]).sum())
+            });
+        }
+        unreachable!("by the Cayley–Hamilton theorem the ninth power depends on the lower ones")
+    }
+
+    /// Returns (q, r) with self = d * q + r and N(r) <= N(d) / 2, so N(r) < N(d): the octavians are norm-Euclidean.
+    /// q is an octavian nearest to d⁻¹ self = conj(d) self / N(d), and r = d (d⁻¹ self - q) has norm N(d) times
+    /// the distance to it, which is at most 1/2. The products are evaluated in `i128` with checked arithmetic.
+    /// Returns `AlcoError::DivisionByZero` if `d` is zero and `AlcoError::Overflow` if an intermediate result
+    /// does not fit in i128 or q or r does not fit in i64.
+    #[cfg(feature = "tables")]
+    pub fn div_rem_left(&self, d: &Self) -> Result<(Self, Self), AlcoError> {
+        if *d == Self::zero() {
+            return Err(AlcoError::DivisionByZero);
+        }
+        let (a, d) = (self.widen(), d.widen());
+        let n = d.checked_norm_in::<i128>().ok_or(AlcoError::Overflow)?;
+        let q = d
+            .conjugate()
+            .checked_mul(&a)
+            .and_then(|x| nearest_to_quotient(&x, n))
+            .ok_or(AlcoError::Overflow)?;
+        let r = d
+            .checked_mul(&q)
+            .and_then(|dq| checked_difference(&a, &dq))
+            .ok_or(AlcoError::Overflow)?;
+        Ok((q.try_narrow()?, r.try_narrow()?))
+    }
+
+    /// Returns (q, r) with self = q * d + r and N(r) <= N(d) / 2, the mirror image of `div_rem_left`,
+    /// with q an octavian nearest to self d⁻¹ = self conj(d) / N(d), and the same errors.
+    #[cfg(feature = "tables")]
+    pub fn div_rem_right(&self, d: &Self) -> Result<(Self, Self), AlcoError> {
+        if *d == Self::zero() {
+            return Err(AlcoError::DivisionByZero);
+        }
+        let (a, d) = (self.widen(), d.widen());
+        let n = d.checked_norm_in::<i128>().ok_or(AlcoError::Overflow)?;
+        let q = a
+            .checked_mul(&d.conjugate())
+            .and_then(|x| nearest_to_quotient(&x, n))
+            .ok_or(AlcoError::Overflow)?;
+        let r = q
+            .checked_mul(&d)
+            .and_then(|qd| checked_difference(&a, &qd))
+            .ok_or(AlcoError::Overflow)?;
+        Ok((q.try_narrow()?, r.try_narrow()?))
+    }
+
+    /// Converts the coefficients to `i128`.
+    #[cfg(feature = "tables")]
+    fn widen(&self) -> Octavian<i128> {
+        Octavian::new(self.coefficients.map(i128::from))
+    }
+}
+
+/// Returns a greatest common left divisor of `a` and `b` by the Euclidean algorithm with `div_rem_left`: the last
+/// nonzero remainder, or zero if both are zero. Like a gcd over the integers it is only defined up to a unit, here
+/// on the right.
+///
+/// Returns `None` exactly when a remainder does not fit in i64, or when the last nonzero remainder does not
+/// left-divide both `a` and `b`. The second case comes from nonassociativity: from a = g s and b = g t the
+/// remainder a - b q = g s - (g t) q need not be a left multiple of g, since (g t) q can differ from g (t q).
+/// A returned divisor always left-divides both inputs. Rational primes are split reliably: for x of norm p,
+/// the first division of p by x is exact, so `gcld(&Octavian::one().scale(p), &x)` is `Some(x)`.
+#[cfg(feature = "tables")]
+pub fn gcld(a: &Octavian<i64>, b: &Octavian<i64>) -> Option<Octavian<i64>> {
+    gcld_with_cofactors(a, b).map(|(g, _, _)| g)
+}
+
+/// A greatest common left divisor g with the cofactors s and t of `gcld_with_cofactors`.
+#[cfg(feature = "tables")]
+pub type Cofactors = (Octavian<i64>, Octavian<i64>, Octavian<i64>);
+
+/// Returns (g, s, t) with g = `gcld(a, b)`, a = g s and b = g t, or `None` exactly when `gcld` returns `None`.
+/// When a and b are zero, g, s and t are zero.
+/// The cofactors are quotients by g rather than Bézout coefficients with g = a x + b y, since the remainders are
+/// not combinations of that form without associativity.
+#[cfg(feature = "tables")]
+pub fn gcld_with_cofactors(a: &Octavian<i64>, b: &Octavian<i64>) -> Option<Cofactors> {
+    let (mut g, mut r) = (*a, *b);
+    while r != Octavian::zero() {
+        let (_, next) = g.div_rem_left(&r).ok()?;
+        (g, r) = (r, next);
+    }
+    if g == Octavian::zero() {
+        return Some((g, g, g));
+    }
+    Some((g, a.checked_div_left(&g)?, b.checked_div_left(&g)?))
+}
+
+/// Returns a greatest common right divisor g of `a` and `b`, with a = s g and b = t g, as the conjugate of `gcld`
+/// of the conjugates, since conj(x y) = conj(y) conj(x). Returns `None` exactly when that `gcld` does,
+/// or when a conjugate does not fit in i64.
+#[cfg(feature = "tables")]
+pub fn gcrd(a: &Octavian<i64>, b: &Octavian<i64>) -> Option<Octavian<i64>> {
+    let conjugate = |x: &Octavian<i64>| x.widen().conjugate().try_narrow().ok();
+    conjugate(&gcld(&conjugate(a)?, &conjugate(b)?)?)
+}
+
+/// Returns x - y, or `None` if a coefficient does not fit in i128.
+#[cfg(feature = "tables")]
+fn checked_difference(x: &Octavian<i128>, y: &Octavian<i128>) -> Option<Octavian<i128>> {
+    let mut coefficients = [0; 8];
+    for ((c, a), b) in coefficients
+        .iter_mut()
+        .zip(&x.coefficients)
+        .zip(&y.coefficients)
+    {
+        *c = a.checked_sub(*b)?;
+    }
+    Some(Octavian::new(coefficients))
+}
+
+/// Returns an octavian nearest to x / n for n > 0, or `None` if an intermediate result does not fit in i128.
+/// Rounding each coordinate alone can miss the nearest octavian in this basis,
+/// so the rounded point is then moved by roots while that strictly decreases the distance, as in
+/// `lattice::closest_octavian`. The Voronoi cell of E8 is cut out by the roots, so the result is within norm 1/2 of x / n.
+#[cfg(feature = "tables")]
+fn nearest_to_quotient(x: &Octavian<i128>, n: i128) -> Option<Octavian<i128>> {
+    let twice = n.checked_mul(2)?;
+    let mut q = Octavian::new([0; 8]);
+    for (q, c) in q.coefficients.iter_mut().zip(&x.coefficients) {
+        *q = (*c).checked_mul(2)?.checked_add(n)?.div_euclid(twice);
+    }
+    loop {
+        let mut scaled = [0; 8];
+        for (s, c) in scaled.iter_mut().zip(&q.coefficients) {
+            *s = (*c).checked_mul(n)?;
+        }
+        let e = checked_difference(x, &Octavian::new(scaled))?;
+        // Moving q by a root r changes <e, e> by 2n (n - <e, r>). Ties go to the last root, as with `max_by_key`.
+        // The inner products <e, bᵢ> with the basis are found once, and <e, r> is their combination by r.
+        let mut gram = [0; 8];
+        for (g, b) in gram.iter_mut().zip(Octavian::<i128>::basis_vectors()) {
+            *g = e.checked_inner_product_in(&b)?;
+        }
+        let mut best = None;
+        for r in &Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS {
+            let mut product = 0i128;
+            for (&c, &g) in r.iter().zip(&gram) {
+                product = product.checked_add(g.checked_mul(i128::from(c))?)?;
+            }
+            if best.is_none_or(|(_, p)| product >= p) {
+                best = Some((Octavian::new(r.map(i128::from)), product));
+            }
+        }
+        let (root, product) = best.expect("there are 240 units");
+        if product <= n {
+            return Some(q);
+        }
+        q = checked_difference(&q, &-root)?;
+    }
+}
+
+/// Defines `ONE` for the primitive numeric types, whose coefficients can be written in a constant expression.
+macro_rules! impl_one_constant {
+    ($($t:ty),*) => {
+        $(
+            impl Octavian<$t> {
+                /// The multiplicative identity, equal to `one()`.
+                pub const ONE: Self = Octavian::new([
+                    -2 as $t, -3 as $t, -4 as $t, -6 as $t, -5 as $t, -4 as $t, -3 as $t, -2 as $t,
+                ]);
+            }
+        )*
+    };
+}
+
+impl_one_constant!(i8, i16, i32, i64, i128, f32, f64);
+
+/// Overrides the vectorized methods of `Coefficient` for a type with a kernel in `simd`.
+macro_rules! simd_kernel {
+    () => {
+        #[cfg(feature = "simd")]
+        fn simd_mul_fits(x: &[Self; 8], y: &[Self; 8]) -> bool {
+            crate::simd::mul_fits(x, y)
+        }
+
+        #[cfg(feature = "simd")]
+        fn simd_add(x: &[Self; 8], y: &[Self; 8]) -> Option<[Self; 8]> {
+            crate::simd::add(x, y)
+        }
+
+        #[cfg(feature = "simd")]
+        fn simd_matvec(m: &[[Self; 8]; 8], x: &[Self; 8]) -> Option<[Self; 8]> {
+            crate::simd::matvec(m, x)
+        }
+
+        #[cfg(feature = "simd")]
+        fn simd_inner_product(x: &[Self; 8], y: &[Self; 8]) -> Option<Self> {
+            crate::simd::inner_product(x, y)
+        }
+    };
+}
+
+/// Implements `Coefficient` for the primitive numeric types, whose identity is the constant `ONE` and whose
+/// converted tables are cached in statics of the concrete type.
+/// The braces after each type hold the methods it overrides besides these.
+macro_rules! impl_coefficient {
+    ($($t:ty { $($extra:tt)* }),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Coefficient for $t {
+                const CACHED_TABLES: bool = true;
+
+                fn with_adjoint_matrices<R>(f: impl FnOnce(&tables::AdjointMatrices<$t>) -> R) -> R {
+                    static CACHE: OnceLock<tables::AdjointMatrices<$t>> = OnceLock::new();
+                    f(CACHE.get_or_init(tables::convert_adjoint_matrices))
+                }
+
+                fn with_gram_matrix<R>(f: impl FnOnce(&tables::GramMatrix<$t>) -> R) -> R {
+                    static CACHE: OnceLock<tables::GramMatrix<$t>> = OnceLock::new();
+                    f(CACHE.get_or_init(tables::convert_gram_matrix))
+                }
+
+                fn one_octavian() -> Octavian<$t> {
+                    Octavian::<$t>::ONE
+                }
+
+                $($extra)*
+            }
+        )*
+    };
+}
+
+impl_coefficient! {
+    // Narrow coefficients can overflow in intermediate sums, so i8 multiplies with `mul_exact_i8`.
+    // The product with the conjugate can overflow i8 even when the quotient fits, so i8 divides in i32.
+    i8 {
+        fn mul_octavian_into(x: &Octavian<i8>, y: &Octavian<i8>, out: &mut Octavian<i8>) {
+            *out = x.mul_exact_i8(y);
+        }
+
+        fn checked_div_octavian(
+            x: &Octavian<i8>,
+            divisor: &Octavian<i8>,
+            left: bool,
+        ) -> Option<Octavian<i8>> {
+            let widen = |x: &Octavian<i8>| Octavian::new(x.coefficients.map(i32::from));
+            i32::checked_div_octavian(&widen(x), &widen(divisor), left)?
+                .try_narrow()
+                .ok()
+        }
+    },
+    i16 {},
+    i32 { simd_kernel!(); },
+    i64 { simd_kernel!(); },
+    i128 {},
+    f32 {},
+    f64 { simd_kernel!(); },
+}
+
+impl Octavian<i8> {
+    /// Multiplies two octavians, accumulating the products of coefficients in `i32`.
+    /// Any i8 coefficients keep every partial sum within `i32`, so the result is exact before it is narrowed,
+    /// and the product is correct whenever its coefficients fit in i8, even if intermediate sums do not.
+    /// Returns `None` if some coefficient of the product does not fit in i8.
+    pub fn checked_mul_i8(&self, other: &Self) -> Option<Self> {
+        let mut coefficients = [0i32; 8];
+        for &(i, j, k, c) in &STRUCTURE_CONSTANTS {
+            coefficients[i as usize] += i32::from(c)
+                * i32::from(self.coefficients[k as usize])
+                * i32::from(other.coefficients[j as usize]);
+        }
+        let mut narrowed = [0i8; 8];
+        for (n, c) in narrowed.iter_mut().zip(coefficients) {
+            *n = i8::try_from(c).ok()?;
+        }
+        Some(Self::new(narrowed))
+    }
+
+    /// Multiplies two octavians exactly, like `checked_mul_i8`, panicking if the product does not fit in i8.
+    /// This is how `Mul` multiplies `Octavian<i8>`.
+    pub fn mul_exact_i8(&self, other: &Self) -> Self {
+        self.checked_mul_i8(other)
+            .expect("the product of the octavians does not fit in i8")
+    }
+}
+
+impl Octavian<f64> {
+    /// Returns the eigenvalues of the left adjoint matrix, the matrix of y ↦ x y, as s + i|v| four times and then s - i|v|
+    /// four times, where s is the real part of x and |v| the length of its imaginary part.
+    /// Alternativity gives x(xy) = x²y, so the matrix L satisfies L² - trace(x) L + norm(x) = 0 and has only these two
+    /// eigenvalues, each with half the multiplicity. Rather than assuming s and |v|, they are read off the matrix itself,
+    /// from tr L = 8s and tr L² = 8(s² - |v|²), so that the result also reflects the adjoint tables.
+    pub fn left_adjoint_eigenvalues(&self) -> [Complex<f64>; 8] {
+        let l = self.left_adjoint_matrix();
+        let trace: f64 = (0..8).map(|i| l[i][i]).sum();
+        let trace_of_square: f64 = (0..8)
+            .flat_map(|i| (0..8).map(move |j| (i, j)))
+            .map(|(i, j)| l[i][j] * l[j][i])
+            .sum();
+        let s = trace / 8.0;
+        let v = (s * s - trace_of_square / 8.0).max(0.0).sqrt();
+        core::array::from_fn(|k| Complex::new(s, if k < 4 { v } else { -v }))
+    }
+
+    /// Returns the singular values of left multiplication by x with respect to the inner product, all equal to √norm(x)
+    /// since norm(xy) = norm(x) norm(y). The adjoint of L for the inner product is G⁻¹ Lᵀ G, and the values are
+    /// read off the trace of G⁻¹ Lᵀ G L, which is norm(x) times the identity.
+    pub fn left_adjoint_singular_values(&self) -> [f64; 8] {
+        let l = self.left_adjoint_matrix();
+        let gram = tables::gram_matrix_i64();
+        let inverse = tables::inverse_gram_matrix_i64();
+        // tr(G⁻¹ Lᵀ G L) = Σ G⁻¹[a][b] L[c][b] G[c][d] L[d][a].
+        let mut trace = 0.0;
+        for a in 0..8 {
+            for b in 0..8 {
+                for c in 0..8 {
+                    for d in 0..8 {
+                        trace += inverse[a][b] as f64 * l[c][b] * gram[c][d] as f64 * l[d][a];
+                    }
+                }
+            }
+        }
+        [(trace / 8.0).max(0.0).sqrt(); 8]
+    }
+}
+
+/// Tests whether the coefficient type is an integer type, which truncates one half to zero.
+fn is_integral<T: FromPrimitive + Num>() -> bool {
+    T::from_f32(0.5).is_some_and(|half| half.is_zero())
+}
+
+/// Tests whether the coefficient type holds at least 32-bit integers.
+/// The partial sums of the trilinear accumulation exceed those of the adjoint matrix, which overflow narrower types.
+fn is_wide<T: FromPrimitive>() -> bool {
+    T::from_i32(i32::MAX).is_some()
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Counts the products taking a fast path for `one()` or a basis vector, so that tests can observe it.
+    pub(crate) static FAST_PATH_HITS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Returns `Some(k)` if the octavian is the k-th basis vector, and `Some(8)` if it is `one()`.
+    fn basis_position(&self) -> Option<usize> {
+        // The first coefficient of `one()` is -2, and that of a basis vector is 0 or 1, so other octavians are
+        // ruled out without scanning the rest.
+        let first = self.coefficients[0];
+        if !(first.is_zero() || first.is_one() || first == from_i8(ONE_COEFFICIENTS[0])) {
+            return None;
+        }
+        let mut position = None;
+        for (k, &c) in self.coefficients.iter().enumerate() {
+            if c.is_zero() {
+                continue;
+            }
+            if position.is_some() || !c.is_one() {
+                let is_one = self
+                    .coefficients
+                    .iter()
+                    .zip(ONE_COEFFICIENTS)
+                    .all(|(&c, one)| c == from_i8(one));
+                return is_one.then_some(8);
+            }
+            position = Some(k);
+        }
+        position
+    }
+
+    /// Multiplies two octavians when one of them is `one()` or a basis vector, reading the product off the adjoint matrices.
+    fn mul_fast_path(&self, other: &Self) -> Option<Self> {
+        let result = match (self.basis_position(), other.basis_position()) {
+            (Some(8), _) => *other,
+            (_, Some(8)) => *self,
+            (Some(k), _) => tables::with_adjoint_entries(|adjoint: tables::AdjointEntries<T>| {
+                Self::new(core::array::from_fn(|i| {
+                    (0..8).fold(T::zero(), |c, j| {
+                        c + adjoint.get(k, i, j) * other.coefficients[j]
+                    })
+                }))
+            }),
+            (_, Some(j)) => tables::with_adjoint_entries(|adjoint: tables::AdjointEntries<T>| {
+                Self::new(core::array::from_fn(|i| {
+                    (0..8).fold(T::zero(), |c, k| {
+                        c + adjoint.get(k, i, j) * self.coefficients[k]
+                    })
+                }))
+            }),
+            (None, None) => return None,
+        };
+        #[cfg(test)]
+        FAST_PATH_HITS.with(|hits| hits.set(hits.get() + 1));
+        Some(result)
+    }
+
+    /// Multiplies two octavians into `out` by accumulating c a_k b_j into coefficient i for each structure constant c,
+    /// skipping the zero coefficients of either factor, so that no adjoint matrix is built.
+    fn mul_trilinear_into(&self, other: &Self, out: &mut [T; 8]) {
+        *out = [T::zero(); 8];
+        tables::with_adjoint_matrices(|adjoint: &tables::AdjointMatrices<T>| {
+            for (matrix, &a) in adjoint.iter().zip(&self.coefficients) {
+                if a.is_zero() {
+                    continue;
+                }
+                for (j, &b) in other.coefficients.iter().enumerate() {
+                    if b.is_zero() {
+                        continue;
+                    }
+                    let ab = a * b;
+                    for (c, row) in out.iter_mut().zip(matrix) {
+                        if !row[j].is_zero() {
+                            *c = *c + row[j] * ab;
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Adds `other` to `self` coefficient by coefficient, without constructing a new octavian.
+    pub fn add_assign_ref(&mut self, other: &Self) {
+        for (x, &y) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *x = *x + y;
+        }
+    }
+
+    /// Subtracts `other` from `self` coefficient by coefficient, without constructing a new octavian.
+    pub fn sub_assign_ref(&mut self, other: &Self) {
+        for (x, &y) in self.coefficients.iter_mut().zip(&other.coefficients) {
+            *x = *x - y;
+        }
+    }
+
+    /// Multiplies `self` by the scalar `t` in place.
+    pub fn scale_in_place(&mut self, t: T) {
+        for x in self.coefficients.iter_mut() {
+            *x = *x * t;
+        }
+    }
+
+    /// Writes the product `self * rhs` into `out`, choosing the algorithm as `Mul` does.
+    /// The dense and trilinear paths accumulate directly into the coefficients of `out`.
+    ///
+    /// The borrow rules keep `out` from aliasing either factor, so multiplying in place goes through a copy:
+    ///
+    /// ```compile_fail
+    /// use alco_rs::octavian::Octavian;
+    /// let mut x = Octavian::<i64>::one();
+    /// x.mul_into(&x, &mut x);
+    /// ```
+    pub fn mul_into(&self, rhs: &Self, out: &mut Self) {
+        T::mul_octavian_into(self, rhs, out);
+    }
+
+    /// Writes the product `self * rhs` into `out` by the path suited to `T`, the default of
+    /// `Coefficient::mul_octavian_into`.
+    fn mul_by_path_into(&self, rhs: &Self, out: &mut Self) {
+        if let Some(product) = self.mul_fast_path(rhs) {
+            *out = product;
+            return;
+        }
+        // With the `simd` feature, the dense path is vectorized for the supported coefficient types,
+        // unless the factors are large enough for its wrapping lanes to overflow.
+        #[cfg(feature = "simd")]
+        if T::simd_mul_fits(&self.coefficients, &rhs.coefficients) {
+            apply_matrix_into(
+                &self.left_adjoint_matrix(),
+                &rhs.coefficients,
+                &mut out.coefficients,
+            );
+            return;
+        }
+        // Types without cached tables would convert the whole table for the dense and trilinear paths,
+        // so they only read the nonzero structure constants.
+        if !T::CACHED_TABLES {
+            *out = self.mul_sparse(rhs);
+        } else if !is_integral::<T>() {
+            apply_matrix_into(
+                &self.left_adjoint_matrix(),
+                &rhs.coefficients,
+                &mut out.coefficients,
+            );
+        } else if is_wide::<T>() {
+            self.mul_trilinear_into(rhs, &mut out.coefficients);
+        } else {
+            *out = self.mul_sparse(rhs);
+        }
+    }
+}
+
+/// Implements multiplication for `Octavian` elements.
+/// `Octavian<i8>` uses `mul_exact_i8`. Products with `one()` or a basis vector take a fast path. Otherwise integer coefficients
+/// of at least 32 bits are multiplied by trilinear accumulation over the structure constants and narrower integers use `mul_sparse`,
+/// while floating-point types keep the dense adjoint matrix,
+/// so that floating-point products involving infinities or NaN are unaffected by skipped zero terms.
+/// Coefficient types other than the primitives, such as `Ratio` or `BigInt`, have no cached tables and use `mul_sparse`.
+///
+/// # Panics
+///
+/// Like the primitive integers, products panic on overflow in debug builds; `Octavian<i8>` products panic in every build
+/// when a coefficient does not fit in i8. Use `checked_mul_i8` to get `None` instead.
+impl<T: Mul<Output = T>> Mul for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let mut product = Self::zero();
+        self.mul_into(&other, &mut product);
+        product
+    }
 }
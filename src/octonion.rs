@@ -0,0 +1,75 @@
+use crate::definitions;
+use crate::error::AlcoError;
+use crate::octavian::Octavian;
+use core::ops::Neg;
+use num::rational::Ratio;
+use num_traits::{FromPrimitive, Num};
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T>,
+{
+    /// The E8 lattice coordinates of the standard octonion basis 1, e1, ..., e7.
+    /// The imaginary units satisfy e_i * e_i = -1 together with the Fano-plane relations
+    /// e1 e2 = e3, e1 e4 = e5, e1 e7 = e6, e2 e4 = e6, e2 e5 = e7, e3 e4 = e7, e3 e6 = e5,
+    /// so that i, j, k, l = e1, e2, e3, e4 and e5, e6, e7 = il, jl, kl.
+    /// Each e_i is a unit octavian, and every octavian has integer or half-integer coordinates in this basis.
+    pub const OCTONION_BASIS: [[i8; 8]; 8] = definitions::OCTONION_BASIS;
+}
+
+impl Octavian<i64> {
+    /// Returns the coordinates of an octavian in the standard octonion basis 1, e1, ..., e7.
+    /// Since the basis is orthonormal, each coordinate is half the inner product with the corresponding basis element.
+    pub fn to_octonion_coords(&self) -> [Ratio<i64>; 8] {
+        Self::OCTONION_BASIS.map(|row| {
+            let e = Octavian::new(row.map(i64::from));
+            Ratio::new(self.inner_product(&e), 2)
+        })
+    }
+
+    /// Creates an octavian from its coordinates in the standard octonion basis 1, e1, ..., e7.
+    /// Returns `None` if the octonion is not an octavian integer.
+    pub fn from_octonion_coords(c: [Ratio<i64>; 8]) -> Option<Octavian<i64>> {
+        Octavian::new(Octavian::<Ratio<i64>>::coordinates_of(&c))
+            .to_integral()
+            .ok()
+    }
+}
+
+impl Octavian<Ratio<i64>> {
+    /// Returns the octavian integer with the same E8 lattice coordinates.
+    /// Returns `AlcoError::NotIntegral` if some coordinate is not an integer.
+    pub fn to_integral(&self) -> Result<Octavian<i64>, AlcoError> {
+        if self.coefficients.iter().all(|x| x.is_integer()) {
+            Ok(Octavian::new(self.coefficients.map(|x| x.to_integer())))
+        } else {
+            Err(AlcoError::NotIntegral)
+        }
+    }
+}
+
+impl Octavian<i8> {
+    /// The imaginary unit e1 of the standard octonion basis (row 1 of `OCTONION_BASIS`).
+    pub const E1: Self = Octavian::new([-2, -2, -3, -4, -3, -2, -1, 0]);
+    /// The imaginary unit e2 of the standard octonion basis (row 2 of `OCTONION_BASIS`).
+    pub const E2: Self = Octavian::new([0, -1, -1, -2, -2, -2, -1, 0]);
+    /// The imaginary unit e3 of the standard octonion basis (row 3 of `OCTONION_BASIS`).
+    pub const E3: Self = Octavian::new([0, 0, 0, 0, 0, 0, 1, 0]);
+    /// The imaginary unit e4 of the standard octonion basis (row 4 of `OCTONION_BASIS`).
+    pub const E4: Self = Octavian::new([0, -1, -1, -2, -1, 0, 0, 0]);
+    /// The imaginary unit e5 of the standard octonion basis (row 5 of `OCTONION_BASIS`).
+    pub const E5: Self = Octavian::new([0, 0, 0, 0, -1, 0, 0, 0]);
+    /// The imaginary unit e6 of the standard octonion basis (row 6 of `OCTONION_BASIS`).
+    pub const E6: Self = Octavian::new([0, 0, 1, 0, 0, 0, 0, 0]);
+    /// The imaginary unit e7 of the standard octonion basis (row 7 of `OCTONION_BASIS`).
+    pub const E7: Self = Octavian::new([0, 1, 0, 0, 0, 0, 0, 0]);
+
+    /// The quaternion unit i, equal to e1.
+    pub const I: Self = Self::E1;
+    /// The quaternion unit j, equal to e2.
+    pub const J: Self = Self::E2;
+    /// The quaternion unit k = ij, equal to e3.
+    pub const K: Self = Self::E3;
+    /// The Cayley-Dickson doubling unit l, equal to e4, so that il, jl, kl = e5, e6, e7.
+    pub const L: Self = Self::E4;
+}
@@ -0,0 +1,106 @@
+//! The Gosset polytope 4_21, whose 240 vertices are the roots of E8.
+//!
+//! Its edges join the roots at the minimal distance, those with ⟨r, s⟩ = 1, and its facets are 7-simplices,
+//! 8 roots that are pairwise adjacent, and 7-orthoplexes, 7 pairs of orthogonal roots that are adjacent across pairs.
+
+use crate::root_graph::{root_graph, RootGraph};
+
+/// Counts of the vertices, edges and facets of a polytope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolytopeStats {
+    pub vertices: usize,
+    pub edges: usize,
+    /// The facets that are 7-simplices.
+    pub simplex_facets: usize,
+    /// The facets that are 7-orthoplexes.
+    pub orthoplex_facets: usize,
+}
+
+/// Returns the vertex, edge and facet counts of 4_21, found from the inner products of the roots.
+/// The facets are enumerated as vertex sets in increasing order, so that each is counted once,
+/// extending a partial facet only by vertices adjacent to all of its members and abandoning it
+/// when too few remain to complete it.
+pub fn gosset_polytope_statistics() -> PolytopeStats {
+    let edges = root_graph(1);
+    let orthogonal = root_graph(0);
+    // The distance between roots is √(4 - 2⟨r, s⟩), so the closest distinct roots are those with the largest inner product,
+    // which is 1 since ⟨r, s⟩ = 2 only when r = s.
+    let vertices = edges.vertex_count();
+    let edge_count = edges.degree_sequence().iter().sum::<usize>() / 2;
+
+    let mut simplex_facets = 0;
+    let mut orthoplex_facets = 0;
+    for v in 0..vertices {
+        let later: Vec<usize> = edges
+            .neighbors(v)
+            .iter()
+            .copied()
+            .filter(|&w| w > v)
+            .collect();
+        simplex_facets += count_simplices(&edges, &later, 7);
+        // v is the smallest vertex of the orthoplex, paired with the orthogonal w opposite it.
+        for &w in orthogonal.neighbors(v).iter().filter(|&&w| w > v) {
+            let common: Vec<usize> = later
+                .iter()
+                .copied()
+                .filter(|&x| edges.is_adjacent(w, x))
+                .collect();
+            orthoplex_facets += count_orthoplexes(&edges, &orthogonal, &common, 6);
+        }
+    }
+    PolytopeStats {
+        vertices,
+        edges: edge_count,
+        simplex_facets,
+        orthoplex_facets,
+    }
+}
+
+/// Counts the ways to choose `needed` pairwise adjacent vertices from the candidates, in increasing order.
+fn count_simplices(edges: &RootGraph, candidates: &[usize], needed: usize) -> usize {
+    if needed == 0 {
+        return 1;
+    }
+    let mut count = 0;
+    for (i, &w) in candidates.iter().enumerate() {
+        if candidates.len() - i < needed {
+            break;
+        }
+        let remaining: Vec<usize> = candidates[i + 1..]
+            .iter()
+            .copied()
+            .filter(|&x| edges.is_adjacent(w, x))
+            .collect();
+        count += count_simplices(edges, &remaining, needed - 1);
+    }
+    count
+}
+
+/// Counts the ways to choose `pairs` pairs of orthogonal vertices from the candidates, adjacent to one another across pairs.
+/// The first vertex of each pair is the smallest vertex not yet chosen, so that each set is counted once.
+fn count_orthoplexes(
+    edges: &RootGraph,
+    orthogonal: &RootGraph,
+    candidates: &[usize],
+    pairs: usize,
+) -> usize {
+    if pairs == 0 {
+        return 1;
+    }
+    if candidates.len() < 2 * pairs {
+        return 0;
+    }
+    let x = candidates[0];
+    candidates[1..]
+        .iter()
+        .filter(|&&y| orthogonal.is_adjacent(x, y))
+        .map(|&y| {
+            let remaining: Vec<usize> = candidates[1..]
+                .iter()
+                .copied()
+                .filter(|&z| edges.is_adjacent(x, z) && edges.is_adjacent(y, z))
+                .collect();
+            count_orthoplexes(edges, orthogonal, &remaining, pairs - 1)
+        })
+        .sum()
+}
@@ -0,0 +1,12 @@
+//! The types and traits most programs need, importable at once with `use alco_rs::prelude::*;`.
+
+pub use crate::compare::CoefficientEq;
+pub use crate::error::AlcoError;
+pub use crate::octavian::{LeftMultiplier, Octavian, RightMultiplier};
+pub use crate::progress::{Enumeration, Progress};
+pub use crate::slice::OctavianSliceExt;
+pub use crate::view::{OctavianRef, OctavianSlice};
+
+/// The 240 unit octavians, `Octavian::UNITS`.
+#[cfg(feature = "tables")]
+pub const UNITS: [Octavian<i8>; 240] = Octavian::UNITS;
@@ -0,0 +1,135 @@
+//! Plain-text and Markdown renderings of multiplication tables, for embedding small subloops in notes.
+
+use crate::octavian::Octavian;
+
+/// How the rows of a rendered table are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLayout {
+    /// Space-aligned columns with a rule under the header and after the row labels.
+    Plain,
+    /// A Markdown pipe table.
+    Markdown,
+}
+
+/// How elements are labelled when no names are supplied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableLabels {
+    /// By their E8 lattice coordinates, such as `[0,0,0,0,0,0,1,0]`.
+    Coordinates,
+    /// By their position in `Octavian::UNITS`.
+    #[cfg(feature = "tables")]
+    UnitIndex,
+}
+
+/// The style of a table rendered by `format_multiplication_table`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStyle {
+    pub layout: TableLayout,
+    pub labels: TableLabels,
+    /// Rendered in place of a product that has no label: one outside the list of named elements,
+    /// a non-unit when labelling by unit index, or a product whose coefficients do not fit in i8.
+    pub placeholder: String,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        TableStyle {
+            layout: TableLayout::Plain,
+            labels: TableLabels::Coordinates,
+            placeholder: "?".to_string(),
+        }
+    }
+}
+
+/// Returns the table of products x * y, with x labelling the rows and y the columns.
+/// With `labels`, each element is named by the label at its position, and so is each product that appears in `elements`;
+/// otherwise elements and products are labelled as `style.labels` says.
+///
+/// # Panics
+/// Panics if `labels` does not have one label for each element.
+pub fn format_multiplication_table(
+    elements: &[Octavian<i8>],
+    labels: Option<&[String]>,
+    style: TableStyle,
+) -> String {
+    if let Some(labels) = labels {
+        assert_eq!(
+            labels.len(),
+            elements.len(),
+            "there must be one label for each element"
+        );
+    }
+    let label = |x: Option<Octavian<i8>>| -> String {
+        let Some(x) = x else {
+            return style.placeholder.clone();
+        };
+        let found = match labels {
+            Some(labels) => elements
+                .iter()
+                .position(|e| *e == x)
+                .map(|i| labels[i].clone()),
+            None => match style.labels {
+                TableLabels::Coordinates => {
+                    let fields: Vec<String> =
+                        x.coefficients.iter().map(|c| c.to_string()).collect();
+                    Some(format!("[{}]", fields.join(",")))
+                }
+                #[cfg(feature = "tables")]
+                TableLabels::UnitIndex => x.unit_index().map(|i| i.to_string()),
+            },
+        };
+        found.unwrap_or_else(|| style.placeholder.clone())
+    };
+
+    let mut rows = vec![std::iter::once("*".to_string())
+        .chain(elements.iter().map(|&y| label(Some(y))))
+        .collect::<Vec<String>>()];
+    for &x in elements {
+        rows.push(
+            std::iter::once(label(Some(x)))
+                .chain(elements.iter().map(|y| label(x.checked_mul_i8(y))))
+                .collect(),
+        );
+    }
+    let widths: Vec<usize> = (0..=elements.len())
+        .map(|j| {
+            rows.iter()
+                .map(|row| row[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let pad = |s: &str, width: usize| format!("{:>width$}", s, width = width);
+
+    let mut lines = Vec::new();
+    match style.layout {
+        TableLayout::Plain => {
+            for (i, row) in rows.iter().enumerate() {
+                let cells: Vec<String> = row[1..]
+                    .iter()
+                    .zip(&widths[1..])
+                    .map(|(s, &w)| pad(s, w))
+                    .collect();
+                lines.push(format!("{} | {}", pad(&row[0], widths[0]), cells.join(" ")));
+                if i == 0 {
+                    let rule: usize =
+                        widths[1..].iter().sum::<usize>() + elements.len().saturating_sub(1);
+                    lines.push(format!("{}-+-{}", "-".repeat(widths[0]), "-".repeat(rule)));
+                }
+            }
+        }
+        TableLayout::Markdown => {
+            // Markdown needs at least three dashes in each column of the delimiter row.
+            let widths: Vec<usize> = widths.iter().map(|&w| w.max(3)).collect();
+            for (i, row) in rows.iter().enumerate() {
+                let cells: Vec<String> = row.iter().zip(&widths).map(|(s, &w)| pad(s, w)).collect();
+                lines.push(format!("| {} |", cells.join(" | ")));
+                if i == 0 {
+                    let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+                    lines.push(format!("| {} |", rule.join(" | ")));
+                }
+            }
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,40 @@
+//! Progress reporting and cooperative cancellation for long-running computations.
+//!
+//! The functions ending in `_with` report after each unit of their work, such as a round of a closure,
+//! and stop early when the callback returns `ControlFlow::Break`, marking their result as incomplete.
+//! Passing `&()` reports nowhere, which is what the functions without the suffix do.
+
+use core::ops::ControlFlow;
+
+/// Receives progress reports from a long-running computation and decides whether it goes on.
+pub trait Progress {
+    /// Reports that `done` items have been found so far, out of about `total_hint` if the total is known.
+    /// Returning `ControlFlow::Break(())` asks the computation to stop at the next opportunity.
+    fn report(&self, done: u64, total_hint: Option<u64>) -> ControlFlow<()>;
+}
+
+/// Ignores every report and never cancels.
+impl Progress for () {
+    fn report(&self, _done: u64, _total_hint: Option<u64>) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+/// Calls the closure with each report.
+impl<F> Progress for F
+where
+    F: Fn(u64, Option<u64>) -> ControlFlow<()>,
+{
+    fn report(&self, done: u64, total_hint: Option<u64>) -> ControlFlow<()> {
+        self(done, total_hint)
+    }
+}
+
+/// The elements found by a computation that can be cancelled, and whether it ran to completion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enumeration<T> {
+    /// The elements found, all of them if `complete` holds.
+    pub elements: Vec<T>,
+    /// Whether the computation finished, rather than stopping at a `ControlFlow::Break`.
+    pub complete: bool,
+}
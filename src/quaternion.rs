@@ -0,0 +1,150 @@
+use crate::octavian::Octavian;
+use core::ops::{Add, Mul, Neg, Sub};
+use num::rational::Ratio;
+use num_traits::Num;
+
+/// A quaternion given by its coordinates in the basis 1, i, j, k.
+/// The Hurwitz integers are the quaternions whose coordinates are all integers or all halves of odd integers.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct HurwitzQuaternion<T> {
+    /// The coordinates of the quaternion in the basis 1, i, j, k.
+    pub coefficients: [T; 4],
+}
+
+impl<T> HurwitzQuaternion<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    /// Create a new `HurwitzQuaternion`.
+    pub const fn new(coefficients: [T; 4]) -> Self {
+        HurwitzQuaternion { coefficients }
+    }
+
+    /// Conjugation of a quaternion.
+    pub fn conjugate(&self) -> Self {
+        let [a, b, c, d] = self.coefficients;
+        Self::new([a, -b, -c, -d])
+    }
+
+    /// Returns the norm of a quaternion, the sum of the squares of its coordinates.
+    pub fn norm(&self) -> T {
+        self.coefficients
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + x * x)
+    }
+}
+
+/// Implements addition for `HurwitzQuaternion` elements, which is just the sum of the coefficients.
+impl<T> Add for HurwitzQuaternion<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut x = self.coefficients;
+        for (a, &b) in x.iter_mut().zip(&other.coefficients) {
+            *a = *a + b;
+        }
+        Self::new(x)
+    }
+}
+
+/// Implements subtraction for `HurwitzQuaternion` elements, which is just the difference of the coefficients.
+impl<T> Sub for HurwitzQuaternion<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let mut x = self.coefficients;
+        for (a, &b) in x.iter_mut().zip(&other.coefficients) {
+            *a = *a - b;
+        }
+        Self::new(x)
+    }
+}
+
+/// Implements the Hamilton product for `HurwitzQuaternion` elements.
+impl<T> Mul for HurwitzQuaternion<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let [a1, b1, c1, d1] = self.coefficients;
+        let [a2, b2, c2, d2] = other.coefficients;
+        Self::new([
+            a1 * a2 - b1 * b2 - c1 * c2 - d1 * d2,
+            a1 * b2 + b1 * a2 + c1 * d2 - d1 * c2,
+            a1 * c2 - b1 * d2 + c1 * a2 + d1 * b2,
+            a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        ])
+    }
+}
+
+/// An embedding of the quaternions into the octonions, determined by the images of i and j.
+/// The images must be anticommuting imaginary units, in which case k is sent to their product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuaternionEmbedding {
+    basis: [Octavian<i64>; 4],
+}
+
+impl QuaternionEmbedding {
+    /// Create a new `QuaternionEmbedding` sending i and j to the given octavians.
+    /// Returns `None` unless both are imaginary units with vanishing inner product, which is equivalent to anticommuting.
+    pub fn new(i: Octavian<i64>, j: Octavian<i64>) -> Option<Self> {
+        let is_imaginary_unit = |x: &Octavian<i64>| x.trace() == 0 && x.norm() == 1;
+        if !is_imaginary_unit(&i) || !is_imaginary_unit(&j) || i.inner_product(&j) != 0 {
+            return None;
+        }
+        Some(QuaternionEmbedding {
+            basis: [Octavian::one(), i, j, i * j],
+        })
+    }
+
+    /// Returns the images of 1, i, j, k, which form an orthonormal basis of the subalgebra.
+    pub fn basis(&self) -> [Octavian<i64>; 4] {
+        self.basis
+    }
+
+    /// Returns the image of a quaternion under the embedding.
+    pub fn embed(&self, q: &HurwitzQuaternion<Ratio<i64>>) -> Octavian<Ratio<i64>> {
+        let mut coefficients = [Ratio::from_integer(0); 8];
+        for (b, &c) in self.basis.iter().zip(&q.coefficients) {
+            for (x, &y) in coefficients.iter_mut().zip(&b.coefficients) {
+                *x += c * y;
+            }
+        }
+        Octavian::new(coefficients)
+    }
+
+    /// Returns the coordinates of the orthogonal projection of an octonion onto the subalgebra.
+    pub fn project(&self, x: &Octavian<Ratio<i64>>) -> HurwitzQuaternion<Ratio<i64>> {
+        // The basis is orthonormal with respect to the octonion norm, which is half the E8 inner product.
+        HurwitzQuaternion::new(
+            self.basis
+                .map(|b| x.inner_product(&Octavian::new(b.coefficients.map(Ratio::from))) / 2),
+        )
+    }
+
+    /// Tests whether an octonion lies in the subalgebra.
+    pub fn contains(&self, x: &Octavian<Ratio<i64>>) -> bool {
+        self.embed(&self.project(x)) == *x
+    }
+}
+
+impl Octavian<i64> {
+    /// Splits an octavian into its component inside a quaternion subalgebra, in quaternion coordinates,
+    /// and its component in the orthogonal complement, in E8 lattice coordinates.
+    pub fn project_onto_quaternion_subalgebra(
+        &self,
+        emb: &QuaternionEmbedding,
+    ) -> (HurwitzQuaternion<Ratio<i64>>, Octavian<Ratio<i64>>) {
+        let x = Octavian::new(self.coefficients.map(Ratio::from));
+        let q = emb.project(&x);
+        (q, x - emb.embed(&q))
+    }
+}
@@ -0,0 +1,80 @@
+//! Self-describing serialized forms of octavians that record the basis of their coordinates,
+//! either `{"basis": "e8", "coords": [...]}` or `{"basis": "octonion", "coords": [...]}`
+//! with the octonion coordinates written as exact fractions such as `"-1/2"`.
+//! Deserialization accepts either form and converts to E8 lattice coordinates.
+
+use crate::octavian::Octavian;
+use num::rational::Ratio;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The basis in which a serialized octavian records its coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerdeRepr {
+    /// The E8 lattice coordinates, as integers.
+    E8,
+    /// The coordinates in the octonion basis 1, e1, ..., e7, as integer or half-integer fractions in strings.
+    Octonion,
+}
+
+/// An octavian together with the basis it is serialized in.
+/// Deserializing records the basis that was read, and the octavian always holds E8 lattice coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Represented {
+    pub repr: SerdeRepr,
+    pub octavian: Octavian<i64>,
+}
+
+/// The serialized form, tagged by the basis.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "basis", content = "coords")]
+enum Tagged {
+    #[serde(rename = "e8")]
+    E8([i64; 8]),
+    #[serde(rename = "octonion")]
+    Octonion([String; 8]),
+}
+
+impl Octavian<i64> {
+    /// Wraps the octavian so that it serializes in the given basis.
+    pub fn serialize_as(&self, repr: SerdeRepr) -> Represented {
+        Represented {
+            repr,
+            octavian: *self,
+        }
+    }
+}
+
+impl Serialize for Represented {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.repr {
+            SerdeRepr::E8 => Tagged::E8(self.octavian.coefficients),
+            SerdeRepr::Octonion => {
+                Tagged::Octonion(self.octavian.to_octonion_coords().map(|c| c.to_string()))
+            }
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Represented {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Tagged::deserialize(deserializer)? {
+            Tagged::E8(coefficients) => Ok(Octavian::new(coefficients).serialize_as(SerdeRepr::E8)),
+            Tagged::Octonion(coords) => {
+                let mut ratios = [Ratio::from_integer(0); 8];
+                for (r, s) in ratios.iter_mut().zip(&coords) {
+                    *r = s
+                        .parse()
+                        .map_err(|_| D::Error::custom(format!("{:?} is not a fraction", s)))?;
+                }
+                let octavian = Octavian::from_octonion_coords(ratios).ok_or_else(|| {
+                    D::Error::custom(
+                        "the octonion coordinates are not those of an octavian integer",
+                    )
+                })?;
+                Ok(octavian.serialize_as(SerdeRepr::Octonion))
+            }
+        }
+    }
+}
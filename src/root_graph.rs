@@ -0,0 +1,207 @@
+//! Graphs on the E8 roots defined by inner products, and their basic invariants.
+//!
+//! `root_graph` joins two of the 240 roots when their inner product takes a given value, and `root_class_graph`
+//! joins two of the 120 classes {r, -r} when it takes the value up to sign. With value ±1 the class graph is
+//! strongly regular with parameters (120, 56, 28, 24), the complement of the orthogonality graph (120, 63, 30, 36).
+
+use crate::octavian::Octavian;
+use crate::units::unit_sign_classes;
+
+/// A simple undirected graph whose vertices are roots or classes of roots, with sorted adjacency lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootGraph {
+    /// The root standing for each vertex: the unit itself, or the unit of smaller index in the class.
+    vertices: Vec<Octavian<i64>>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// Returns the graph on the 240 roots, with vertex i the unit `Octavian::UNITS[i]`,
+/// and an edge between two distinct roots r and s when ⟨r, s⟩ = `inner_product_value`.
+pub fn root_graph(inner_product_value: i64) -> RootGraph {
+    let vertices: Vec<Octavian<i64>> = Octavian::UNITS
+        .iter()
+        .map(|u| Octavian::new(u.coefficients.map(i64::from)))
+        .collect();
+    RootGraph::from_relation(vertices, |r, s| r.inner_product(s) == inner_product_value)
+}
+
+/// Returns the graph on the 120 classes {r, -r} of roots, with vertex c the class of the units in entry c of `unit_sign_classes`,
+/// and an edge between two distinct classes when ⟨r, s⟩ = ±`inner_product_value`,
+/// since the inner product of classes is only defined up to sign.
+pub fn root_class_graph(inner_product_value: i64) -> RootGraph {
+    let vertices: Vec<Octavian<i64>> = unit_sign_classes()
+        .iter()
+        .map(|&(u, _)| Octavian::new(Octavian::UNITS[u as usize].coefficients.map(i64::from)))
+        .collect();
+    RootGraph::from_relation(vertices, |r, s| {
+        r.inner_product(s).abs() == inner_product_value.abs()
+    })
+}
+
+impl RootGraph {
+    fn from_relation(
+        vertices: Vec<Octavian<i64>>,
+        adjacent: impl Fn(&Octavian<i64>, &Octavian<i64>) -> bool,
+    ) -> Self {
+        let neighbors = (0..vertices.len())
+            .map(|i| {
+                (0..vertices.len())
+                    .filter(|&j| i != j && adjacent(&vertices[i], &vertices[j]))
+                    .collect()
+            })
+            .collect();
+        RootGraph {
+            vertices,
+            neighbors,
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns the root standing for each vertex.
+    pub fn vertices(&self) -> &[Octavian<i64>] {
+        &self.vertices
+    }
+
+    /// Returns the neighbours of a vertex in increasing order.
+    pub fn neighbors(&self, v: usize) -> &[usize] {
+        &self.neighbors[v]
+    }
+
+    pub fn is_adjacent(&self, a: usize, b: usize) -> bool {
+        self.neighbors[a].binary_search(&b).is_ok()
+    }
+
+    /// Returns the degree of each vertex, in vertex order.
+    pub fn degree_sequence(&self) -> Vec<usize> {
+        self.neighbors.iter().map(Vec::len).collect()
+    }
+
+    /// Returns the common degree if the graph is regular.
+    pub fn regularity(&self) -> Option<usize> {
+        let degrees = self.degree_sequence();
+        let k = *degrees.first()?;
+        degrees.iter().all(|&d| d == k).then_some(k)
+    }
+
+    /// Returns the parameters (n, k, λ, μ) if the graph is strongly regular: it is k-regular on n vertices,
+    /// adjacent vertices have λ common neighbours, and distinct non-adjacent vertices have μ.
+    pub fn strongly_regular_parameters(&self) -> Option<(usize, usize, usize, usize)> {
+        let k = self.regularity()?;
+        let n = self.vertex_count();
+        let (mut lambda, mut mu) = (None, None);
+        for a in 0..n {
+            for b in a + 1..n {
+                let common = self.neighbors[a]
+                    .iter()
+                    .filter(|&&c| self.is_adjacent(b, c))
+                    .count();
+                let parameter = if self.is_adjacent(a, b) {
+                    &mut lambda
+                } else {
+                    &mut mu
+                };
+                if *parameter.get_or_insert(common) != common {
+                    return None;
+                }
+            }
+        }
+        Some((n, k, lambda.unwrap_or(0), mu.unwrap_or(0)))
+    }
+
+    /// Returns every clique with at least 1 and at most `max_size` vertices, each in increasing order,
+    /// listed by size and then lexicographically.
+    pub fn cliques(&self, max_size: usize) -> Vec<Vec<usize>> {
+        let mut by_size: Vec<Vec<Vec<usize>>> = vec![Vec::new(); max_size + 1];
+        let mut clique = Vec::new();
+        for v in 0..self.vertex_count() {
+            let candidates: Vec<usize> = self.neighbors[v]
+                .iter()
+                .copied()
+                .filter(|&w| w > v)
+                .collect();
+            clique.push(v);
+            self.extend_cliques(&mut clique, &candidates, max_size, &mut by_size);
+            clique.pop();
+        }
+        by_size.into_iter().flatten().collect()
+    }
+
+    /// Records the clique and extends it by each candidate, all of which are adjacent to every member and larger than it.
+    fn extend_cliques(
+        &self,
+        clique: &mut Vec<usize>,
+        candidates: &[usize],
+        max_size: usize,
+        by_size: &mut [Vec<Vec<usize>>],
+    ) {
+        if clique.len() > max_size {
+            return;
+        }
+        by_size[clique.len()].push(clique.clone());
+        for (i, &w) in candidates.iter().enumerate() {
+            let remaining: Vec<usize> = candidates[i + 1..]
+                .iter()
+                .copied()
+                .filter(|&x| self.is_adjacent(w, x))
+                .collect();
+            clique.push(w);
+            self.extend_cliques(clique, &remaining, max_size, by_size);
+            clique.pop();
+        }
+    }
+
+    /// Returns the eigenvalues of the adjacency matrix in decreasing order,
+    /// computed with the cyclic Jacobi method for symmetric matrices.
+    pub fn spectrum(&self) -> Vec<f64> {
+        let n = self.vertex_count();
+        let mut a = vec![vec![0.0; n]; n];
+        for (v, neighbors) in self.neighbors.iter().enumerate() {
+            for &w in neighbors {
+                a[v][w] = 1.0;
+            }
+        }
+        let mut eigenvalues = symmetric_eigenvalues(a);
+        eigenvalues.sort_by(|x, y| y.total_cmp(x));
+        eigenvalues
+    }
+}
+
+/// Returns the eigenvalues of a symmetric matrix, by Jacobi rotations that annihilate the off-diagonal entries
+/// one at a time, sweeping until they are negligible.
+fn symmetric_eigenvalues(mut a: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = a.len();
+    let scale: f64 = a.iter().flatten().map(|x| x * x).sum::<f64>().max(1.0);
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|p| (0..n).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        if off <= 1e-22 * scale {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    (*apk, *aqk) = (c * *apk - s * *aqk, s * *apk + c * *aqk);
+                }
+            }
+        }
+    }
+    (0..n).map(|i| a[i][i]).collect()
+}
@@ -0,0 +1,195 @@
+//! Vectorized kernels for `Octavian<i32>`, `Octavian<i64>` and `Octavian<f64>`, enabled by the `simd` feature.
+//! The generic arithmetic calls into these kernels when the coefficient type is one of the supported primitives
+//! and otherwise falls back to the scalar code, so the public API is the same with or without the feature.
+//! Integer lanes wrap on overflow, so the integer kernels are only used when the magnitudes of the operands
+//! bound every partial sum, of the kernel and of the scalar code alike, within the coefficient type.
+//! Larger operands take the scalar path, and overflow there as they would without the feature.
+
+use crate::octavian::{Coefficient, Octavian};
+use crate::tables;
+use wide::{f64x4, i32x8, i64x4};
+
+/// The arithmetic on eight coefficients at a time for one primitive coefficient type.
+/// Results agree with the scalar code, which sums in the same order, whenever no partial sum overflows.
+pub(crate) trait Kernel: Coefficient {
+    /// The largest magnitude a lane can hold without wrapping, unbounded for floating-point lanes.
+    const LIMIT: u128;
+    /// The magnitude of a lane value, or 0 for floating-point lanes, which do not wrap.
+    fn magnitude(self) -> u128;
+    fn add(x: [Self; 8], y: [Self; 8]) -> [Self; 8];
+    /// Returns m·x, as the sum over j of x_j times the j-th column of m.
+    fn matvec(m: &[[Self; 8]; 8], x: &[Self; 8]) -> [Self; 8];
+    fn dot(x: [Self; 8], y: [Self; 8]) -> Self;
+}
+
+impl Kernel for i32 {
+    const LIMIT: u128 = i32::MAX as u128;
+
+    fn magnitude(self) -> u128 {
+        u128::from(self.unsigned_abs())
+    }
+
+    fn add(x: [i32; 8], y: [i32; 8]) -> [i32; 8] {
+        (i32x8::new(x) + i32x8::new(y)).to_array()
+    }
+
+    fn matvec(m: &[[i32; 8]; 8], x: &[i32; 8]) -> [i32; 8] {
+        let mut sum = i32x8::ZERO;
+        for (j, &xj) in x.iter().enumerate() {
+            sum += i32x8::new(m.map(|row| row[j])) * i32x8::splat(xj);
+        }
+        sum.to_array()
+    }
+
+    fn dot(x: [i32; 8], y: [i32; 8]) -> i32 {
+        (i32x8::new(x) * i32x8::new(y))
+            .to_array()
+            .iter()
+            .fold(0, |a, &b| a.wrapping_add(b))
+    }
+}
+
+/// Implements `Kernel` for a type whose eight coefficients fill two vectors of four lanes.
+macro_rules! impl_two_lane_groups {
+    ($t:ty, $v:ty, $limit:expr, $magnitude:expr, $add:expr) => {
+        impl Kernel for $t {
+            const LIMIT: u128 = $limit;
+
+            fn magnitude(self) -> u128 {
+                $magnitude(self)
+            }
+
+            fn add(x: [$t; 8], y: [$t; 8]) -> [$t; 8] {
+                let (x, y) = (split::<$t, $v>(x), split::<$t, $v>(y));
+                join([x[0] + y[0], x[1] + y[1]])
+            }
+
+            fn matvec(m: &[[$t; 8]; 8], x: &[$t; 8]) -> [$t; 8] {
+                let mut sum = [<$v>::ZERO; 2];
+                for (j, &xj) in x.iter().enumerate() {
+                    let column = split::<$t, $v>(m.map(|row| row[j]));
+                    let xj = <$v>::splat(xj);
+                    sum = [sum[0] + column[0] * xj, sum[1] + column[1] * xj];
+                }
+                join(sum)
+            }
+
+            fn dot(x: [$t; 8], y: [$t; 8]) -> $t {
+                let (x, y) = (split::<$t, $v>(x), split::<$t, $v>(y));
+                join([x[0] * y[0], x[1] * y[1]])
+                    .into_iter()
+                    .fold(<$t>::default(), $add)
+            }
+        }
+    };
+}
+
+/// Splits eight coefficients into two vectors of four lanes.
+fn split<T: Copy, V: From<[T; 4]>>(x: [T; 8]) -> [V; 2] {
+    [
+        V::from([x[0], x[1], x[2], x[3]]),
+        V::from([x[4], x[5], x[6], x[7]]),
+    ]
+}
+
+/// Joins two vectors of four lanes back into eight coefficients.
+fn join<T: Copy + Default, V: Into<[T; 4]>>(v: [V; 2]) -> [T; 8] {
+    let [a, b] = v.map(Into::into);
+    let mut x = [T::default(); 8];
+    x[..4].copy_from_slice(&a);
+    x[4..].copy_from_slice(&b);
+    x
+}
+
+impl_two_lane_groups!(
+    i64,
+    i64x4,
+    i64::MAX as u128,
+    |x: i64| u128::from(x.unsigned_abs()),
+    i64::wrapping_add
+);
+impl_two_lane_groups!(f64, f64x4, u128::MAX, |_| 0, |a: f64, b: f64| a + b);
+
+/// Returns the largest magnitude among the coefficients.
+fn max_magnitude<K: Kernel>(x: &[K]) -> u128 {
+    x.iter().map(|&c| c.magnitude()).max().unwrap_or(0)
+}
+
+/// Tests whether sums of at most `weight` products of a coefficient of magnitude at most `a` and one of magnitude
+/// at most `b` stay within the lanes of `K`.
+fn fits<K: Kernel>(weight: u128, a: u128, b: u128) -> bool {
+    within::<K>(weight.saturating_mul(a).saturating_mul(b))
+}
+
+/// Tests whether a bound on the magnitude of a value stays within the lanes of `K`.
+fn within<K: Kernel>(bound: u128) -> bool {
+    bound <= K::LIMIT
+}
+
+/// The sum of the magnitudes of the entries of the Gram matrix: every partial sum of an inner product is at most
+/// this times the largest coefficients of the two vectors.
+const GRAM_WEIGHT: u128 = {
+    let gram = Octavian::<i8>::GRAM_MATRIX;
+    let mut weight = 0;
+    let mut i = 0;
+    while i < 8 {
+        let mut j = 0;
+        while j < 8 {
+            weight += gram[i][j].unsigned_abs() as u128;
+            j += 1;
+        }
+        i += 1;
+    }
+    weight
+};
+
+/// The largest sum of the magnitudes of the structure constants contributing to one coefficient of a product:
+/// every partial sum of a product, and every entry of a left adjoint matrix, is at most this times the largest
+/// coefficients of the factors.
+const PRODUCT_WEIGHT: u128 = {
+    let adjoint = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    let mut weight = 0;
+    let mut i = 0;
+    while i < 8 {
+        let mut row = 0;
+        let mut j = 0;
+        while j < 8 {
+            let mut k = 0;
+            while k < 8 {
+                row += adjoint[k][i][j].unsigned_abs() as u128;
+                k += 1;
+            }
+            j += 1;
+        }
+        if row > weight {
+            weight = row;
+        }
+        i += 1;
+    }
+    weight
+};
+
+/// Tests whether the product of two coefficient vectors can be computed by the vectorized dense path:
+/// the factors are small enough that neither that path nor the scalar ones overflow.
+pub(crate) fn mul_fits<K: Kernel>(x: &[K; 8], y: &[K; 8]) -> bool {
+    fits::<K>(PRODUCT_WEIGHT, max_magnitude(x), max_magnitude(y).max(1))
+}
+
+/// Adds two coefficient vectors, or returns `None` if the sum could overflow.
+pub(crate) fn add<K: Kernel>(x: &[K; 8], y: &[K; 8]) -> Option<[K; 8]> {
+    let bound = max_magnitude(x).saturating_add(max_magnitude(y));
+    within::<K>(bound).then(|| <K as Kernel>::add(*x, *y))
+}
+
+/// Multiplies a coefficient vector by a matrix on the left, or returns `None` if a partial sum could overflow.
+pub(crate) fn matvec<K: Kernel>(m: &[[K; 8]; 8], x: &[K; 8]) -> Option<[K; 8]> {
+    fits::<K>(8, max_magnitude(m.as_flattened()), max_magnitude(x)).then(|| K::matvec(m, x))
+}
+
+/// Returns the E8 inner product of two coefficient vectors, or `None` if a partial sum could overflow.
+pub(crate) fn inner_product<K: Kernel>(x: &[K; 8], y: &[K; 8]) -> Option<K> {
+    fits::<K>(GRAM_WEIGHT, max_magnitude(x), max_magnitude(y)).then(|| {
+        let gy = tables::with_gram_matrix(|gram: &tables::GramMatrix<K>| K::matvec(gram, y));
+        K::dot(*x, gy)
+    })
+}
@@ -0,0 +1,77 @@
+//! Folds over slices of octavians, so that sums, products and norms of a list need no hand-written loops.
+
+use crate::octavian::{Coefficient, Octavian};
+use core::hash::Hash;
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+use std::collections::HashSet;
+
+/// Extension methods on slices of octavians.
+pub trait OctavianSliceExt<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Returns the sum of the octavians, `zero()` for an empty slice.
+    fn sum_octavians(&self) -> Octavian<T>;
+
+    /// Returns the product of the octavians associated to the left, ((x0 x1) x2) ..., and `one()` for an empty slice.
+    /// Multiplication is not associative, so the bracketing matters.
+    fn product_left(&self) -> Octavian<T>;
+
+    /// Returns the norm of each octavian.
+    fn norms(&self) -> Vec<T>;
+
+    /// Returns the largest norm, or `None` for an empty slice or if some norms are incomparable, such as NaN.
+    fn max_norm(&self) -> Option<T>
+    where
+        T: PartialOrd;
+
+    /// Returns the distinct octavians in order of their first occurrence.
+    fn dedup_exact(&self) -> Vec<Octavian<T>>
+    where
+        T: Hash + Eq;
+}
+
+impl<T> OctavianSliceExt<T> for [Octavian<T>]
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn sum_octavians(&self) -> Octavian<T> {
+        let mut sum = Octavian::zero();
+        for x in self {
+            sum.add_assign_ref(x);
+        }
+        sum
+    }
+
+    fn product_left(&self) -> Octavian<T> {
+        match self.split_first() {
+            Some((&first, rest)) => rest.iter().fold(first, |product, &x| product * x),
+            None => Octavian::one(),
+        }
+    }
+
+    fn norms(&self) -> Vec<T> {
+        self.iter().map(Octavian::norm).collect()
+    }
+
+    fn max_norm(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        let mut norms = self.iter().map(Octavian::norm);
+        let first = norms.next()?;
+        norms.try_fold(first, |max, n| match n.partial_cmp(&max)? {
+            core::cmp::Ordering::Greater => Some(n),
+            _ => Some(max),
+        })
+    }
+
+    fn dedup_exact(&self) -> Vec<Octavian<T>>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::new();
+        self.iter().copied().filter(|x| seen.insert(*x)).collect()
+    }
+}
@@ -0,0 +1,67 @@
+use crate::lattice::{hermite_normal_form, sublattice_index};
+use crate::octavian::Octavian;
+
+/// The isomorphism type of a subring of the octavian integers, determined by its rank.
+/// Since the octonions form a division algebra over the rationals, the only possible ranks are 1, 2, 4 and 8.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SubalgebraType {
+    /// The rational integers.
+    Rational,
+    /// An order in an imaginary quadratic field.
+    ImaginaryQuadratic,
+    /// An order in a definite quaternion algebra.
+    Quaternionic,
+    /// An order of full rank in the octonions.
+    Octonionic,
+}
+
+/// A subring of the octavian integers, described by a Z-basis in Hermite normal form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubalgebraInfo {
+    /// The rank of the subring as a Z-module.
+    pub rank: usize,
+    /// A Z-basis of the subring.
+    pub basis: Vec<Octavian<i64>>,
+    /// The type of the subring.
+    pub kind: SubalgebraType,
+}
+
+impl SubalgebraInfo {
+    /// Tests whether the subring is the whole ring of octavian integers.
+    pub fn is_whole_order(&self) -> bool {
+        sublattice_index(&self.basis) == Some(1)
+    }
+}
+
+/// Returns the smallest subring of the octavian integers containing `one()` and the given elements.
+/// The Z-span of the generators is repeatedly enlarged by the products of its basis vectors until it is closed under multiplication.
+pub fn smallest_subalgebra_containing(elements: &[Octavian<i64>]) -> SubalgebraInfo {
+    let mut generators = vec![Octavian::<i64>::one().coefficients];
+    generators.extend(elements.iter().map(|x| x.coefficients));
+    let mut basis = hermite_normal_form(&generators);
+    loop {
+        let mut products = basis.clone();
+        for x in &basis {
+            for y in &basis {
+                products.push((Octavian::new(*x) * Octavian::new(*y)).coefficients);
+            }
+        }
+        let closure = hermite_normal_form(&products);
+        if closure == basis {
+            break;
+        }
+        basis = closure;
+    }
+    let kind = match basis.len() {
+        1 => SubalgebraType::Rational,
+        2 => SubalgebraType::ImaginaryQuadratic,
+        4 => SubalgebraType::Quaternionic,
+        8 => SubalgebraType::Octonionic,
+        rank => unreachable!("a subalgebra of the octonions cannot have rank {rank}"),
+    };
+    SubalgebraInfo {
+        rank: basis.len(),
+        basis: basis.into_iter().map(Octavian::new).collect(),
+        kind,
+    }
+}
@@ -0,0 +1,421 @@
+//! Polynomials with integer coefficients in the variables x1, ..., x8, y1, ..., y8, used as octavian coefficients
+//! to check identities symbolically.
+//!
+//! With `symbolic_x()` and `symbolic_y()`, whose coefficients are the variables themselves, each coefficient of an
+//! expression in x and y is a polynomial. An identity holds for all octavians, and over every commutative ring,
+//! exactly when both sides have the same polynomials. One evaluation therefore proves it for the structure constants,
+//! where random samples could only make it likely.
+//!
+//! `Octavian` requires `Copy` coefficients, so a `MultiPoly` is a handle to a polynomial interned in a table shared
+//! by the process. Equal polynomials get equal handles, which makes comparison cheap. Interned polynomials are never
+//! freed, which suits one-off checks but not long-running computations.
+
+use crate::octavian::{sealed, Coefficient, Octavian};
+use core::fmt;
+use core::num::ParseIntError;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use num_traits::{FromPrimitive, Num, One, Zero};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+/// The number of variables: x1, ..., x8 and then y1, ..., y8.
+pub const VARIABLES: usize = 16;
+
+/// The exponents of x1, ..., x8, y1, ..., y8 in a monomial.
+pub type Monomial = [u8; VARIABLES];
+
+/// The nonzero coefficients of a polynomial, by monomial.
+type Terms = BTreeMap<Monomial, i64>;
+
+/// The interned polynomials. The zero polynomial has index 0 and the constant 1 index 1.
+struct Interner {
+    polynomials: Vec<Terms>,
+    indices: HashMap<Terms, u32>,
+}
+
+impl Interner {
+    fn intern(&mut self, terms: Terms) -> MultiPoly {
+        if let Some(&index) = self.indices.get(&terms) {
+            return MultiPoly(index);
+        }
+        let index = u32::try_from(self.polynomials.len()).expect("too many interned polynomials");
+        self.polynomials.push(terms.clone());
+        self.indices.insert(terms, index);
+        MultiPoly(index)
+    }
+}
+
+/// Runs `f` on the interner, which is created with the zero polynomial and 1 on first use.
+fn with_interner<R>(f: impl FnOnce(&mut Interner) -> R) -> R {
+    static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+    let interner = INTERNER.get_or_init(|| {
+        let mut interner = Interner {
+            polynomials: Vec::new(),
+            indices: HashMap::new(),
+        };
+        interner.intern(Terms::new());
+        interner.intern(Terms::from([([0; VARIABLES], 1)]));
+        Mutex::new(interner)
+    });
+    // The interner is only modified by `intern`, which leaves it consistent even if a caller panicked.
+    f(&mut interner.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Combines the terms of two polynomials into a new interned polynomial.
+fn combine(a: MultiPoly, b: MultiPoly, f: impl FnOnce(&Terms, &Terms) -> Terms) -> MultiPoly {
+    with_interner(|interner| {
+        let terms = f(
+            &interner.polynomials[a.0 as usize],
+            &interner.polynomials[b.0 as usize],
+        );
+        interner.intern(terms)
+    })
+}
+
+/// Adds `c` times the monomial to the terms, removing it if its coefficient becomes 0.
+fn add_term(terms: &mut Terms, monomial: Monomial, c: i64) {
+    let entry = terms.entry(monomial).or_insert(0);
+    *entry += c;
+    if *entry == 0 {
+        terms.remove(&monomial);
+    }
+}
+
+/// A polynomial with `i64` coefficients in x1, ..., x8, y1, ..., y8.
+///
+/// Arithmetic follows `i64`: coefficients overflow as `i64` does, and division and remainder are only defined
+/// by constants, applied to each coefficient. These are enough for `norm`, which halves an even polynomial.
+/// Conversions from floats truncate like those of the integers, so `Octavian` multiplies it as an integer type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MultiPoly(u32);
+
+impl MultiPoly {
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: i64) -> Self {
+        match c {
+            0 => Self::zero(),
+            1 => Self::one(),
+            _ => with_interner(|interner| interner.intern(Terms::from([([0; VARIABLES], c)]))),
+        }
+    }
+
+    /// Returns the variable with the given index, where 0, ..., 7 are x1, ..., x8 and 8, ..., 15 are y1, ..., y8.
+    ///
+    /// # Panics
+    /// Panics if `index` is not below `VARIABLES`.
+    pub fn variable(index: usize) -> Self {
+        assert!(index < VARIABLES, "there are {} variables", VARIABLES);
+        let mut monomial = [0; VARIABLES];
+        monomial[index] = 1;
+        with_interner(|interner| interner.intern(Terms::from([(monomial, 1)])))
+    }
+
+    /// Returns the nonzero terms as (exponents, coefficient) pairs, in increasing lexicographic order of the exponents.
+    pub fn terms(&self) -> Vec<(Monomial, i64)> {
+        with_interner(|interner| {
+            interner.polynomials[self.0 as usize]
+                .iter()
+                .map(|(&m, &c)| (m, c))
+                .collect()
+        })
+    }
+
+    /// Returns the total degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<u32> {
+        self.terms()
+            .iter()
+            .map(|(m, _)| m.iter().map(|&e| u32::from(e)).sum())
+            .max()
+    }
+
+    /// Returns the value of the polynomial if it is constant.
+    pub fn as_constant(&self) -> Option<i64> {
+        match self.terms().as_slice() {
+            [] => Some(0),
+            [(m, c)] if *m == [0; VARIABLES] => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Applies `f` to each coefficient, for division and remainder by a constant.
+    fn map_coefficients(self, f: impl Fn(i64) -> i64) -> Self {
+        with_interner(|interner| {
+            let terms = interner.polynomials[self.0 as usize]
+                .iter()
+                .map(|(&m, &c)| (m, f(c)))
+                .filter(|&(_, c)| c != 0)
+                .collect();
+            interner.intern(terms)
+        })
+    }
+}
+
+impl Add for MultiPoly {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            return self;
+        }
+        if self.is_zero() {
+            return rhs;
+        }
+        combine(self, rhs, |a, b| {
+            let mut sum = a.clone();
+            for (&m, &c) in b {
+                add_term(&mut sum, m, c);
+            }
+            sum
+        })
+    }
+}
+
+impl Sub for MultiPoly {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for MultiPoly {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map_coefficients(|c| -c)
+    }
+}
+
+impl Mul for MultiPoly {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        if self.is_one() {
+            return rhs;
+        }
+        if rhs.is_one() {
+            return self;
+        }
+        combine(self, rhs, |a, b| {
+            let mut product = Terms::new();
+            for (ma, &ca) in a {
+                for (mb, &cb) in b {
+                    let monomial: Monomial = core::array::from_fn(|i| {
+                        ma[i].checked_add(mb[i]).expect("exponent overflow")
+                    });
+                    add_term(&mut product, monomial, ca * cb);
+                }
+            }
+            product
+        })
+    }
+}
+
+/// Divides each coefficient by a constant, truncating like `i64`.
+///
+/// # Panics
+/// Panics if the divisor is not a nonzero constant.
+impl Div for MultiPoly {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let d = rhs
+            .as_constant()
+            .expect("a MultiPoly can only be divided by a constant");
+        self.map_coefficients(|c| c / d)
+    }
+}
+
+/// Takes the remainder of each coefficient by a constant, like `i64`.
+///
+/// # Panics
+/// Panics if the divisor is not a nonzero constant.
+impl Rem for MultiPoly {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        let d = rhs
+            .as_constant()
+            .expect("a MultiPoly can only be divided by a constant");
+        self.map_coefficients(|c| c % d)
+    }
+}
+
+impl Zero for MultiPoly {
+    fn zero() -> Self {
+        MultiPoly(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for MultiPoly {
+    fn one() -> Self {
+        MultiPoly(1)
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+/// Parses an integer, as a constant.
+impl Num for MultiPoly {
+    type FromStrRadixErr = ParseIntError;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i64::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl FromPrimitive for MultiPoly {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::constant(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        i64::try_from(n).ok().map(Self::constant)
+    }
+}
+
+impl sealed::Sealed for MultiPoly {}
+
+impl Coefficient for MultiPoly {}
+
+/// Writes the terms in the order of `terms`, increasing in the exponents, so that a constant term comes first,
+/// as in `1 + 3 x1 y2 - x1^2`.
+impl fmt::Display for MultiPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self.terms();
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (n, (monomial, c)) in terms.iter().enumerate() {
+            let sign = if *c < 0 { "-" } else { "+" };
+            match n {
+                0 if *c < 0 => write!(f, "-")?,
+                0 => {}
+                _ => write!(f, " {} ", sign)?,
+            }
+            let constant = *monomial == [0; VARIABLES];
+            if c.unsigned_abs() != 1 || constant {
+                write!(f, "{}", c.unsigned_abs())?;
+            }
+            let mut first = c.unsigned_abs() == 1 && !constant;
+            for (i, &e) in monomial.iter().enumerate().filter(|&(_, &e)| e > 0) {
+                if !first {
+                    write!(f, " ")?;
+                }
+                first = false;
+                let name = if i < 8 { 'x' } else { 'y' };
+                write!(f, "{}{}", name, i % 8 + 1)?;
+                if e > 1 {
+                    write!(f, "^{}", e)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for MultiPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MultiPoly({})", self)
+    }
+}
+
+/// Returns the octavian x with coefficients x1, ..., x8.
+pub fn symbolic_x() -> Octavian<MultiPoly> {
+    Octavian::new(core::array::from_fn(MultiPoly::variable))
+}
+
+/// Returns the octavian y with coefficients y1, ..., y8.
+pub fn symbolic_y() -> Octavian<MultiPoly> {
+    Octavian::new(core::array::from_fn(|i| MultiPoly::variable(i + 8)))
+}
+
+/// Multiplies two octavians with the given left adjoint matrices in place of
+/// `Octavian::OCTAVIAN_ADJOINT_MATRICES`, so that candidate tables can be checked before they are generated.
+pub fn mul_with_table(
+    adjoint: &[[[i8; 8]; 8]; 8],
+    x: &Octavian<MultiPoly>,
+    y: &Octavian<MultiPoly>,
+) -> Octavian<MultiPoly> {
+    let mut product = [MultiPoly::zero(); 8];
+    for (matrix, &a) in adjoint.iter().zip(&x.coefficients) {
+        for (j, &b) in y.coefficients.iter().enumerate() {
+            let ab = a * b;
+            for (c, row) in product.iter_mut().zip(matrix) {
+                if row[j] != 0 {
+                    *c = *c + MultiPoly::constant(i64::from(row[j])) * ab;
+                }
+            }
+        }
+    }
+    Octavian::new(product)
+}
+
+/// An identity of the octavians checked by `verify_identities`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Identity {
+    /// conj(x y) = conj(y) conj(x).
+    ConjugateAntiHomomorphism,
+    /// N(x y) = N(x) N(y).
+    NormMultiplicative,
+    /// (x x) y = x (x y).
+    LeftAlternative,
+    /// (y x) x = y (x x).
+    RightAlternative,
+}
+
+impl fmt::Display for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Identity::ConjugateAntiHomomorphism => write!(f, "conj(x y) = conj(y) conj(x)"),
+            Identity::NormMultiplicative => write!(f, "N(x y) = N(x) N(y)"),
+            Identity::LeftAlternative => write!(f, "(x x) y = x (x y)"),
+            Identity::RightAlternative => write!(f, "(y x) x = y (x x)"),
+        }
+    }
+}
+
+/// Checks the identities symbolically for the multiplication `mul`, returning those that fail.
+/// Conjugation and the norm come from `one()` and the Gram matrix, as for `verify_tables`.
+pub fn verify_identities_with(
+    mul: impl Fn(&Octavian<MultiPoly>, &Octavian<MultiPoly>) -> Octavian<MultiPoly>,
+) -> Result<(), Vec<Identity>> {
+    let (x, y) = (symbolic_x(), symbolic_y());
+    let xy = mul(&x, &y);
+    let xx = mul(&x, &x);
+    let checks = [
+        (
+            Identity::ConjugateAntiHomomorphism,
+            xy.conjugate() == mul(&y.conjugate(), &x.conjugate()),
+        ),
+        (
+            Identity::NormMultiplicative,
+            xy.norm() == x.norm() * y.norm(),
+        ),
+        (
+            Identity::LeftAlternative,
+            mul(&xx, &y) == mul(&x, &mul(&x, &y)),
+        ),
+        (
+            Identity::RightAlternative,
+            mul(&mul(&y, &x), &x) == mul(&y, &xx),
+        ),
+    ];
+    let failed: Vec<Identity> = checks
+        .iter()
+        .filter(|&&(_, holds)| !holds)
+        .map(|&(identity, _)| identity)
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(failed)
+    }
+}
+
+/// Checks the identities symbolically for the product of `Octavian<MultiPoly>`.
+pub fn verify_identities() -> Result<(), Vec<Identity>> {
+    verify_identities_with(|x, y| *x * *y)
+}
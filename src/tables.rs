@@ -0,0 +1,176 @@
+//! Derived tables, each computed at most once per process and shared as a `'static` reference.
+//! The constant tables on `Octavian` are the source of truth; everything here is derived from them on first use.
+//! The tables converted to a generic coefficient type are cached for the primitive numeric types only;
+//! products with other types read the entries they need one at a time instead of converting the whole table.
+
+use crate::basis::unimodular_inverse;
+use crate::octavian::{from_i8, Coefficient, Octavian};
+use num_traits::FromPrimitive;
+#[cfg(all(feature = "par", feature = "tables"))]
+use rayon::prelude::*;
+#[cfg(feature = "tables")]
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// The set of unit octavians, computed once, for constant-time membership tests.
+#[cfg(feature = "tables")]
+pub fn unit_set() -> &'static HashSet<Octavian<i8>> {
+    static UNIT_SET: OnceLock<HashSet<Octavian<i8>>> = OnceLock::new();
+    UNIT_SET.get_or_init(|| Octavian::UNITS.into_iter().collect())
+}
+
+/// The multiplication table of the unit octavians, computed once.
+/// Entry `[i][j]` is the index of the product `UNITS[i] * UNITS[j]`, so that loop computations can work with indices alone.
+#[cfg(feature = "tables")]
+pub fn unit_multiplication_table() -> &'static [[u16; 240]; 240] {
+    static TABLE: OnceLock<[[u16; 240]; 240]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [[0u16; 240]; 240];
+        #[cfg(feature = "par")]
+        let rows = table.par_iter_mut();
+        #[cfg(not(feature = "par"))]
+        let rows = table.iter_mut();
+        rows.enumerate().for_each(|(i, row)| {
+            for (j, entry) in row.iter_mut().enumerate() {
+                let product = Octavian::UNITS[i] * Octavian::UNITS[j];
+                *entry = product
+                    .unit_index()
+                    .expect("the units are closed under multiplication");
+            }
+        });
+        table
+    })
+}
+
+/// The index of the inverse of each unit, read off the multiplication table once.
+#[cfg(feature = "tables")]
+pub fn unit_inverses() -> &'static [u16; 240] {
+    static INVERSES: OnceLock<[u16; 240]> = OnceLock::new();
+    INVERSES.get_or_init(|| {
+        let table = unit_multiplication_table();
+        let identity = Octavian::<i8>::one().unit_index().unwrap();
+        core::array::from_fn(|i| {
+            table[i]
+                .iter()
+                .position(|&p| p == identity)
+                .expect("every unit has an inverse") as u16
+        })
+    })
+}
+
+/// The Gram matrix with `i64` entries.
+pub fn gram_matrix_i64() -> &'static [[i64; 8]; 8] {
+    static GRAM: OnceLock<[[i64; 8]; 8]> = OnceLock::new();
+    GRAM.get_or_init(|| Octavian::<i8>::GRAM_MATRIX.map(|row| row.map(i64::from)))
+}
+
+/// The inverse of the Gram matrix, which is an integer matrix because E8 is unimodular.
+pub fn inverse_gram_matrix_i64() -> &'static [[i64; 8]; 8] {
+    static INVERSE_GRAM: OnceLock<[[i64; 8]; 8]> = OnceLock::new();
+    INVERSE_GRAM.get_or_init(|| {
+        unimodular_inverse(gram_matrix_i64()).expect("the Gram matrix is unimodular")
+    })
+}
+
+/// The left adjoint matrices of the basis vectors with `i64` entries, in the layout of `Octavian::OCTAVIAN_ADJOINT_MATRICES`.
+pub fn adjoint_matrices_i64() -> &'static [[[i64; 8]; 8]; 8] {
+    static ADJOINT: OnceLock<[[[i64; 8]; 8]; 8]> = OnceLock::new();
+    ADJOINT.get_or_init(|| {
+        Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES.map(|m| m.map(|row| row.map(i64::from)))
+    })
+}
+
+/// The left adjoint matrices of the basis vectors with entries of type `T`.
+pub type AdjointMatrices<T> = [[[T; 8]; 8]; 8];
+
+/// The Gram matrix with entries of type `T`.
+pub type GramMatrix<T> = [[T; 8]; 8];
+
+#[cfg(test)]
+thread_local! {
+    /// Counts the conversions of the constant tables into a coefficient type made by the current thread.
+    pub(crate) static TABLE_CONVERSIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Converts the left adjoint matrices into `T`, for the first use of the cache of a primitive type
+/// or for every use by the other coefficient types.
+pub(crate) fn convert_adjoint_matrices<T: FromPrimitive>() -> AdjointMatrices<T> {
+    #[cfg(test)]
+    TABLE_CONVERSIONS.with(|c| c.set(c.get() + 1));
+    Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES.map(|m| m.map(|row| row.map(from_i8)))
+}
+
+/// Converts the Gram matrix into `T`, like `convert_adjoint_matrices`.
+pub(crate) fn convert_gram_matrix<T: FromPrimitive>() -> GramMatrix<T> {
+    #[cfg(test)]
+    TABLE_CONVERSIONS.with(|c| c.set(c.get() + 1));
+    Octavian::<i8>::GRAM_MATRIX.map(|row| row.map(from_i8))
+}
+
+/// Runs `f` on the left adjoint matrices converted to `T`.
+/// The conversion is cached for the primitive integer and floating-point types, so generic code can call this per product.
+pub fn with_adjoint_matrices<T: Coefficient, R>(f: impl FnOnce(&AdjointMatrices<T>) -> R) -> R {
+    T::with_adjoint_matrices(f)
+}
+
+/// Runs `f` on the Gram matrix converted to `T`, cached like `with_adjoint_matrices`.
+pub fn with_gram_matrix<T: Coefficient, R>(f: impl FnOnce(&GramMatrix<T>) -> R) -> R {
+    T::with_gram_matrix(f)
+}
+
+/// The entries of the left adjoint matrices in a coefficient type, read from the cached table when there is one
+/// and otherwise converted one at a time, so that a product never converts the whole table.
+pub(crate) enum AdjointEntries<'a, T> {
+    /// The table cached for a primitive type.
+    Cached(&'a AdjointMatrices<T>),
+    /// No cached table: entries are converted from `Octavian::OCTAVIAN_ADJOINT_MATRICES` when read.
+    Converted,
+}
+
+impl<T: FromPrimitive + Copy> AdjointEntries<'_, T> {
+    /// Returns the entry `[k][i][j]`.
+    pub(crate) fn get(&self, k: usize, i: usize, j: usize) -> T {
+        match self {
+            AdjointEntries::Cached(adjoint) => adjoint[k][i][j],
+            AdjointEntries::Converted => {
+                from_i8(Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES[k][i][j])
+            }
+        }
+    }
+}
+
+/// Runs `f` on the entries of the left adjoint matrices in `T`, without converting the whole table for types
+/// that have no cache.
+pub(crate) fn with_adjoint_entries<T: Coefficient, R>(
+    f: impl FnOnce(AdjointEntries<'_, T>) -> R,
+) -> R {
+    if T::CACHED_TABLES {
+        with_adjoint_matrices(|adjoint| f(AdjointEntries::Cached(adjoint)))
+    } else {
+        f(AdjointEntries::Converted)
+    }
+}
+
+/// Returns a copy of the left adjoint matrices converted to `T`.
+pub fn adjoint_matrices_typed<T: Coefficient>() -> AdjointMatrices<T> {
+    with_adjoint_matrices(|m| *m)
+}
+
+/// Returns a copy of the Gram matrix converted to `T`.
+pub fn gram_matrix_typed<T: Coefficient>() -> GramMatrix<T> {
+    with_gram_matrix(|g| *g)
+}
+
+/// Computes every table in this module now, so that later calls only read them.
+/// Calling it again does nothing.
+pub fn warm_tables() {
+    #[cfg(feature = "tables")]
+    {
+        unit_set();
+        unit_multiplication_table();
+        unit_inverses();
+    }
+    gram_matrix_i64();
+    inverse_gram_matrix_i64();
+    adjoint_matrices_i64();
+}
@@ -1,8 +1,27 @@
 use super::*;
+use basis::Basis;
+use num::rational::Ratio;
+use num::BigInt;
 use octavian::Octavian;
+use quaternion::{HurwitzQuaternion, QuaternionEmbedding};
+#[cfg(feature = "tables")]
+use rand::rngs::StdRng;
+#[cfg(feature = "tables")]
+use rand::SeedableRng;
+#[cfg(feature = "par")]
 use rayon::prelude::*;
+#[cfg(feature = "tables")]
+use std::cell::Cell;
 use std::collections::HashSet;
+#[cfg(feature = "tables")]
+use subalgebra::{smallest_subalgebra_containing, SubalgebraType};
+#[cfg(feature = "tables")]
+use walk::UnitWalk;
+#[cfg(feature = "tables")]
+use weyl::WeylElement;
 
+#[cfg(feature = "tables")]
+#[allow(clippy::needless_range_loop)]
 #[test]
 /// Ensure that the norm works.
 fn test_norm() {
@@ -12,6 +31,7 @@ fn test_norm() {
     }
 }
 
+#[allow(clippy::needless_range_loop)]
 #[test]
 /// Ensure that the trace works.
 fn test_trace() {
@@ -43,6 +63,7 @@ fn test_inner_product() {
     }
 }
 
+#[allow(clippy::clone_on_copy)]
 #[test]
 /// Ensure that addition works.
 fn test_addition() {
@@ -53,6 +74,7 @@ fn test_addition() {
     );
 }
 
+#[allow(clippy::clone_on_copy)]
 #[test]
 /// Ensure that subtraction works.
 fn test_subtraction() {
@@ -67,6 +89,7 @@ fn test_negation() {
     assert_eq!(-one, Octavian::new([2, 3, 4, 6, 5, 4, 3, 2]));
 }
 
+#[cfg(feature = "tables")]
 #[test]
 /// Ensure that the 240 Octavian units form a closed set under multiplication.
 fn closure_of_units() {
@@ -85,6 +108,8 @@ fn closure_of_units() {
     assert_eq!(240, result.len())
 }
 
+#[cfg(all(feature = "tables", feature = "par"))]
+#[allow(clippy::clone_on_copy)]
 #[test]
 fn closure_of_units_parallel() {
     let units: HashSet<Octavian<i8>> = Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS
@@ -101,3 +126,5083 @@ fn closure_of_units_parallel() {
 
     assert_eq!(240, result.len());
 }
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the multiplicative closure of the unit set is the unit set itself.
+fn test_closure_of_unit_set() {
+    let units: Vec<Octavian<i8>> = units::unit_set().iter().copied().collect();
+    assert_eq!(240, units.len());
+    let result = closure::multiplicative_closure(&units, 240);
+    assert!(result.stabilized);
+    assert_eq!(units, result.elements);
+}
+
+#[cfg(all(feature = "tables", feature = "par"))]
+#[test]
+/// Ensure that the parallel closure of the unit set is the unit set itself.
+fn test_closure_of_unit_set_parallel() {
+    let units: Vec<Octavian<i8>> = units::unit_set().iter().copied().collect();
+    let result = closure::multiplicative_closure_par(&units, 240);
+    assert!(result.stabilized);
+    assert_eq!(units, result.elements);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the worklist closure finds the subloops generated by units and basis vectors, and reports truncation at the cap.
+fn test_multiplicative_closure() {
+    let units = Octavian::UNITS.map(|u| Octavian::new(u.coefficients.map(i64::from)));
+    // The unit loop is diassociative, so any two units generate a group, and three basis vectors are needed.
+    let pair = [units[17], units[200]];
+    let result = closure::multiplicative_closure(&pair, 1000);
+    assert!(result.stabilized);
+    assert_eq!(result.elements.len(), 12);
+    let basis = Octavian::<i64>::basis_vectors();
+    let triple = [basis[0], basis[6], basis[7]];
+    let result = closure::multiplicative_closure(&triple, 1000);
+    assert!(result.stabilized);
+    assert_eq!(result.elements.len(), 240);
+    assert_eq!(result.elements[..3], triple);
+    let found: HashSet<_> = result.elements.iter().collect();
+    assert!(units.iter().all(|u| found.contains(u)));
+
+    // A closure with exactly `cap` elements has stabilized; one fewer is a truncation.
+    assert!(closure::multiplicative_closure(&triple, 240).stabilized);
+    let truncated = closure::multiplicative_closure(&triple, 239);
+    assert!(!truncated.stabilized);
+    assert_eq!(truncated.elements.len(), 239);
+
+    // The powers of a non-unit grow without bound.
+    let two = Octavian::<i64>::one().scale(2);
+    let result = closure::multiplicative_closure(&[two, units[5]], 50);
+    assert!(!result.stabilized);
+    assert_eq!(result.elements.len(), 50);
+    let result = closure::multiplicative_closure(&[two, two], 1);
+    assert!(!result.stabilized);
+    assert_eq!(result.elements, [two]);
+    assert!(!closure::multiplicative_closure(&[two, units[5]], 1).stabilized);
+
+    let empty = closure::multiplicative_closure::<i64>(&[], 10);
+    assert!(empty.stabilized && empty.elements.is_empty());
+
+    #[cfg(feature = "par")]
+    for (generators, cap) in [
+        (triple.to_vec(), 1000),
+        (triple.to_vec(), 100),
+        (pair.to_vec(), 1000),
+        (vec![units[3]], 1000),
+        (vec![two, units[5]], 50),
+        (vec![two, units[5]], 1),
+    ] {
+        assert_eq!(
+            closure::multiplicative_closure(&generators, cap),
+            closure::multiplicative_closure_par(&generators, cap)
+        );
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the typed unit constants agree with the raw coefficient table.
+fn test_units_constant() {
+    let product = Octavian::UNITS[0] * Octavian::UNITS[1];
+    assert!(units::unit_set().contains(&product));
+    for (u, coefficients) in Octavian::UNITS
+        .iter()
+        .zip(Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS)
+    {
+        assert_eq!(coefficients, u.coefficients);
+    }
+    let wide = units::units::<i64>();
+    for (u, w) in Octavian::UNITS.iter().zip(&wide) {
+        assert_eq!(u.coefficients.map(i64::from), w.coefficients);
+    }
+    assert!(!units::unit_set().contains(&Octavian::zero()));
+    assert!(std::ptr::eq(units::unit_set(), units::unit_set()));
+}
+
+/// A small deterministic xorshift generator, so that the randomized tests are reproducible.
+struct TestRng(u64);
+
+impl TestRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A random integer in `-bound..=bound`.
+    fn next_i64(&mut self, bound: i64) -> i64 {
+        (self.next_u64() % (2 * bound as u64 + 1)) as i64 - bound
+    }
+
+    fn octavian(&mut self, bound: i64) -> Octavian<i64> {
+        Octavian::new([(); 8].map(|_| self.next_i64(bound)))
+    }
+}
+
+/// A unimodular change of basis: the simple roots are replaced by partial sums along the Dynkin diagram.
+fn partial_sum_basis() -> Basis {
+    let mut matrix = [[0i64; 8]; 8];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for entry in row.iter_mut().take(i + 1) {
+            *entry = 1;
+        }
+    }
+    matrix[3][0] = -2;
+    Basis::new(matrix).unwrap()
+}
+
+#[test]
+/// Ensure that rebasing into another basis and back is the identity.
+fn test_rebase_round_trip() {
+    let standard = Basis::standard();
+    let other = partial_sum_basis();
+    let mut rng = TestRng(0x0c7a_71a5);
+    for _ in 0..100 {
+        let x = rng.octavian(20);
+        assert_eq!(x, x.rebase(&standard, &other).rebase(&other, &standard));
+        assert_eq!(x, x.rebase(&other, &standard).rebase(&standard, &other));
+        assert_eq!(x, x.rebase(&standard, &standard));
+    }
+}
+
+#[test]
+/// Ensure that products and inner products computed in a rebased system agree with the default basis.
+fn test_rebased_arithmetic() {
+    let standard = Basis::standard();
+    let other = partial_sum_basis();
+    let mut rng = TestRng(0x5eed);
+    for _ in 0..100 {
+        let x = rng.octavian(10);
+        let y = rng.octavian(10);
+        let (xo, yo) = (x.rebase(&standard, &other), y.rebase(&standard, &other));
+        assert_eq!((x * y).rebase(&standard, &other), other.mul(&xo, &yo));
+        assert_eq!(x * y, standard.mul(&x, &y));
+        assert_eq!(x.inner_product(&y), other.inner_product(&xo, &yo));
+        assert_eq!(x.norm(), other.norm(&xo));
+    }
+    assert_eq!(
+        Octavian::<i64>::one().rebase(&standard, &other),
+        other.from_standard(&Octavian::one())
+    );
+}
+
+#[test]
+/// Ensure that a change of basis with determinant other than ±1 is rejected.
+fn test_basis_rejects_non_unimodular() {
+    let mut matrix = [[0i64; 8]; 8];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[i] = 1;
+    }
+    matrix[0][0] = 2;
+    assert!(Basis::new(matrix).is_none());
+    matrix[0] = [0; 8];
+    assert!(Basis::new(matrix).is_none());
+    matrix[0] = matrix[1];
+    assert!(Basis::new(matrix).is_none());
+}
+
+/// Multiplies two octonions given in the standard basis 1, e1, ..., e7 using the Fano-plane table.
+fn octonion_mul(a: &[Ratio<i64>; 8], b: &[Ratio<i64>; 8]) -> [Ratio<i64>; 8] {
+    let mut table = [[(1i64, 0usize); 8]; 8];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = (1, i);
+        row[i] = (-1, 0);
+    }
+    table[0] = [0, 1, 2, 3, 4, 5, 6, 7].map(|i| (1, i));
+    for [i, j, k] in fano::FANO_TRIPLES {
+        for (x, y, z) in [(i, j, k), (j, k, i), (k, i, j)] {
+            table[x][y] = (1, z);
+            table[y][x] = (-1, z);
+        }
+    }
+    let mut result = [Ratio::from_integer(0); 8];
+    for i in 0..8 {
+        for j in 0..8 {
+            let (sign, k) = table[i][j];
+            result[k] += a[i] * b[j] * sign;
+        }
+    }
+    result
+}
+
+#[test]
+/// Derive the octonion basis from the requirement that it multiplies according to the Fano plane.
+fn test_octonion_basis_relations() {
+    let e = Octavian::<i64>::OCTONION_BASIS.map(|row| Octavian::new(row.map(i64::from)));
+    let one = Octavian::<i64>::one();
+    assert_eq!(one, e[0]);
+    for x in &e[1..] {
+        assert_eq!(-one, *x * *x);
+        assert_eq!(0, x.trace());
+        assert_eq!(1, x.norm());
+    }
+    for [i, j, k] in fano::FANO_TRIPLES {
+        for (x, y, z) in [(i, j, k), (j, k, i), (k, i, j)] {
+            assert_eq!(e[z], e[x] * e[y]);
+            assert_eq!(-e[z], e[y] * e[x]);
+        }
+    }
+    // Together with the relations above, orthonormality pins the basis down as the image of 1, e1, ..., e7.
+    for i in 0..8 {
+        for j in 0..8 {
+            assert_eq!(if i == j { 2 } else { 0 }, e[i].inner_product(&e[j]));
+        }
+    }
+}
+
+#[test]
+/// Ensure that conversion to octonion coordinates preserves the identity, round-trips, and commutes with multiplication.
+fn test_octonion_coords() {
+    let coords = Octavian::<i64>::one().to_octonion_coords();
+    assert_eq!(Ratio::from_integer(1), coords[0]);
+    assert!(coords[1..].iter().all(|&c| c == Ratio::from_integer(0)));
+
+    let mut rng = TestRng(0xfa70);
+    for _ in 0..200 {
+        let x = rng.octavian(10);
+        let y = rng.octavian(10);
+        let (cx, cy) = (x.to_octonion_coords(), y.to_octonion_coords());
+        assert!(cx.iter().all(|c| (c * 2).is_integer()));
+        assert_eq!(Some(x), Octavian::from_octonion_coords(cx));
+        assert_eq!((x * y).to_octonion_coords(), octonion_mul(&cx, &cy));
+    }
+}
+
+#[test]
+/// Ensure that octonions outside the octavian integers are rejected.
+fn test_octonion_coords_non_integral() {
+    let half = Ratio::new(1, 2);
+    let zero = Ratio::from_integer(0);
+    assert_eq!(
+        None,
+        Octavian::from_octonion_coords([half, zero, zero, zero, zero, zero, zero, zero])
+    );
+    assert_eq!(None, Octavian::from_octonion_coords([Ratio::new(1, 3); 8]));
+    assert_eq!(
+        Some(Octavian::zero()),
+        Octavian::from_octonion_coords([zero; 8])
+    );
+}
+
+#[test]
+/// Ensure that the named imaginary units satisfy the Fano-plane relations.
+fn test_imaginary_unit_constants() {
+    let e = [
+        Octavian::one(),
+        Octavian::E1,
+        Octavian::E2,
+        Octavian::E3,
+        Octavian::E4,
+        Octavian::E5,
+        Octavian::E6,
+        Octavian::E7,
+    ];
+    for (x, row) in e.iter().zip(Octavian::<i8>::OCTONION_BASIS) {
+        assert_eq!(Octavian::new(row), *x);
+    }
+    for x in &e[1..] {
+        assert_eq!(0, x.trace());
+        assert_eq!(1, x.norm());
+        assert_eq!(-Octavian::one(), *x * *x);
+    }
+    for [i, j, k] in fano::FANO_TRIPLES {
+        for (x, y, z) in [(i, j, k), (j, k, i), (k, i, j)] {
+            assert_eq!(e[z], e[x] * e[y]);
+            assert_eq!(-e[z], e[y] * e[x]);
+        }
+    }
+    assert_eq!(Octavian::K, Octavian::I * Octavian::J);
+    assert_eq!(Octavian::I, Octavian::J * Octavian::K);
+    assert_eq!(Octavian::J, Octavian::K * Octavian::I);
+    assert_eq!(Octavian::E5, Octavian::I * Octavian::L);
+    assert_eq!(Octavian::E6, Octavian::J * Octavian::L);
+    assert_eq!(Octavian::E7, Octavian::K * Octavian::L);
+}
+
+#[test]
+/// Ensure that the dual basis is biorthogonal to the basis and spans the same lattice.
+fn test_dual_basis() {
+    let dual = Octavian::<Ratio<i64>>::dual_basis();
+    let basis = Octavian::<Ratio<i64>>::basis_vectors();
+    for (i, d) in dual.iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let expected = if i == j { 1 } else { 0 };
+            assert_eq!(Ratio::from_integer(expected), d.inner_product(b));
+        }
+    }
+    // E8 is unimodular, so the dual basis is an integral basis of E8 itself.
+    assert!(dual
+        .iter()
+        .all(|d| d.coefficients.iter().all(|c| c.is_integer())));
+    let matrix = dual.map(|d| d.coefficients.map(|c| c.to_integer()));
+    assert!(Basis::new(matrix).is_some());
+}
+
+#[test]
+/// Ensure that the dual basis recovers the E8 coordinates of octonions.
+fn test_coordinates_of() {
+    let mut rng = TestRng(0xd0a1);
+    for _ in 0..100 {
+        let x = rng.octavian(20);
+        let coords = Octavian::<Ratio<i64>>::coordinates_of(&x.to_octonion_coords());
+        assert_eq!(x.coefficients.map(Ratio::from_integer), coords);
+    }
+    // Non-integral octonions are handled too: (1/3, ..., 1/3) has norm 8/9.
+    let x = Octavian::new(Octavian::<Ratio<i64>>::coordinates_of(
+        &[Ratio::new(1, 3); 8],
+    ));
+    assert_eq!(Ratio::new(8, 9), x.norm());
+}
+
+/// The quaternion subalgebra spanned by 1, i, j, k in the standard octonion basis.
+fn standard_quaternion_embedding() -> QuaternionEmbedding {
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    QuaternionEmbedding::new(widen(Octavian::I), widen(Octavian::J)).unwrap()
+}
+
+#[test]
+/// Ensure that the quaternion embedding is multiplicative and rejects commuting generators.
+fn test_quaternion_embedding() {
+    let emb = standard_quaternion_embedding();
+    let [one, i, j, k] = emb.basis();
+    assert_eq!(Octavian::one(), one);
+    assert_eq!(k, i * j);
+    assert!(QuaternionEmbedding::new(i, i).is_none());
+    assert!(QuaternionEmbedding::new(one, j).is_none());
+    assert!(QuaternionEmbedding::new(i.scale(2), j).is_none());
+
+    let mut rng = TestRng(0x9a7e);
+    let mut quaternion = || HurwitzQuaternion::new([(); 4].map(|_| Ratio::new(rng.next_i64(5), 2)));
+    for _ in 0..50 {
+        let (p, q) = (quaternion(), quaternion());
+        assert_eq!(emb.embed(&(p * q)), emb.embed(&p) * emb.embed(&q));
+    }
+}
+
+#[test]
+/// Ensure that projection onto a quaternion subalgebra splits an octavian into orthogonal parts.
+fn test_project_onto_quaternion_subalgebra() {
+    let emb = standard_quaternion_embedding();
+    let basis = emb
+        .basis()
+        .map(|b| Octavian::new(b.coefficients.map(Ratio::from)));
+    let mut rng = TestRng(0x4a7);
+    for _ in 0..100 {
+        let x = rng.octavian(10);
+        let (q, complement) = x.project_onto_quaternion_subalgebra(&emb);
+        let inside = emb.embed(&q);
+        assert_eq!(
+            x.coefficients.map(Ratio::from),
+            (inside + complement).coefficients
+        );
+        assert!(emb.contains(&inside));
+        for b in &basis {
+            assert_eq!(Ratio::from_integer(0), complement.inner_product(b));
+        }
+    }
+    for b in emb.basis() {
+        let (q, complement) = b.project_onto_quaternion_subalgebra(&emb);
+        assert_eq!(b.coefficients.map(Ratio::from), emb.embed(&q).coefficients);
+        assert_eq!(Octavian::zero(), complement);
+    }
+}
+
+#[test]
+/// Ensure that the Hermite normal form is reduced and spans the same lattice.
+fn test_hermite_normal_form() {
+    let b = Octavian::<i64>::basis_vectors().map(|x| x.coefficients);
+    let hnf = lattice::hermite_normal_form(&b);
+    assert_eq!(b.to_vec(), hnf);
+    let doubled =
+        lattice::hermite_normal_form(&[[2, 4, 0, 0, 0, 0, 0, 6], [3, 6, 0, 0, 0, 0, 0, 9], [0; 8]]);
+    assert_eq!(vec![[1, 2, 0, 0, 0, 0, 0, 3]], doubled);
+    assert_eq!(1, lattice::determinant(&b));
+    assert_eq!(
+        1,
+        lattice::determinant(&Octavian::<i64>::GRAM_MATRIX.map(|r| r.map(i64::from)))
+    );
+}
+
+/// Widens an `Octavian<i8>` constant for use with the `i64` APIs.
+#[cfg(feature = "tables")]
+fn widen(x: Octavian<i8>) -> Octavian<i64> {
+    Octavian::new(x.coefficients.map(i64::from))
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the smallest subalgebra containing given elements has the expected rank and is closed.
+fn test_smallest_subalgebra_containing() {
+    let trivial = smallest_subalgebra_containing(&[]);
+    assert_eq!(1, trivial.rank);
+    assert_eq!(SubalgebraType::Rational, trivial.kind);
+    assert_eq!(trivial, smallest_subalgebra_containing(&[Octavian::zero()]));
+
+    for u in Octavian::<i64>::unit_vectors().iter().step_by(17) {
+        assert!(smallest_subalgebra_containing(&[*u]).rank <= 2);
+    }
+    let i = smallest_subalgebra_containing(&[widen(Octavian::I)]);
+    assert_eq!(SubalgebraType::ImaginaryQuadratic, i.kind);
+
+    // By Artin's theorem two elements always generate an associative subalgebra, so pairs give rank at most 4.
+    let ij = smallest_subalgebra_containing(&[widen(Octavian::I), widen(Octavian::J)]);
+    assert_eq!(4, ij.rank);
+    assert_eq!(SubalgebraType::Quaternionic, ij.kind);
+    let ijl = smallest_subalgebra_containing(&[
+        widen(Octavian::I),
+        widen(Octavian::J),
+        widen(Octavian::L),
+    ]);
+    assert_eq!(8, ijl.rank);
+    assert_eq!(SubalgebraType::Octonionic, ijl.kind);
+    assert!(smallest_subalgebra_containing(&Octavian::<i64>::basis_vectors()).is_whole_order());
+    assert!(
+        !smallest_subalgebra_containing(&[Octavian::<i64>::basis_vectors()[0].scale(2)])
+            .is_whole_order()
+    );
+
+    for info in [&ij, &ijl] {
+        let span: Vec<[i64; 8]> = info.basis.iter().map(|x| x.coefficients).collect();
+        for x in &info.basis {
+            for y in &info.basis {
+                let mut extended = span.clone();
+                extended.push((*x * *y).coefficients);
+                assert_eq!(span, lattice::hermite_normal_form(&extended));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the unit multiplication table is a Latin square with the identity at index 0 and agrees with multiplication.
+fn test_unit_multiplication_table() {
+    let table = units::unit_multiplication_table();
+    let identity = Octavian::one().unit_index().unwrap() as usize;
+    for (i, row) in table.iter().enumerate() {
+        assert_eq!(i as u16, table[identity][i]);
+        assert_eq!(i as u16, row[identity]);
+    }
+    for (i, row) in table.iter().enumerate() {
+        let row: HashSet<u16> = row.iter().copied().collect();
+        let column: HashSet<u16> = table.iter().map(|row| row[i]).collect();
+        assert_eq!(240, row.len());
+        assert_eq!(240, column.len());
+    }
+    let mut rng = TestRng(0x7ab1e);
+    for _ in 0..1000 {
+        let i = (rng.next_u64() % 240) as usize;
+        let j = (rng.next_u64() % 240) as usize;
+        let product = Octavian::UNITS[i] * Octavian::UNITS[j];
+        assert_eq!(product, Octavian::UNITS[table[i][j] as usize]);
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that unit indices round-trip and reject non-units.
+fn test_unit_index() {
+    for i in 0..240u16 {
+        let u = Octavian::unit_from_index(i).unwrap();
+        assert_eq!(Some(i), u.unit_index());
+    }
+    assert_eq!(
+        Err(error::AlcoError::IndexOutOfRange {
+            index: 240,
+            len: 240
+        }),
+        Octavian::<i8>::unit_from_index(240)
+    );
+    assert_eq!(None, Octavian::<i8>::zero().unit_index());
+    assert_eq!(None, (Octavian::<i8>::one() + Octavian::E1).unit_index());
+    assert_eq!(None, Octavian::<i8>::one().scale(2).unit_index());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that unit inverses are two-sided and form an involution on indices.
+fn test_unit_inverse() {
+    let one = Octavian::<i8>::one();
+    for u in Octavian::UNITS {
+        let v = u.unit_inverse().unwrap();
+        assert_eq!(one, u * v);
+        assert_eq!(one, v * u);
+        assert_eq!(u.conjugate(), v);
+    }
+    for i in 0..240 {
+        let j = units::unit_inverse_index(i).unwrap();
+        assert_eq!(Ok(i), units::unit_inverse_index(j));
+    }
+    let identity = one.unit_index().unwrap();
+    assert_eq!(Ok(identity), units::unit_inverse_index(identity));
+    assert_eq!(
+        Err(error::AlcoError::IndexOutOfRange {
+            index: 240,
+            len: 240
+        }),
+        units::unit_inverse_index(240)
+    );
+    assert_eq!(None, one.scale(2).unit_inverse());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that generated subloops have the expected orders and associativity.
+fn test_generated_subloop() {
+    let one = Octavian::<i8>::one();
+    assert_eq!((vec![one], true), units::generated_subloop(&[]).unwrap());
+
+    let (cyclic, is_group) = units::generated_subloop(&[Octavian::I]).unwrap();
+    assert_eq!(4, cyclic.len());
+    assert!(is_group);
+    for x in [one, Octavian::I, -one, -Octavian::I] {
+        assert!(cyclic.contains(&x));
+    }
+    assert!(cyclic
+        .windows(2)
+        .all(|w| w[0].coefficients < w[1].coefficients));
+
+    let (q8, is_group) = units::generated_subloop(&[Octavian::I, Octavian::J]).unwrap();
+    assert_eq!(8, q8.len());
+    assert!(is_group);
+
+    // Octonion loops are diassociative, so it takes three units to generate the whole non-associative loop.
+    let (all, is_group) =
+        units::generated_subloop(&[Octavian::I, Octavian::UNITS[1], Octavian::UNITS[3]]).unwrap();
+    assert_eq!(240, all.len());
+    assert!(!is_group);
+    let (moufang, is_group) =
+        units::generated_subloop(&[Octavian::I, Octavian::J, Octavian::L]).unwrap();
+    assert_eq!(16, moufang.len());
+    assert!(!is_group);
+    let (pair, is_group) =
+        units::generated_subloop(&[Octavian::UNITS[3], Octavian::UNITS[100]]).unwrap();
+    assert!(pair.len() < 240);
+    assert!(is_group);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the copies of Q8 in the unit loop are closed subgroups with the right order spectrum.
+fn test_find_subloops_isomorphic_to_q8() {
+    let table = units::unit_multiplication_table();
+    let minus_one = (-Octavian::<i8>::one()).unit_index().unwrap();
+    let subgroups = units::find_subloops_isomorphic_to_q8();
+    for q in &subgroups {
+        for &x in q {
+            for &y in q {
+                assert!(q.contains(&table[x as usize][y as usize]));
+            }
+        }
+        let mut orders: Vec<u32> = q.iter().map(|&x| units::unit_order(x).unwrap()).collect();
+        orders.sort_unstable();
+        assert_eq!(vec![1, 2, 4, 4, 4, 4, 4, 4], orders);
+        assert!(q.contains(&minus_one));
+    }
+    // 7560 ordered pairs of units generate a Q8, and each Q8 has 24 ordered generating pairs.
+    assert_eq!(315, subgroups.len());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the maximal associative subloops are groups, include 2T, and are not nested.
+fn test_maximal_associative_subloops() {
+    let subloops = units::maximal_associative_subloops();
+    let count = |kind| subloops.iter().filter(|s| s.kind == kind).count();
+    assert_eq!(63, count(units::GroupType::BinaryTetrahedral));
+    assert_eq!(252, count(units::GroupType::Quaternion));
+    assert_eq!(336, count(units::GroupType::Dicyclic(12)));
+    assert_eq!(651, subloops.len());
+    for s in &subloops {
+        assert!(units::is_associative_indices(&s.elements).unwrap());
+        assert_eq!(s.order, s.elements.len());
+        assert_eq!(2, s.center_size);
+    }
+    for (i, s) in subloops.iter().enumerate() {
+        for t in &subloops[..i] {
+            assert!(!s.elements.iter().all(|x| t.elements.contains(x)));
+        }
+    }
+    // Adjoining any further unit to a 2T destroys associativity.
+    let binary_tetrahedral = &subloops[0];
+    assert_eq!(units::GroupType::BinaryTetrahedral, binary_tetrahedral.kind);
+    for u in (0..240).step_by(7) {
+        if !binary_tetrahedral.elements.contains(&u) {
+            let mut generators = binary_tetrahedral.elements.clone();
+            generators.push(u);
+            assert!(!units::is_associative_indices(
+                &units::generated_subloop_indices(&generators).unwrap()
+            )
+            .unwrap());
+        }
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the commutation and association statistics of the unit loop have the expected values.
+fn test_unit_commutation_and_association_statistics() {
+    let table = units::unit_multiplication_table();
+    let one = Octavian::<i8>::one().unit_index().unwrap() as usize;
+    let minus_one = (-Octavian::<i8>::one()).unit_index().unwrap() as usize;
+    for x in 0..240 {
+        assert_eq!(table[one][x], table[x][one]);
+        assert_eq!(table[minus_one][x], table[x][minus_one]);
+        for y in 0..240 {
+            let (x1, xy) = (table[x][one] as usize, table[x][y] as usize);
+            assert_eq!(table[x1][y], table[x][table[one][y] as usize]);
+            assert_eq!(table[xy][one], table[x][table[y][one] as usize]);
+        }
+    }
+    assert_eq!((1656, 57600), units::unit_commuting_fraction());
+    assert_eq!((1353024, 13824000), units::unit_associating_fraction());
+    #[cfg(feature = "par")]
+    assert_eq!(
+        units::unit_associating_fraction(),
+        units::unit_associating_fraction_par()
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that translations by units are permutations with the expected cycle structure.
+fn test_translation_permutations() {
+    let table = units::unit_multiplication_table();
+    let identity: [u16; 240] = core::array::from_fn(|i| i as u16);
+    let one = Octavian::<i8>::one();
+    assert_eq!(Some(identity), units::left_translation_permutation(&one));
+    assert_eq!(Some(identity), units::right_translation_permutation(&one));
+    assert_eq!(None, units::left_translation_permutation(&Octavian::zero()));
+    for u in Octavian::UNITS.iter().step_by(11) {
+        for p in [
+            units::left_translation_permutation(u).unwrap(),
+            units::right_translation_permutation(u).unwrap(),
+        ] {
+            let image: HashSet<u16> = p.iter().copied().collect();
+            assert_eq!(240, image.len());
+        }
+    }
+    // By diassociativity, translation by a unit of order 4 has order 4 and, fixing nothing, consists of 4-cycles.
+    let left = units::left_translation_permutation(&Octavian::I).unwrap();
+    assert_eq!(Some(vec![4; 60]), units::cycle_type(&left));
+    assert_eq!(Some(4), units::permutation_order(&left));
+    assert_eq!(Some(1), units::permutation_order(&identity));
+    let right = units::right_translation_permutation(&Octavian::J).unwrap();
+    let composed = units::compose_permutations(&left, &right).unwrap();
+    for x in 0..240 {
+        let xj = table[x][Octavian::J.unit_index().unwrap() as usize];
+        assert_eq!(
+            table[Octavian::I.unit_index().unwrap() as usize][xj as usize],
+            composed[x]
+        );
+    }
+    // Out-of-range and repeated entries are not permutations.
+    let mut broken = identity;
+    broken[7] = 240;
+    assert_eq!(None, units::cycle_type(&broken));
+    assert_eq!(None, units::permutation_order(&broken));
+    assert_eq!(None, units::compose_permutations(&broken, &identity));
+    broken[7] = 8;
+    assert_eq!(None, units::permutation_order(&broken));
+    assert_eq!(None, units::compose_permutations(&identity, &broken));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the nuclei and center of the unit loop are computed to be {1, -1}.
+fn test_loop_nucleus_and_center() {
+    let one = Octavian::<i8>::one();
+    let expected = vec![one, -one];
+    assert_eq!(expected, units::loop_left_nucleus());
+    assert_eq!(expected, units::loop_middle_nucleus());
+    assert_eq!(expected, units::loop_right_nucleus());
+    assert_eq!(expected, units::loop_nucleus());
+    assert_eq!(expected, units::loop_center());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the GAP export of the unit loop is a well-formed 1-based Cayley table.
+fn test_export_loop_gap() {
+    let script = units::export_loop_gap();
+    let lines: Vec<&str> = script.lines().collect();
+    assert_eq!(
+        "# The unit loop of the octavian integers; element i is UNITS[i - 1].",
+        lines[0]
+    );
+    assert_eq!("unitLoop := LoopByCayleyTable([", lines[2]);
+    assert_eq!("]);", *lines.last().unwrap());
+    let rows = &lines[3..lines.len() - 1];
+    assert_eq!(240, rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        let row = row.trim_end_matches(',');
+        let entries: Vec<u16> = row[1..row.len() - 1]
+            .split(", ")
+            .map(|x| x.parse().unwrap())
+            .collect();
+        assert_eq!(240, entries.len());
+        assert!(entries.iter().all(|&x| (1..=240).contains(&x)));
+        assert_eq!(
+            units::unit_multiplication_table()[i]
+                .map(|x| x + 1)
+                .to_vec(),
+            entries
+        );
+    }
+    let unit_lines: Vec<String> = units::export_units_gap()
+        .lines()
+        .map(String::from)
+        .collect();
+    assert_eq!(242, unit_lines.len());
+    assert_eq!("[-2, -3, -4, -6, -5, -4, -3, -2],", unit_lines[1]);
+    assert_eq!("[2, 3, 4, 6, 5, 4, 3, 2]", unit_lines[240]);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the Hurwitz unit subgroups are copies of 2T that pull back to the Hurwitz units.
+fn test_hurwitz_unit_subgroups() {
+    let subgroups = units::hurwitz_unit_subgroups();
+    assert_eq!(63, subgroups.len());
+    for subgroup in &subgroups {
+        assert!(units::is_associative_indices(subgroup).unwrap());
+        assert_eq!(
+            units::GroupType::BinaryTetrahedral,
+            units::SubloopInfo::new(subgroup.to_vec()).kind
+        );
+        let emb = units::hurwitz_embedding(subgroup).unwrap();
+        let pulled_back: Vec<HurwitzQuaternion<Ratio<i64>>> = subgroup
+            .iter()
+            .map(|&i| {
+                let u = widen(Octavian::UNITS[i as usize]);
+                let (q, complement) = u.project_onto_quaternion_subalgebra(&emb);
+                assert_eq!(Octavian::zero(), complement);
+                q
+            })
+            .collect();
+        for q in &pulled_back {
+            assert_eq!(Ratio::from_integer(1), q.norm());
+            assert!(q.coefficients.iter().all(|c| (c * 2).is_integer()));
+        }
+        for p in &pulled_back {
+            for q in &pulled_back {
+                assert_eq!(emb.embed(&(*p * *q)), emb.embed(p) * emb.embed(q));
+            }
+        }
+    }
+
+    let mut subgroup = subgroups[0];
+    subgroup.reverse();
+    assert!(units::hurwitz_embedding(&subgroup).is_ok());
+    subgroup[3] = 240;
+    assert_eq!(
+        Err(error::AlcoError::IndexOutOfRange {
+            index: 240,
+            len: 240
+        }),
+        units::hurwitz_embedding(&subgroup)
+    );
+    let outside = (0..240).find(|i| !subgroups[0].contains(i)).unwrap();
+    subgroup[3] = outside;
+    assert_eq!(
+        Err(error::AlcoError::NotASubgroup),
+        units::hurwitz_embedding(&subgroup)
+    );
+    let first_24: [u16; 24] = core::array::from_fn(|i| i as u16);
+    assert_eq!(
+        Err(error::AlcoError::NotASubgroup),
+        units::hurwitz_embedding(&first_24)
+    );
+    // The identity repeated 24 times is closed and associative, but not 24 distinct units.
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    assert_eq!(
+        Err(error::AlcoError::NotASubgroup),
+        units::hurwitz_embedding(&[identity; 24])
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the units split into 120 classes {u, -u} with stable canonical representatives.
+fn test_unit_sign_classes() {
+    let classes = units::unit_sign_classes();
+    let mut seen = HashSet::new();
+    for (c, &(i, j)) in classes.iter().enumerate() {
+        assert!(seen.insert(i));
+        assert!(seen.insert(j));
+        let (u, v) = (Octavian::UNITS[i as usize], Octavian::UNITS[j as usize]);
+        assert_eq!(-u, v);
+        assert_eq!(Some(c as u16), u.projective_index());
+        assert_eq!(Some(c as u16), v.projective_index());
+        let representative = u.projective_representative().unwrap();
+        assert_eq!(representative, v.projective_representative().unwrap());
+        assert_eq!(v, representative);
+        assert!(representative.coefficients > (-representative).coefficients);
+    }
+    assert_eq!(240, seen.len());
+    assert_eq!(None, Octavian::<i8>::zero().projective_index());
+    assert_eq!(None, Octavian::<i8>::zero().projective_representative());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that units snap to themselves, ties go to the smallest index, and small perturbations snap back.
+fn test_nearest_unit() {
+    let to_f64 = |x: Octavian<i8>| Octavian::new(x.coefficients.map(f64::from));
+    for u in Octavian::UNITS {
+        assert_eq!((u, 0.0), units::nearest_unit(&to_f64(u)));
+        assert_eq!(Some((u, 0)), units::nearest_unit_exact(&widen(u)));
+    }
+    // The midpoint of u and -u is zero, which is equidistant from every unit.
+    assert_eq!(
+        (Octavian::UNITS[0], 1.0),
+        units::nearest_unit(&Octavian::zero())
+    );
+    assert_eq!(
+        Some((Octavian::UNITS[0], 1)),
+        units::nearest_unit_exact(&Octavian::zero())
+    );
+    // Far from the units the distance leaves i64, and with the largest coefficients the norm leaves i128.
+    let far = Octavian::<i64>::one().scale(-(1 << 40));
+    assert_eq!(
+        Some((-Octavian::<i8>::one(), (1 << 80) - (1 << 41) + 1)),
+        units::nearest_unit_exact(&far)
+    );
+    assert_eq!(
+        None,
+        units::nearest_unit_exact(&Octavian::new([i64::MAX; 8]))
+    );
+    // The midpoint of two units at minimal distance is equidistant from both.
+    let (u, v) = (Octavian::UNITS[1], Octavian::UNITS[0]);
+    assert_eq!(1, (u - v).norm());
+    let midpoint = (to_f64(u) + to_f64(v)).scale(0.5);
+    assert_eq!(v, units::nearest_unit(&midpoint).0);
+
+    let mut rng = TestRng(0x5a4b);
+    for _ in 0..1000 {
+        let i = (rng.next_u64() % 240) as usize;
+        let noise = [(); 8].map(|_| (rng.next_i64(1000) as f64) / 10001.0);
+        let x = to_f64(Octavian::UNITS[i]) + Octavian::new(noise);
+        let (nearest, distance) = units::nearest_unit(&x);
+        assert_eq!(Octavian::UNITS[i], nearest);
+        assert!(distance < 0.5);
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that Cayley graphs of the unit loop have the expected connectivity, symmetry, diameter and girth.
+fn test_unit_cayley_graph() {
+    let index = |x: Octavian<i8>| x.unit_index().unwrap();
+    // Right-nested products of these four units reach every unit; three generators of the loop need not suffice.
+    let generators = [16, 79, 90, 209];
+    let graph = cayley::unit_cayley_graph(&generators);
+    assert!(graph.is_connected());
+    assert_eq!(Some(6), graph.diameter());
+    // Negation commutes with left translations, so u and -u have the same distance profile.
+    let profile = |x: u16| {
+        let mut d: Vec<u32> = graph.distances_from(x).iter().map(|d| d.unwrap()).collect();
+        d.sort_unstable();
+        d
+    };
+    for u in [0u16, 17, 100] {
+        let minus_u = index(-Octavian::UNITS[u as usize]);
+        assert_eq!(profile(u), profile(minus_u));
+    }
+
+    let quaternion = cayley::unit_cayley_graph(&[index(Octavian::I), index(Octavian::J)]);
+    assert!(!quaternion.is_connected());
+    assert_eq!(None, quaternion.diameter());
+
+    let cyclic = cayley::unit_cayley_graph(&[index(Octavian::I)]);
+    assert_eq!(Some(4), cyclic.girth());
+    assert_eq!(240, cyclic.edge_count());
+    let matching = cayley::unit_cayley_graph(&[index(-Octavian::one())]);
+    assert_eq!(None, matching.girth());
+    assert_eq!(120, matching.edge_count());
+    // Generator 209 has order 3, so its translations form triangles.
+    assert_eq!(3, units::unit_order(209).unwrap());
+    assert_eq!(Some(3), graph.girth());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that shortest words in generators evaluate to their targets and have the BFS length.
+fn test_unit_word() {
+    let generators = [16, 79, 90, 209];
+    let graph = cayley::unit_cayley_graph(&generators);
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let distances = graph.distances_from(identity);
+    assert_eq!(Some(vec![]), cayley::unit_word(identity, &generators));
+    for target in 0..240 {
+        let word = cayley::unit_word(target, &generators).unwrap();
+        assert_eq!(Some(target), cayley::evaluate_word(&word, &generators));
+        assert_eq!(distances[target as usize], Some(word.len() as u32));
+    }
+    let quaternion = [Octavian::I, Octavian::J].map(|x| x.unit_index().unwrap());
+    let outside = Octavian::L.unit_index().unwrap();
+    assert_eq!(None, cayley::unit_word(outside, &quaternion));
+    assert_eq!(None, cayley::evaluate_word(&[outside], &quaternion));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the units split by inner product with a root into classes of sizes 1, 56, 126, 56, 1,
+/// and that the classes are preserved by a reflection fixing the root.
+fn test_unit_neighbors() {
+    for r in [0, 17, 119, 120, 239].map(|i| Octavian::UNITS[i]) {
+        let partition = units::unit_neighbors(&r).unwrap();
+        let sizes = partition.classes().map(|c| c.len());
+        assert_eq!([1, 56, 126, 56, 1], sizes);
+        assert_eq!(vec![r.unit_index().unwrap()], partition.equal);
+        assert_eq!(vec![(-r).unit_index().unwrap()], partition.opposite);
+
+        // The reflection in a root orthogonal to r fixes r and permutes the units.
+        let a = Octavian::UNITS[partition.orthogonal[0] as usize];
+        let reflect = |x: &Octavian<i8>| *x - a.scale(x.inner_product(&a));
+        assert_eq!(r, reflect(&r));
+        for (class, inner) in partition.classes().iter().zip([2, 1, 0, -1, -2]) {
+            for &i in class.iter() {
+                let image = reflect(&Octavian::UNITS[i as usize]);
+                assert!(image.unit_index().is_some());
+                assert_eq!(inner, r.inner_product(&image));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that orbits of units under sets of lattice automorphisms partition the units and respect conjugation.
+fn test_unit_orbit_under() {
+    let simple = WeylElement::simple_reflections();
+    for start in [0, 77, 239] {
+        assert_eq!(
+            (0..240).collect::<Vec<u16>>(),
+            weyl::unit_orbit_under(&simple, start)
+        );
+        assert_eq!(
+            vec![start],
+            weyl::unit_orbit_under(&[WeylElement::identity()], start)
+        );
+    }
+
+    // The reflections in the roots orthogonal to the first simple root, and a conjugate of that set.
+    let r = Octavian::<i8>::basis_vectors()[0];
+    let generators: Vec<WeylElement> = units::unit_neighbors(&r)
+        .unwrap()
+        .orthogonal
+        .iter()
+        .map(|&i| WeylElement::reflection(&Octavian::UNITS[i as usize]).unwrap())
+        .collect();
+    let g = simple[1].then(&simple[3]).then(&simple[0]);
+    assert_eq!(WeylElement::identity(), g.then(&g.inverse()));
+    let conjugates: Vec<WeylElement> = generators
+        .iter()
+        .map(|h| g.inverse().then(h).then(&g))
+        .collect();
+    let orbit_sizes = |set: &[WeylElement]| {
+        let mut seen = [false; 240];
+        let mut sizes = Vec::new();
+        for start in 0..240u16 {
+            if seen[start as usize] {
+                continue;
+            }
+            let orbit = weyl::unit_orbit_under(set, start);
+            for &x in &orbit {
+                assert!(!seen[x as usize]);
+                seen[x as usize] = true;
+            }
+            sizes.push(orbit.len());
+        }
+        sizes.sort_unstable();
+        sizes
+    };
+    let sizes = orbit_sizes(&generators);
+    assert_eq!(vec![1, 1, 56, 56, 126], sizes);
+    assert_eq!(sizes, orbit_sizes(&conjugates));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that loop homomorphisms are recognized and that isomorphisms are found for relabelled tables only.
+fn test_loop_isomorphism() {
+    let table = units::unit_multiplication_table();
+    let identity: [u16; 240] = core::array::from_fn(|i| i as u16);
+    assert!(units::is_loop_homomorphism(&identity));
+    // Negation is not a homomorphism, since (-u)(-v) = uv rather than -(uv).
+    let negation = Octavian::UNITS.map(|u| (-u).unit_index().unwrap());
+    assert!(!units::is_loop_homomorphism(&negation));
+    // Inversion reverses products, and the loop is not commutative.
+    let inversion: [u16; 240] =
+        core::array::from_fn(|i| units::unit_inverse_index(i as u16).unwrap());
+    assert!(!units::is_loop_homomorphism(&inversion));
+
+    // Relabel the units by a random permutation p, so that p is an isomorphism onto the scrambled table.
+    let mut rng = TestRng(0x150);
+    let mut p = identity;
+    for i in (1..240).rev() {
+        p.swap(i, (rng.next_u64() % (i as u64 + 1)) as usize);
+    }
+    let mut scrambled = [[0u16; 240]; 240];
+    for x in 0..240 {
+        for y in 0..240 {
+            scrambled[p[x] as usize][p[y] as usize] = p[table[x][y] as usize];
+        }
+    }
+    let f = units::find_isomorphism(&scrambled).unwrap();
+    for x in 0..240 {
+        for y in 0..240 {
+            assert_eq!(
+                f[table[x][y] as usize],
+                scrambled[f[x] as usize][f[y] as usize]
+            );
+        }
+    }
+    assert!(units::find_isomorphism(table).is_some());
+
+    // Changing a single entry breaks the Latin square property.
+    let mut corrupted = scrambled;
+    corrupted[3][5] = corrupted[3][6];
+    assert_eq!(None, units::find_isomorphism(&corrupted));
+    // Transposing gives the opposite loop, which is isomorphic to the unit loop via inversion.
+    let mut opposite = [[0u16; 240]; 240];
+    for x in 0..240 {
+        for y in 0..240 {
+            opposite[x][y] = table[y][x];
+        }
+    }
+    assert!(units::find_isomorphism(&opposite).is_some());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that random walks on the unit loop stay in the loop, are reproducible, and mix as expected.
+fn test_unit_walk() {
+    let generators = [16, 79, 90, 209];
+    let walk = UnitWalk::uniform(StdRng::seed_from_u64(7), &generators).unwrap();
+    let trajectory: Vec<u16> = walk.take(100).collect();
+    assert!(trajectory.iter().all(|&x| x < 240));
+    let again: Vec<u16> = UnitWalk::uniform(StdRng::seed_from_u64(7), &generators)
+        .unwrap()
+        .take(100)
+        .collect();
+    assert_eq!(trajectory, again);
+
+    let all: Vec<u16> = (0..240).collect();
+    let mut walk = UnitWalk::uniform(StdRng::seed_from_u64(11), &all).unwrap();
+    assert!(walk
+        .distribution_after(1)
+        .iter()
+        .all(|&p| (p - 1.0 / 240.0).abs() < 1e-12));
+    assert!(walk.mixing_statistics(1, 24000) < 0.05);
+    assert!(walk.mixing_statistics(0, 10) > 0.99);
+
+    assert!(UnitWalk::new(StdRng::seed_from_u64(0), &[1, 2], &[1.0]).is_none());
+    assert!(UnitWalk::new(StdRng::seed_from_u64(0), &[240], &[1.0]).is_none());
+    assert!(UnitWalk::new(StdRng::seed_from_u64(0), &[1], &[0.0]).is_none());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the norm shells of E8 have the sizes 240 σ3(n) given by the theta series.
+fn test_norm_shell() {
+    assert_eq!(vec![Octavian::<i64>::zero()], lattice::norm_shell(0));
+    assert!(lattice::norm_shell(-1).is_empty());
+    for (n, size) in [(1, 240), (2, 2160), (3, 6720)] {
+        let shell = lattice::norm_shell(n);
+        assert_eq!(size, shell.len());
+        assert!(shell.iter().all(|x| x.norm() == n));
+    }
+    assert_eq!(units::units::<i64>().to_vec(), lattice::norm_shell(1));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that square roots square to their target, and count the square roots of 1 and -1 among the units.
+fn test_square_roots() {
+    let one = Octavian::<i8>::one();
+    // The units squaring to one are those of order dividing 2, namely ±1.
+    let roots_of_one = units::square_roots_of(&one);
+    let order_two: Vec<Octavian<i8>> = (0..240)
+        .filter(|&i| 2 % units::unit_order(i).unwrap() == 0)
+        .map(|i| Octavian::UNITS[i as usize])
+        .collect();
+    assert_eq!(order_two, roots_of_one);
+    assert_eq!(2, roots_of_one.len());
+    assert!(roots_of_one.contains(&one) && roots_of_one.contains(&-one));
+    // The square roots of -1 are the 126 imaginary units, those orthogonal to 1.
+    let roots_of_minus_one = units::square_roots_of(&-one);
+    assert_eq!(126, roots_of_minus_one.len());
+    for u in &roots_of_minus_one {
+        assert_eq!(-one, *u * *u);
+        assert_eq!(0, u.trace());
+    }
+    assert!(units::square_roots_of(&one.scale(2)).is_empty());
+
+    // A square root of a non-unit octavian has norm sqrt(N(x)), found among the norm shells.
+    for y in lattice::norm_shell(2).into_iter().step_by(97) {
+        let x = y * y;
+        let roots = units::square_roots_with_norm_at_most(&x, 2);
+        assert!(roots.contains(&y) && roots.contains(&-y));
+        assert!(roots.iter().all(|r| *r * *r == x));
+        assert!(units::square_roots_with_norm_at_most(&x, 1).is_empty());
+    }
+    let x = lattice::norm_shell(2)[0];
+    assert!(units::square_roots_with_norm_at_most(&x, 10).is_empty());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the exponent of the unit loop is 12 and that power maps are bijective exactly for k coprime to it.
+fn test_power_maps() {
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let exponent = units::loop_exponent();
+    let orders: HashSet<u32> = (0..240).map(|i| units::unit_order(i).unwrap()).collect();
+    assert_eq!(orders.into_iter().fold(1, num::integer::lcm), exponent);
+    assert_eq!(12, exponent);
+
+    let is_bijection =
+        |map: &[u16; 240]| map.iter().copied().collect::<HashSet<u16>>().len() == 240;
+    assert_eq!(core::array::from_fn(|i| i as u16), units::power_map(1));
+    assert_eq!([identity; 240], units::power_map(exponent));
+    assert_eq!([identity; 240], units::power_map(0));
+    // Units of order 3 and 2 collapse under cubes and squares, but every k coprime to 12 permutes the units.
+    assert!(!is_bijection(&units::power_map(2)));
+    assert!(!is_bijection(&units::power_map(3)));
+    for k in [5, 7, 11, 13] {
+        assert!(is_bijection(&units::power_map(k)));
+    }
+    let table = units::unit_multiplication_table();
+    let squares = units::power_map(2);
+    for u in 0..240 {
+        assert_eq!(table[u][u], squares[u]);
+    }
+
+    let classes = units::kth_power_classes(2);
+    assert_eq!(240, classes.iter().map(|(_, c)| c.len()).sum::<usize>());
+    let (_, roots_of_minus_one) = classes
+        .iter()
+        .find(|(p, _)| Octavian::UNITS[*p as usize] == -Octavian::<i8>::one())
+        .unwrap();
+    assert_eq!(126, roots_of_minus_one.len());
+    for (p, class) in &classes {
+        assert!(class.iter().all(|&u| squares[u as usize] == *p));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the inner-mapping conjugacy classes are ±1 and the units of orders 3, 4 and 6, permuted by automorphisms.
+fn test_unit_conjugacy_classes() {
+    let classes = units::unit_conjugacy_classes();
+    let one = Octavian::<i8>::one().unit_index().unwrap();
+    let minus_one = (-Octavian::<i8>::one()).unit_index().unwrap();
+    assert!(classes.contains(&vec![one]));
+    assert!(classes.contains(&vec![minus_one]));
+    let mut seen = [false; 240];
+    for class in &classes {
+        for &x in class {
+            assert!(!seen[x as usize]);
+            seen[x as usize] = true;
+        }
+        // Each class consists of units of a single order, and the order determines the class.
+        let order = units::unit_order(class[0]).unwrap();
+        assert!(class
+            .iter()
+            .all(|&x| units::unit_order(x).unwrap() == order));
+        assert_eq!(
+            class.len(),
+            (0..240)
+                .filter(|&x| units::unit_order(x).unwrap() == order)
+                .count()
+        );
+        // Inverses have the same order, so every class is closed under inversion.
+        assert!(class
+            .iter()
+            .all(|&x| class.contains(&units::unit_inverse_index(x).unwrap())));
+    }
+    assert!(seen.iter().all(|&s| s));
+    let mut sizes: Vec<usize> = classes.iter().map(Vec::len).collect();
+    sizes.sort_unstable();
+    assert_eq!(vec![1, 1, 56, 56, 126], sizes);
+
+    // An automorphism found by matching the loop against a relabelled copy of itself permutes the classes.
+    let table = units::unit_multiplication_table();
+    let mut rng = TestRng(0xc1a55);
+    let mut p: [u16; 240] = core::array::from_fn(|i| i as u16);
+    for i in (1..240).rev() {
+        p.swap(i, (rng.next_u64() % (i as u64 + 1)) as usize);
+    }
+    let mut scrambled = [[0u16; 240]; 240];
+    for x in 0..240 {
+        for y in 0..240 {
+            scrambled[p[x] as usize][p[y] as usize] = p[table[x][y] as usize];
+        }
+    }
+    let f = units::find_isomorphism(&scrambled).unwrap();
+    let mut p_inverse = [0u16; 240];
+    for (x, &y) in p.iter().enumerate() {
+        p_inverse[y as usize] = x as u16;
+    }
+    let automorphism = units::compose_permutations(&p_inverse, &f).unwrap();
+    assert!(units::is_loop_homomorphism(&automorphism));
+    for class in &classes {
+        let mut image: Vec<u16> = class.iter().map(|&x| automorphism[x as usize]).collect();
+        image.sort_unstable();
+        assert!(classes.contains(&image));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that every pair of units generates a group, and that a corrupted table is caught.
+fn test_diassociativity() {
+    assert!(units::verify_diassociativity());
+    let mut rng = TestRng(0xd1a5);
+    for _ in 0..50 {
+        let u = (rng.next_u64() % 240) as u16;
+        let v = (rng.next_u64() % 240) as u16;
+        let subloop = units::generated_subloop_indices(&[u, v]).unwrap();
+        let table = units::unit_multiplication_table();
+        let brute_force = subloop.iter().all(|&x| {
+            subloop.iter().all(|&y| {
+                subloop.iter().all(|&z| {
+                    let (xy, yz) = (table[x as usize][y as usize], table[y as usize][z as usize]);
+                    table[xy as usize][z as usize] == table[x as usize][yz as usize]
+                })
+            })
+        });
+        assert_eq!(brute_force, units::pair_generates_group(u, v).unwrap());
+        assert!(brute_force);
+    }
+    let out_of_range = Err(error::AlcoError::IndexOutOfRange {
+        index: 240,
+        len: 240,
+    });
+    assert_eq!(out_of_range, units::pair_generates_group(0, 240));
+    assert_eq!(out_of_range, units::is_associative_indices(&[240]));
+    assert!(matches!(
+        units::unit_order(240),
+        Err(error::AlcoError::IndexOutOfRange { index: 240, .. })
+    ));
+    assert!(matches!(
+        units::generated_subloop_indices(&[0, 240]),
+        Err(error::AlcoError::IndexOutOfRange { index: 240, .. })
+    ));
+
+    let mut corrupted = *units::unit_multiplication_table();
+    let (i, j) = (
+        Octavian::I.unit_index().unwrap(),
+        Octavian::J.unit_index().unwrap(),
+    );
+    corrupted[i as usize][j as usize] = Octavian::L.unit_index().unwrap();
+    assert!(!units::verify_diassociativity_in(&corrupted));
+}
+
+#[test]
+/// Ensure that cached left and right multipliers agree with the product, including over many reuses.
+fn test_multipliers() {
+    let mut rng = TestRng(0x1ef7);
+    for _ in 0..200 {
+        let x = rng.octavian(20);
+        let y = rng.octavian(20);
+        assert_eq!(x * y, x.left_multiplier().apply(&y));
+        assert_eq!(x * y, y.right_multiplier().apply(&x));
+    }
+    let x = rng.octavian(5);
+    let (left, right) = (x.left_multiplier(), x.right_multiplier());
+    for _ in 0..100_000 {
+        let y = rng.octavian(5);
+        assert_eq!(x * y, left.apply(&y));
+        assert_eq!(y * x, right.apply(&y));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that sparse multiplication over the nonzero structure constants agrees with the dense adjoint-matrix path.
+fn test_mul_sparse() {
+    let adjoint = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    let nonzero = adjoint
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|&&c| c != 0)
+        .count();
+    assert_eq!(nonzero, octavian::STRUCTURE_CONSTANT_COUNT);
+    assert_eq!(311, octavian::STRUCTURE_CONSTANT_COUNT);
+    for &(i, j, k, c) in &octavian::STRUCTURE_CONSTANTS {
+        assert_eq!(c, adjoint[k as usize][i as usize][j as usize]);
+    }
+
+    for u in Octavian::UNITS {
+        for v in Octavian::UNITS {
+            assert_eq!(u.left_multiplier().apply(&v), u.mul_sparse(&v));
+        }
+    }
+    let mut rng = TestRng(0x5ba75e);
+    for _ in 0..10_000 {
+        let x = rng.octavian(1000);
+        let y = rng.octavian(1000);
+        assert_eq!(x.left_multiplier().apply(&y), x.mul_sparse(&y));
+        assert_eq!(x * y, x.mul_sparse(&y));
+    }
+
+    let x = Octavian::<f64>::basis_vectors()[2];
+    let y = Octavian::<f64>::basis_vectors()[5].scale(0.5);
+    assert_eq!(x.left_multiplier().apply(&y), x * y);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the trilinear products agree with the adjoint matrix, and that products with basis vectors and one() take the fast path.
+fn test_trilinear_mul() {
+    let units = Octavian::UNITS.map(|u| Octavian::new(u.coefficients.map(i64::from)));
+    for u in units {
+        for v in units {
+            assert_eq!(u.left_multiplier().apply(&v), u * v);
+        }
+    }
+    let mut rng = TestRng(0x7e1);
+    for _ in 0..10_000 {
+        let x = rng.octavian(1000);
+        let y = rng.octavian(1000);
+        assert_eq!(x.left_multiplier().apply(&y), x * y);
+        assert_eq!(x.mul_sparse(&y), x * y);
+    }
+
+    let hits = || octavian::FAST_PATH_HITS.with(|hits| hits.get());
+    let x = rng.octavian(1000);
+    let before = hits();
+    for (i, b) in Octavian::<i64>::basis_vectors().into_iter().enumerate() {
+        assert_eq!(
+            b.left_multiplier().apply(&x),
+            b * x,
+            "basis vector {i} on the left"
+        );
+        assert_eq!(
+            x.left_multiplier().apply(&b),
+            x * b,
+            "basis vector {i} on the right"
+        );
+    }
+    assert_eq!(Octavian::one() * x, x);
+    assert_eq!(x * Octavian::one(), x);
+    assert_eq!(hits(), before + 18);
+    let y = rng.octavian(1000);
+    let _ = x * y;
+    assert_eq!(hits(), before + 18);
+}
+
+/// Widens an octavian to `i128` coefficients, which have no vectorized kernel and so take the scalar path.
+#[cfg(feature = "simd")]
+fn to_i128<
+    T: Into<i128> + Copy + num_traits::FromPrimitive + num_traits::Num + core::ops::Neg<Output = T>,
+>(
+    x: &Octavian<T>,
+) -> Octavian<i128> {
+    Octavian::new(x.coefficients.map(Into::into))
+}
+
+#[cfg(all(feature = "simd", feature = "tables"))]
+#[test]
+/// Ensure that the vectorized kernels agree with the scalar arithmetic, over the units and on large random inputs.
+fn test_simd_kernels() {
+    let check_i64 = |x: Octavian<i64>, y: Octavian<i64>| {
+        assert_eq!(to_i128(&(x * y)), to_i128(&x) * to_i128(&y));
+        assert_eq!(to_i128(&(x + y)), to_i128(&x) + to_i128(&y));
+        assert_eq!(
+            i128::from(x.inner_product(&y)),
+            to_i128(&x).inner_product(&to_i128(&y))
+        );
+    };
+    let check_i32 = |x: Octavian<i32>, y: Octavian<i32>| {
+        assert_eq!(to_i128(&(x * y)), to_i128(&x) * to_i128(&y));
+        assert_eq!(to_i128(&(x + y)), to_i128(&x) + to_i128(&y));
+        assert_eq!(
+            i128::from(x.inner_product(&y)),
+            to_i128(&x).inner_product(&to_i128(&y))
+        );
+    };
+    // Integer-valued floats are exact, so the float kernels must agree with the integer ones.
+    let check_f64 = |x: Octavian<i64>, y: Octavian<i64>| {
+        let float = |z: &Octavian<i64>| Octavian::new(z.coefficients.map(|c| c as f64));
+        let (xf, yf) = (float(&x), float(&y));
+        assert_eq!(float(&(x * y)), xf * yf);
+        assert_eq!(float(&(x + y)), xf + yf);
+        assert_eq!(x.inner_product(&y) as f64, xf.inner_product(&yf));
+    };
+    let (wide, narrow) = (units::units::<i64>(), units::units::<i32>());
+    for i in 0..240 {
+        for j in 0..240 {
+            check_i64(wide[i], wide[j]);
+            check_i32(narrow[i], narrow[j]);
+            check_f64(wide[i], wide[j]);
+        }
+    }
+    let mut rng = TestRng(0x51bd);
+    for _ in 0..100_000 {
+        let (x, y) = (rng.octavian(1 << 26), rng.octavian(1 << 26));
+        check_i64(x, y);
+        let narrow = |z: Octavian<i64>| Octavian::new(z.coefficients.map(|c| (c >> 16) as i32));
+        check_i32(narrow(x), narrow(y));
+        let small = |z: Octavian<i64>| Octavian::new(z.coefficients.map(|c| c >> 8));
+        check_f64(small(x), small(y));
+    }
+}
+
+#[cfg(feature = "simd")]
+#[test]
+/// Ensure that operands near the overflow threshold give the same results with the vectorized kernels as without:
+/// exact when the result fits, and a panic in debug builds when a partial sum overflows, rather than a wrapped value.
+fn test_simd_near_overflow() {
+    let mut rng = TestRng(0x0fe1);
+    for _ in 0..10_000 {
+        let x = rng.octavian(1 << 28);
+        let y = rng.octavian(1 << 28);
+        assert_eq!(to_i128(&(x * y)), to_i128(&x) * to_i128(&y));
+        assert_eq!(
+            i128::from(x.inner_product(&y)),
+            to_i128(&x).inner_product(&to_i128(&y))
+        );
+        let big = x.scale(1 << 33);
+        assert_eq!(to_i128(&(big + big)), to_i128(&big) + to_i128(&big));
+        assert_eq!(to_i128(&(big - x)), to_i128(&big) - to_i128(&x));
+
+        let narrow = |z: Octavian<i64>| Octavian::new(z.coefficients.map(|c| (c >> 16) as i32));
+        let (x, y) = (narrow(x), narrow(y));
+        assert_eq!(to_i128(&(x * y)), to_i128(&x) * to_i128(&y));
+        assert_eq!(
+            i128::from(x.inner_product(&y)),
+            to_i128(&x).inner_product(&to_i128(&y))
+        );
+        let big = x.scale(1 << 17);
+        assert_eq!(to_i128(&(big + big)), to_i128(&big) + to_i128(&big));
+    }
+
+    // The coefficients of the sum cancel, but the partial sums of the products and inner products do not fit.
+    fn overflows<R>(f: impl FnOnce() -> R + std::panic::UnwindSafe) -> bool {
+        std::panic::catch_unwind(f).is_err()
+    }
+    let x = Octavian::<i64>::new([i64::MAX / 2 + 1; 8]);
+    assert_eq!(Octavian::zero(), x + -x);
+    let z = Octavian::<i32>::new([i32::MAX / 2 + 1; 8]);
+    assert_eq!(Octavian::zero(), z + -z);
+    if cfg!(debug_assertions) {
+        assert!(overflows(|| x + x));
+        assert!(overflows(|| x * x));
+        assert!(overflows(|| x.inner_product(&x)));
+        assert!(overflows(|| x.left_multiplier().apply(&x)));
+        assert!(overflows(|| z + z));
+        assert!(overflows(|| z * z));
+        assert!(overflows(|| z.inner_product(&z)));
+    }
+}
+
+#[test]
+/// Ensure that batched products agree with the naive loops, and that the parallel paths agree exactly with the serial ones.
+fn test_batched_products() {
+    let mut rng = TestRng(0xba7c4);
+    let lhs: Vec<Octavian<i64>> = (0..30).map(|_| rng.octavian(50)).collect();
+    let rhs: Vec<Octavian<i64>> = (0..40).map(|_| rng.octavian(50)).collect();
+    let all = batch::multiply_all_pairs(&lhs, &rhs);
+    let mut naive = Vec::new();
+    for x in &lhs {
+        for y in &rhs {
+            naive.push(*x * *y);
+        }
+    }
+    assert_eq!(naive, all);
+    let mut sorted = all.clone();
+    sorted.sort_unstable_by_key(|x| x.coefficients);
+    naive.sort_unstable_by_key(|x| x.coefficients);
+    assert_eq!(naive, sorted);
+
+    let pairwise = batch::multiply_pairwise(&lhs, &rhs[..30]).unwrap();
+    for (i, p) in pairwise.iter().enumerate() {
+        assert_eq!(lhs[i] * rhs[i], *p);
+    }
+    assert_eq!(None, batch::multiply_pairwise(&lhs, &rhs));
+
+    #[cfg(feature = "par")]
+    {
+        assert_eq!(all, batch::multiply_all_pairs_par(&lhs, &rhs));
+        assert_eq!(
+            Some(pairwise),
+            batch::multiply_pairwise_par(&lhs, &rhs[..30])
+        );
+        assert_eq!(None, batch::multiply_pairwise_par(&lhs, &rhs));
+        let floats: Vec<Octavian<f64>> = (0..20)
+            .map(|_| Octavian::new(rng.octavian(1000).coefficients.map(|c| c as f64 / 7.0)))
+            .collect();
+        let serial = batch::multiply_all_pairs(&floats, &floats);
+        let parallel = batch::multiply_all_pairs_par(&floats, &floats);
+        let bits = |v: &[Octavian<f64>]| -> Vec<[u64; 8]> {
+            v.iter().map(|x| x.coefficients.map(f64::to_bits)).collect()
+        };
+        assert_eq!(bits(&serial), bits(&parallel));
+    }
+}
+
+#[test]
+/// Ensure that the theta series of E8 has coefficients 240 σ3(n), and that the parallel enumerations match the serial ones.
+fn test_theta_coefficients() {
+    let sigma3 = |n: u64| {
+        (1..=n)
+            .filter(|&d| n.is_multiple_of(d))
+            .map(|d| d * d * d)
+            .sum::<u64>()
+    };
+    let theta = lattice::theta_coefficients(8);
+    assert_eq!(1, theta[0]);
+    for n in 1..=8 {
+        assert_eq!(240 * sigma3(n), theta[n as usize]);
+    }
+    #[cfg(feature = "par")]
+    {
+        assert_eq!(theta, lattice::theta_coefficients_par(8));
+        for n in 0..=10 {
+            assert_eq!(lattice::norm_shell(n), lattice::norm_shell_par(n));
+        }
+        // A single-threaded pool must give the same result.
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            lattice::norm_shell(4),
+            pool.install(|| lattice::norm_shell_par(4))
+        );
+        assert_eq!(theta, pool.install(|| lattice::theta_coefficients_par(8)));
+        // A larger shell must complete; its size is 240 σ3(14).
+        assert_eq!(240 * sigma3(14), lattice::norm_shell_par(14).len() as u64);
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the cached tables are built once and agree with the constant tables they are derived from.
+fn test_tables() {
+    tables::warm_tables();
+    tables::warm_tables();
+
+    assert!(std::ptr::eq(tables::unit_set(), tables::unit_set()));
+    assert!(std::ptr::eq(
+        tables::unit_multiplication_table(),
+        tables::unit_multiplication_table()
+    ));
+    assert!(std::ptr::eq(
+        units::unit_multiplication_table(),
+        tables::unit_multiplication_table()
+    ));
+    assert!(std::ptr::eq(
+        tables::unit_inverses(),
+        tables::unit_inverses()
+    ));
+    assert!(std::ptr::eq(
+        tables::adjoint_matrices_i64(),
+        tables::adjoint_matrices_i64()
+    ));
+
+    assert!(Octavian::UNITS
+        .windows(2)
+        .all(|w| w[0].coefficients < w[1].coefficients));
+    assert_eq!(tables::unit_set().len(), 240);
+    assert!(Octavian::UNITS
+        .iter()
+        .all(|u| tables::unit_set().contains(u)));
+    let table = tables::unit_multiplication_table();
+    let one = Octavian::<i8>::one().unit_index().unwrap();
+    for (i, u) in Octavian::UNITS.into_iter().enumerate() {
+        let inverse = tables::unit_inverses()[i] as usize;
+        assert_eq!(table[i][inverse], one);
+        assert_eq!(Octavian::UNITS[inverse], u.conjugate());
+        for (j, v) in Octavian::UNITS.into_iter().enumerate() {
+            assert_eq!(
+                Octavian::UNITS[table[i][j] as usize],
+                u.left_multiplier().apply(&v)
+            );
+        }
+    }
+    let gram = tables::gram_matrix_i64();
+    let inverse = tables::inverse_gram_matrix_i64();
+    for (i, row) in gram.iter().enumerate() {
+        for j in 0..8 {
+            assert_eq!(row[j], i64::from(Octavian::<i8>::GRAM_MATRIX[i][j]));
+            let entry: i64 = (0..8).map(|k| row[k] * inverse[k][j]).sum();
+            assert_eq!(entry, i64::from(i == j));
+        }
+    }
+    let basis = Octavian::<i64>::basis_vectors();
+    for (k, matrix) in tables::adjoint_matrices_i64().iter().enumerate() {
+        for (j, b) in basis.iter().enumerate() {
+            let product = basis[k].left_multiplier().apply(b);
+            assert!((0..8).all(|i| product.coefficients[i] == matrix[i][j]));
+        }
+    }
+}
+
+/// A primitive cube root of unity: a unit of trace -1, so that ω² + ω + 1 = 0.
+#[cfg(feature = "tables")]
+const OMEGA: Octavian<i64> = Octavian::new([0, 0, 0, 0, 0, 1, 1, 1]);
+
+/// Tests coefficient equality in a constant expression.
+#[cfg(feature = "tables")]
+const fn const_eq(a: &Octavian<i64>, b: &Octavian<i64>) -> bool {
+    let mut i = 0;
+    while i < 8 {
+        if a.coefficients[i] != b.coefficients[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The closure of the given units under multiplication, as a fixed-size array padded with zeros, and its size.
+#[cfg(feature = "tables")]
+const fn const_closure<const N: usize>(
+    generators: &[Octavian<i64>],
+) -> ([Octavian<i64>; N], usize) {
+    let mut elements = [Octavian::new([0; 8]); N];
+    let mut len = 0;
+    let mut g = 0;
+    while g < generators.len() {
+        elements[len] = generators[g];
+        len += 1;
+        g += 1;
+    }
+    let mut i = 0;
+    while i < len {
+        let mut j = 0;
+        while j < len {
+            let product = Octavian::const_mul(elements[i], elements[j]);
+            let mut k = 0;
+            while k < len && !const_eq(&elements[k], &product) {
+                k += 1;
+            }
+            if k == len {
+                elements[len] = product;
+                len += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    (elements, len)
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the const fn arithmetic agrees with the operators and can build constants such as a cyclic closure.
+fn test_const_arithmetic() {
+    const OMEGA_SQUARED: Octavian<i64> = Octavian::const_mul(OMEGA, OMEGA);
+    const SUM: Octavian<i64> = Octavian::const_add(OMEGA, OMEGA_SQUARED);
+    const NEGATED: Octavian<i64> = Octavian::const_neg(OMEGA);
+    assert_eq!(OMEGA_SQUARED, OMEGA * OMEGA);
+    assert_eq!(SUM, OMEGA + OMEGA * OMEGA);
+    assert_eq!(NEGATED, -OMEGA);
+    assert_eq!(SUM, -Octavian::one());
+    assert_eq!(Octavian::const_mul(OMEGA_SQUARED, OMEGA), Octavian::one());
+
+    let mut rng = TestRng(0xc0);
+    for _ in 0..1000 {
+        let (x, y) = (rng.octavian(1000), rng.octavian(1000));
+        assert_eq!(Octavian::const_mul(x, y), x * y);
+        assert_eq!(Octavian::const_add(x, y), x + y);
+        assert_eq!(Octavian::const_neg(x), -x);
+    }
+
+    // The units ω and -1 generate the cyclic group of order 6.
+    const GENERATORS: [Octavian<i64>; 2] = [OMEGA, Octavian::new([2, 3, 4, 6, 5, 4, 3, 2])];
+    const CLOSURE: ([Octavian<i64>; 8], usize) = const_closure(&GENERATORS);
+    let (elements, len) = CLOSURE;
+    let (generated, _) = units::generated_subloop(
+        &GENERATORS.map(|g| Octavian::new(g.coefficients.map(|c| c as i8))),
+    )
+    .unwrap();
+    assert_eq!(len, 6);
+    assert_eq!(len, generated.len());
+    for x in &elements[..len] {
+        assert!(generated.contains(&Octavian::new(x.coefficients.map(|c| c as i8))));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that i8 products are exact whenever the result fits, even when partial sums do not, and fail otherwise.
+fn test_mul_exact_i8() {
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    for u in Octavian::UNITS {
+        for v in Octavian::UNITS {
+            assert_eq!(widen(u.mul_exact_i8(&v)), widen(u) * widen(v));
+        }
+    }
+
+    // The octavian x has norm 4 and x * x̄ = 4, but the partial sums of the product reach 132,
+    // which overflowed i8 before the product was accumulated in a wider type.
+    let x = Octavian::<i8>::new([-1, -1, -2, -1, -2, -2, -2, -2]);
+    let four = Octavian::<i8>::one().scale(4);
+    assert_eq!(x.mul_exact_i8(&x.conjugate()), four);
+    assert_eq!(x * x.conjugate(), four);
+    assert_eq!(x.checked_mul_i8(&x.conjugate()), Some(four));
+
+    let big = Octavian::<i8>::one().scale(11);
+    assert_eq!(big.checked_mul_i8(&big), None);
+    assert!(std::panic::catch_unwind(|| big * big).is_err());
+}
+
+#[test]
+/// Ensure that norms and inner products evaluated in a wider type agree with arbitrary-precision arithmetic.
+fn test_wide_norm() {
+    // The inner product evaluated directly from the Gram matrix in arbitrary precision.
+    let reference = |x: &[i64; 8], y: &[i64; 8]| {
+        let mut sum = BigInt::from(0);
+        for (i, row) in Octavian::<i64>::GRAM_MATRIX.iter().enumerate() {
+            for (j, &g) in row.iter().enumerate() {
+                sum += BigInt::from(g) * BigInt::from(x[i]) * BigInt::from(y[j]);
+            }
+        }
+        sum
+    };
+    let widen = |x: &Octavian<i32>| x.coefficients.map(i64::from);
+
+    let x = Octavian::<i32>::new([40000, -40000, 40000, -40000, 40000, -40000, 40000, -40000]);
+    assert_eq!(
+        BigInt::from(2 * x.norm_in::<i64>()),
+        reference(&widen(&x), &widen(&x))
+    );
+    assert!(x.norm_in::<i64>() > i64::from(i32::MAX));
+    assert_eq!(BigInt::from(x.norm_in::<i64>()), x.norm_in::<BigInt>());
+
+    let mut rng = TestRng(0x3de);
+    for _ in 0..1000 {
+        let x = Octavian::new(rng.octavian(1 << 15).coefficients.map(|c| c as i32));
+        let y = Octavian::new(rng.octavian(1 << 15).coefficients.map(|c| c as i32));
+        assert_eq!(
+            BigInt::from(x.inner_product_in::<i64>(&y)),
+            reference(&widen(&x), &widen(&y))
+        );
+        assert_eq!(
+            BigInt::from(2 * x.norm_in::<i64>()),
+            reference(&widen(&x), &widen(&x))
+        );
+
+        let z = rng.octavian(1 << 40);
+        assert_eq!(
+            BigInt::from(2 * z.norm_i128().unwrap()),
+            reference(&z.coefficients, &z.coefficients)
+        );
+    }
+
+    // Coefficients near the limits of i64 can overflow the inner product in i128, which is reported rather than wrapped.
+    let huge = Octavian::<i64>::new([i64::MAX, i64::MAX, 0, 0, 1 << 32, 0, 1 << 32, 0]);
+    assert_eq!(Err(error::AlcoError::Overflow), huge.norm_i128());
+    assert_eq!(None, huge.checked_norm_in::<i128>());
+    assert_eq!(
+        Some(reference(&huge.coefficients, &huge.coefficients) / 2),
+        huge.checked_norm_in::<BigInt>()
+    );
+    let z = rng.octavian(1 << 40);
+    assert_eq!(
+        Some(z.inner_product_in::<i128>(&huge)),
+        z.checked_inner_product_in::<i128>(&huge)
+    );
+
+    // Where the narrow path does not overflow, both agree, including the factor of two between norm and inner product.
+    for _ in 0..1000 {
+        let x = Octavian::new(rng.octavian(1000).coefficients.map(|c| c as i32));
+        let y = Octavian::new(rng.octavian(1000).coefficients.map(|c| c as i32));
+        assert_eq!(x.norm_in::<i64>(), i64::from(x.norm()));
+        assert_eq!(
+            x.inner_product_in::<i64>(&y),
+            i64::from(x.inner_product(&y))
+        );
+        assert_eq!(2 * x.norm_in::<i64>(), x.inner_product_in::<i64>(&x));
+    }
+}
+
+#[test]
+/// Ensure that the typed tables convert the constant tables correctly and that the cached types convert them only once.
+fn test_typed_tables() {
+    fn check<T: octavian::Coefficient + PartialEq + std::fmt::Debug>() {
+        let adjoint = tables::adjoint_matrices_typed::<T>();
+        assert_eq!(adjoint, tables::adjoint_matrices_typed::<T>());
+        assert_eq!(
+            adjoint,
+            Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES
+                .map(|m| m.map(|row| row.map(|x| T::from_i8(x).unwrap())))
+        );
+        let gram = tables::gram_matrix_typed::<T>();
+        assert_eq!(gram, tables::gram_matrix_typed::<T>());
+        assert_eq!(
+            gram,
+            Octavian::<i8>::GRAM_MATRIX.map(|row| row.map(|x| T::from_i8(x).unwrap()))
+        );
+        // The cached types convert at most once per process, whichever thread gets there first.
+        let conversions = || tables::TABLE_CONVERSIONS.with(|c| c.get());
+        let before = conversions();
+        for _ in 0..100 {
+            tables::with_adjoint_matrices(|m: &tables::AdjointMatrices<T>| assert_eq!(m, &adjoint));
+            tables::with_gram_matrix(|g: &tables::GramMatrix<T>| assert_eq!(g, &gram));
+        }
+        assert_eq!(conversions(), before);
+    }
+    check::<i32>();
+    check::<i64>();
+    check::<f64>();
+    check::<i128>();
+
+    // A tight multiplication loop reads the cached tables instead of converting them for every product.
+    let conversions = || tables::TABLE_CONVERSIONS.with(|c| c.get());
+    let mut rng = TestRng(0x7ab);
+    let x = rng.octavian(100);
+    let y = rng.octavian(100);
+    let z = Octavian::new(x.coefficients.map(|c| c as f64));
+    let before = conversions();
+    for _ in 0..10_000 {
+        std::hint::black_box(x * y);
+        std::hint::black_box(x.left_multiplier().apply(&y));
+        std::hint::black_box(z * z);
+    }
+    assert!(conversions() - before <= 2);
+
+    // Coefficient types without a cache read the structure constants instead of converting the whole table.
+    let ratio = |x: &Octavian<i64>| Octavian::new(x.coefficients.map(Ratio::from_integer));
+    let (r, s) = (ratio(&x), ratio(&y));
+    let before = conversions();
+    for _ in 0..10 {
+        assert_eq!(r * s, ratio(&(x * y)));
+        assert_eq!(r.left_multiplier().apply(&s), ratio(&(x * y)));
+        assert_eq!(s.right_multiplier().apply(&r), ratio(&(x * y)));
+    }
+    for b in Octavian::<i64>::basis_vectors() {
+        assert_eq!(ratio(&b) * r, ratio(&(b * x)));
+        assert_eq!(r * ratio(&b), ratio(&(x * b)));
+    }
+    assert_eq!(conversions(), before);
+}
+
+/// The checked-in snapshot of the generated tables.
+#[cfg(feature = "verify-tables")]
+mod snapshot {
+    include!("../snapshots/tables.rs");
+}
+
+#[test]
+#[cfg(feature = "verify-tables")]
+/// Ensure that the tables generated by build.rs match the checked-in snapshot.
+fn test_generated_tables_match_snapshot() {
+    assert_eq!(
+        Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES,
+        snapshot::OCTAVIAN_ADJOINT_MATRICES
+    );
+    assert_eq!(
+        Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS,
+        snapshot::OCTAVIAN_UNITS_COEFFICIENTS
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the in-place and borrowing arithmetic agree with the operators.
+fn test_in_place_arithmetic() {
+    let mut rng = TestRng(0x1a9);
+    for _ in 0..1000 {
+        let (x, y) = (rng.octavian(1000), rng.octavian(1000));
+        let mut z = x;
+        z.add_assign_ref(&y);
+        assert_eq!(z, x + y);
+        z.sub_assign_ref(&y);
+        assert_eq!(z, x);
+        z.scale_in_place(-7);
+        assert_eq!(z, x.scale(-7));
+        let mut product = Octavian::zero();
+        x.mul_into(&y, &mut product);
+        assert_eq!(product, x * y);
+        // Reusing an output buffer overwrites it rather than accumulating into it.
+        y.mul_into(&x, &mut product);
+        assert_eq!(product, y * x);
+    }
+    for u in Octavian::UNITS {
+        for v in Octavian::UNITS {
+            let mut product = Octavian::one();
+            u.mul_into(&v, &mut product);
+            assert_eq!(product, u * v);
+        }
+    }
+    let x = Octavian::<f64>::basis_vectors()[3].scale(0.25);
+    let y = Octavian::<f64>::one().scale(-1.5) + Octavian::<f64>::basis_vectors()[6];
+    let mut product = Octavian::zero();
+    x.mul_into(&y, &mut product);
+    assert_eq!(product, x * y);
+
+    // Multiplying in place goes through a copy of the factor, since `out` cannot alias an input.
+    let mut x = rng.octavian(100);
+    let expected = x * x;
+    let copy = x;
+    copy.mul_into(&copy, &mut x);
+    assert_eq!(x, expected);
+
+    // Accumulate a long sum of products in wide coefficients, reusing a single product buffer.
+    let factors: Vec<Octavian<i128>> = (0..200)
+        .map(|_| Octavian::new(rng.octavian(1 << 30).coefficients.map(i128::from)))
+        .collect();
+    let mut sum = Octavian::zero();
+    let mut product = Octavian::zero();
+    for pair in factors.windows(2) {
+        pair[0].mul_into(&pair[1], &mut product);
+        sum.add_assign_ref(&product);
+    }
+    let expected = factors
+        .windows(2)
+        .fold(Octavian::zero(), |acc, pair| acc + pair[0] * pair[1]);
+    assert_eq!(sum, expected);
+}
+
+#[test]
+/// Ensure that borrowed views of flat coefficient buffers agree with the owned octavians they describe.
+fn test_octavian_views() {
+    let mut rng = TestRng(0x51e);
+    let owned: Vec<Octavian<i64>> = (0..1000).map(|_| rng.octavian(1000)).collect();
+    let flat: Vec<i64> = owned.iter().flat_map(|x| x.coefficients).collect();
+    let slice = view::OctavianSlice::new(&flat).unwrap();
+    assert_eq!(slice.len(), 1000);
+    assert!(!slice.is_empty());
+    assert!(slice.get(1000).is_none());
+    assert!(slice.get(usize::MAX / 8).is_none());
+    assert!(slice.get(usize::MAX / 8 + 1).is_none());
+    assert!(slice.get(usize::MAX).is_none());
+
+    for (i, (x, y)) in owned.iter().zip(slice.iter()).enumerate() {
+        assert_eq!(y, *x);
+        assert_eq!(*x, y);
+        assert_eq!(slice.get(i), Some(y));
+        assert_eq!(y.to_octavian(), *x);
+        assert_eq!(y.norm(), x.norm());
+        assert_eq!(x.as_ref_view(), y);
+        let z = slice.get((i + 1) % 1000).unwrap();
+        assert_eq!(y.inner_product(&z), x.inner_product(&z.to_octavian()));
+        assert_eq!(y * z, *x * z.to_octavian());
+    }
+
+    // Norms over the whole buffer borrow each chunk in place.
+    let norms: i64 = slice.iter().map(|x| x.norm()).sum();
+    assert_eq!(norms, owned.iter().map(|x| x.norm()).sum::<i64>());
+
+    assert!(view::OctavianSlice::new(&flat[..12]).is_none());
+    assert!(view::OctavianSlice::new(&flat[..7]).is_none());
+    let empty = view::OctavianSlice::<i64>::new(&[]).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the deduplicating buffer agrees with a HashSet on membership and keeps insertion order.
+fn test_dedup_buffer() {
+    let units = Octavian::UNITS.map(|u| Octavian::new(u.coefficients.map(i64::from)));
+    let mut buffer = dedup::DedupBuffer::new();
+    assert!(units.iter().all(|u| buffer.insert(u)));
+    assert!(units.iter().all(|u| !buffer.insert(u)));
+    assert_eq!(buffer.len(), 240);
+    assert!(buffer.iter().eq(units.iter().copied()));
+    assert_eq!(buffer.get(17), Some(units[17]));
+    assert_eq!(buffer.get(240), None);
+    assert_eq!(buffer.get(usize::MAX / 8 + 1), None);
+    assert_eq!(buffer.get(usize::MAX), None);
+    assert!(!buffer.contains(&Octavian::zero()));
+
+    // Membership and iteration order agree with a HashSet and the insertion order on a workload with many repeats.
+    let mut rng = TestRng(0xdedb);
+    let workload: Vec<Octavian<i64>> = (0..20_000).map(|_| rng.octavian(1)).collect();
+    let mut buffer = dedup::DedupBuffer::new();
+    let mut again = dedup::DedupBuffer::with_capacity(10);
+    let mut set = HashSet::new();
+    let mut order = Vec::new();
+    for x in &workload {
+        let inserted = set.insert(*x);
+        assert_eq!(buffer.insert(x), inserted);
+        assert_eq!(again.insert(x), inserted);
+        if inserted {
+            order.push(*x);
+        }
+    }
+    assert!(buffer.iter().eq(order.iter().copied()));
+    assert!(buffer.iter().eq(again.iter()));
+    assert!(set.iter().all(|x| buffer.contains(x)));
+
+    // A million distinct octavians take less memory than in a HashSet, even counting the set's buckets alone.
+    let mut buffer = dedup::DedupBuffer::new();
+    let mut set = HashSet::new();
+    for n in 0..1_000_000i64 {
+        let x = Octavian::new([n, -n, 2 * n, 0, 1, n % 7, 3, n / 3]);
+        buffer.insert(&x);
+        set.insert(x);
+    }
+    assert_eq!(buffer.len(), set.len());
+    let set_bytes = set.capacity() * size_of::<Octavian<i64>>();
+    assert!(buffer.allocated_bytes() < set_bytes);
+
+    // The set used by the closure moves into a buffer past the threshold and keeps answering membership correctly.
+    let mut seen = dedup::Dedup::new();
+    for n in 0..2 * dedup::DEDUP_THRESHOLD as i64 {
+        assert!(seen.insert(&Octavian::new([n; 8])));
+        assert!(!seen.insert(&Octavian::new([n / 2; 8])));
+    }
+    assert!(matches!(seen, dedup::Dedup::Large(_)));
+}
+
+#[test]
+/// Ensure that batch maps keep the input order, match the serial calls, and turn a panic into that element's error.
+fn test_batch_map_each() {
+    let mut rng = TestRng(0x0dd5);
+    let mut elements: Vec<Octavian<i64>> = (0..10_000).map(|_| rng.octavian(1 << 20)).collect();
+    elements[17] = Octavian::zero();
+    let inverse_norm = |x: &Octavian<i64>| {
+        let n = x.norm_i128().unwrap();
+        assert_ne!(n, 0, "zero has no inverse");
+        (x.conjugate(), n)
+    };
+    let results = batch::map_each(&elements, inverse_norm);
+    assert_eq!(results.len(), elements.len());
+    for (i, (x, result)) in elements.iter().zip(&results).enumerate() {
+        if i == 17 {
+            let error = result.as_ref().unwrap_err();
+            assert_eq!(error.index, 17);
+            assert!(error.message.contains("zero has no inverse"));
+        } else {
+            assert_eq!(result.as_ref().ok(), Some(&inverse_norm(x)));
+        }
+    }
+    #[cfg(feature = "par")]
+    assert_eq!(results, batch::map_each_par(&elements, inverse_norm));
+}
+
+#[test]
+/// Ensure that batch factorization keeps the input order, matches the serial calls, and keeps each element's error.
+fn test_factor_many() {
+    let mut rng = TestRng(0xfa11);
+    let mut elements: Vec<Octavian<i64>> = (0..2000).map(|_| rng.octavian(3)).collect();
+    elements[17] = Octavian::zero();
+    let results = batch::factor_many(&elements);
+    assert_eq!(results.len(), elements.len());
+    for (x, result) in elements.iter().zip(&results) {
+        assert_eq!(&factor::factor(x), result);
+    }
+    assert_eq!(Err(error::AlcoError::Zero), results[17]);
+    #[cfg(feature = "par")]
+    assert_eq!(results, batch::factor_many_par(&elements));
+
+    #[cfg(feature = "tables")]
+    {
+        let pairs: Vec<_> = elements
+            .chunks(2)
+            .take(1000)
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+        let divisors = batch::gcrd_many(&pairs);
+        for ((a, b), g) in pairs.iter().zip(&divisors) {
+            assert_eq!(&octavian::gcrd(a, b), g);
+        }
+        #[cfg(feature = "par")]
+        assert_eq!(divisors, batch::gcrd_many_par(&pairs));
+    }
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+/// Ensure that octavians round-trip through nalgebra vectors and that the nalgebra adjoint matrices multiply correctly.
+fn test_nalgebra_interop() {
+    use nalgebra::{SMatrix, SVector};
+    let mut rng = TestRng(0x4a16);
+    for _ in 0..100 {
+        let (a, b) = (rng.octavian(1000), rng.octavian(1000));
+        let v: SVector<i64, 8> = a.into();
+        assert_eq!(a, Octavian::from(v));
+        assert_eq!(a * b, linalg::apply_matrix(&a.left_adjoint_matrix_na(), &b));
+    }
+    for u in Octavian::UNITS {
+        let u = Octavian::new(u.coefficients.map(f64::from));
+        for v in Octavian::UNITS {
+            let v = Octavian::new(v.coefficients.map(f64::from));
+            assert_eq!(u * v, linalg::apply_matrix(&u.left_adjoint_matrix_na(), &v));
+        }
+    }
+    let gram: SMatrix<f64, 8, 8> = Octavian::gram_matrix_na();
+    assert!((gram.determinant() - 1.0).abs() < 1e-9);
+    assert!(gram.cholesky().is_some());
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+/// Ensure that octavians round-trip through ndarray arrays, that the width is validated,
+/// and that the Gram array gives the same inner products as the crate.
+fn test_ndarray_interop() {
+    use ndarray::Array2;
+    let shell = lattice::norm_shell(2);
+    let a = arrays::octavians_to_array2(&shell);
+    assert_eq!(a.dim(), (shell.len(), 8));
+    assert_eq!(Some(shell.clone()), arrays::array2_to_octavians(a.view()));
+    assert_eq!(
+        None,
+        arrays::array2_to_octavians(Array2::<i64>::zeros((3, 7)).view())
+    );
+    assert_eq!(None, arrays::array2_to_octavians(a.t()));
+    assert_eq!(
+        Some(vec![]),
+        arrays::array2_to_octavians(Array2::<i64>::zeros((0, 8)).view())
+    );
+
+    let gram = arrays::gram_as_array2::<i64>();
+    let products = a.dot(&gram).dot(&a.t());
+    let mut rng = TestRng(0xa77a);
+    for _ in 0..1000 {
+        let i = rng.next_u64() as usize % shell.len();
+        let j = rng.next_u64() as usize % shell.len();
+        assert_eq!(shell[i].inner_product(&shell[j]), products[(i, j)]);
+    }
+}
+
+/// A value of the GAP list syntax used by the exports: an integer or a list of values.
+#[cfg(feature = "tables")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GapValue {
+    Int(i64),
+    List(Vec<GapValue>),
+}
+
+#[cfg(feature = "tables")]
+impl GapValue {
+    /// Parses a complete GAP value by recursive descent, returning `None` on any syntax error or trailing input.
+    fn parse(s: &str) -> Option<GapValue> {
+        let mut chars = s.chars().filter(|c| !c.is_whitespace()).peekable();
+        let value = Self::parse_value(&mut chars)?;
+        chars.next().is_none().then_some(value)
+    }
+
+    fn parse_value(
+        chars: &mut std::iter::Peekable<impl Iterator<Item = char>>,
+    ) -> Option<GapValue> {
+        if chars.next_if_eq(&'[').is_some() {
+            let mut items = Vec::new();
+            if chars.next_if_eq(&']').is_some() {
+                return Some(GapValue::List(items));
+            }
+            loop {
+                items.push(Self::parse_value(chars)?);
+                match chars.next()? {
+                    ',' => continue,
+                    ']' => return Some(GapValue::List(items)),
+                    _ => return None,
+                }
+            }
+        }
+        let mut digits = String::new();
+        if let Some(sign) = chars.next_if_eq(&'-') {
+            digits.push(sign);
+        }
+        while let Some(d) = chars.next_if(char::is_ascii_digit) {
+            digits.push(d);
+        }
+        digits.parse().ok().map(GapValue::Int)
+    }
+
+    /// Returns the value as a list of integer vectors of length 8, if it is one.
+    fn vectors(&self) -> Option<Vec<[i64; 8]>> {
+        let GapValue::List(items) = self else {
+            return None;
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                GapValue::List(entries) => entries
+                    .iter()
+                    .map(|e| match e {
+                        GapValue::Int(x) => Some(*x),
+                        GapValue::List(_) => None,
+                    })
+                    .collect::<Option<Vec<i64>>>()?
+                    .try_into()
+                    .ok(),
+                GapValue::Int(_) => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the GAP exports parse as nested lists with the right shapes and entries,
+/// and that the Gram matrix export matches its snapshot.
+fn test_gap_export() {
+    use export::gap;
+    assert_eq!(None, GapValue::parse("[ [ 1, 2 ], [ 3 ]"));
+    assert_eq!(None, GapValue::parse("[ 1,, 2 ]"));
+    assert_eq!(None, GapValue::parse("[ 1 ] ]"));
+
+    let gram = tables::gram_matrix_i64();
+    let exported = gap::gap_matrix(gram);
+    assert_eq!(
+        exported,
+        "[ [ 2, 0, -1, 0, 0, 0, 0, 0 ],\n  \
+         [ 0, 2, 0, -1, 0, 0, 0, 0 ],\n  \
+         [ -1, 0, 2, -1, 0, 0, 0, 0 ],\n  \
+         [ 0, -1, -1, 2, -1, 0, 0, 0 ],\n  \
+         [ 0, 0, 0, -1, 2, -1, 0, 0 ],\n  \
+         [ 0, 0, 0, 0, -1, 2, -1, 0 ],\n  \
+         [ 0, 0, 0, 0, 0, -1, 2, -1 ],\n  \
+         [ 0, 0, 0, 0, 0, 0, -1, 2 ] ]"
+    );
+    assert_eq!(
+        Some(gram.to_vec()),
+        GapValue::parse(&exported).unwrap().vectors()
+    );
+
+    let units: Vec<Octavian<i64>> = Octavian::UNITS
+        .iter()
+        .map(|u| Octavian::new(u.coefficients.map(i64::from)))
+        .collect();
+    let parsed = GapValue::parse(&gap::gap_octavian_list(&units)).unwrap();
+    let vectors = parsed.vectors().unwrap();
+    assert_eq!(vectors.len(), 240);
+    assert!(vectors
+        .iter()
+        .zip(&units)
+        .all(|(v, u)| *v == u.coefficients));
+    assert_eq!(
+        Some(GapValue::List(vec![])),
+        GapValue::parse(&gap::gap_octavian_list(&[]))
+    );
+
+    let GapValue::List(matrices) = GapValue::parse(&gap::gap_adjoint_matrices()).unwrap() else {
+        panic!("the adjoint matrices export is a list");
+    };
+    assert_eq!(matrices.len(), 8);
+    for (m, expected) in matrices.iter().zip(tables::adjoint_matrices_i64()) {
+        assert_eq!(Some(expected.to_vec()), m.vectors());
+    }
+}
+
+#[test]
+/// Ensure that the Magma exports match their snapshots and embed the right element counts.
+fn test_magma_export() {
+    use export::magma;
+    assert_eq!(
+        magma::magma_lattice("L"),
+        "L := LatticeWithGram(Matrix(Integers(), 8, 8, [\n  \
+         2, 0, -1, 0, 0, 0, 0, 0,\n  \
+         0, 2, 0, -1, 0, 0, 0, 0,\n  \
+         -1, 0, 2, -1, 0, 0, 0, 0,\n  \
+         0, -1, -1, 2, -1, 0, 0, 0,\n  \
+         0, 0, 0, -1, 2, -1, 0, 0,\n  \
+         0, 0, 0, 0, -1, 2, -1, 0,\n  \
+         0, 0, 0, 0, 0, -1, 2, -1,\n  \
+         0, 0, 0, 0, 0, 0, -1, 2 ]));"
+    );
+    let vectors = [
+        Octavian::<i64>::one(),
+        Octavian::new([1, 0, 0, 0, 0, 0, 0, 0]),
+        Octavian::new([0, 0, 0, 0, 0, 0, 3, -4]),
+    ];
+    assert_eq!(
+        magma::magma_vectors("V", &vectors),
+        "V := Matrix(Integers(), 3, 8, [\n  \
+         -2, -3, -4, -6, -5, -4, -3, -2,\n  \
+         1, 0, 0, 0, 0, 0, 0, 0,\n  \
+         0, 0, 0, 0, 0, 0, 3, -4 ]);"
+    );
+    assert_eq!(
+        magma::magma_vectors("E", &[]),
+        "E := Matrix(Integers(), 0, 8, [\n ]);"
+    );
+
+    let shell = lattice::norm_shell(2);
+    let exported = magma::magma_vectors("S", &shell);
+    assert!(exported.starts_with(&format!("S := Matrix(Integers(), {}, 8, [", shell.len())));
+    assert_eq!(exported.lines().count(), shell.len() + 1);
+
+    // The sparse structure constants list every nonzero entry of the adjoint matrices once.
+    let algebra = magma::magma_algebra("O");
+    let nonzero = tables::adjoint_matrices_i64()
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|&&c| c != 0)
+        .count();
+    assert_eq!(algebra.matches('<').count(), nonzero + 1);
+    assert!(algebra.starts_with("O := Algebra<Integers(), 8 | [\n"));
+    assert!(algebra.ends_with(" ]>;"));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that CSV round-trips the units with and without a header and norm column,
+/// tolerates whitespace and CRLF, and reports the line and column of malformed input.
+fn test_csv_round_trip() {
+    let units: Vec<Octavian<i64>> = Octavian::UNITS
+        .iter()
+        .map(|u| Octavian::new(u.coefficients.map(i64::from)))
+        .collect();
+    for header in [false, true] {
+        for norm_column in [false, true] {
+            let mut buffer = Vec::new();
+            let options = io::CsvOptions {
+                header,
+                norm_column,
+            };
+            io::write_csv_with(&mut buffer, &units, options).unwrap();
+            let text = String::from_utf8(buffer).unwrap();
+            assert_eq!(text.lines().count(), 240 + usize::from(header));
+            assert_eq!(units, io::read_csv(text.as_bytes()).unwrap());
+        }
+    }
+    let mut buffer = Vec::new();
+    io::write_csv(&mut buffer, &units[..2]).unwrap();
+    assert_eq!(
+        "-2,-3,-4,-6,-5,-4,-3,-2\n-2,-3,-4,-6,-5,-4,-3,-1\n",
+        String::from_utf8(buffer).unwrap()
+    );
+
+    let messy = "c0, c1, c2, c3, c4, c5, c6, c7, norm\r\n 1, 0 ,0,0,0,0,0,0 , 1\r\n\r\n0,0,0,0,0,0,0,-1\r\n";
+    assert_eq!(
+        vec![
+            Octavian::new([1, 0, 0, 0, 0, 0, 0, 0]),
+            Octavian::new([0, 0, 0, 0, 0, 0, 0, -1])
+        ],
+        io::read_csv(messy.as_bytes()).unwrap()
+    );
+
+    let short = io::read_csv("1,0,0,0,0,0,0,0\n1,0,0,0,0,0,0\n".as_bytes()).unwrap_err();
+    assert_eq!((short.line, short.column), (2, 8));
+    assert!(matches!(short.cause, io::CsvErrorCause::ColumnCount(7)));
+    assert_eq!(
+        "line 2, column 8: expected 8 columns, found 7",
+        short.to_string()
+    );
+    let invalid = io::read_csv("1,0,0,x,0,0,0,0\n".as_bytes()).unwrap_err();
+    assert_eq!((invalid.line, invalid.column), (1, 4));
+    assert!(matches!(invalid.cause, io::CsvErrorCause::InvalidInteger(ref s) if s == "x"));
+
+    use std::error::Error;
+    let error = error::AlcoError::from(invalid);
+    assert_eq!(
+        "invalid CSV: line 1, column 4: \"x\" is not an integer",
+        error.to_string()
+    );
+    assert_eq!(
+        "line 1, column 4: \"x\" is not an integer",
+        error.source().unwrap().to_string()
+    );
+    assert_eq!(error.clone(), error);
+    let failing = io::read_csv(FailingReader).unwrap_err();
+    assert_eq!(
+        std::io::ErrorKind::Other,
+        failing
+            .source()
+            .unwrap()
+            .downcast_ref::<std::io::Error>()
+            .unwrap()
+            .kind()
+    );
+    let error = error::AlcoError::from(failing);
+    let io_error = error.source().and_then(Error::source).unwrap();
+    assert_eq!("the reader failed", io_error.to_string());
+}
+
+/// A reader whose every read fails.
+#[cfg(feature = "tables")]
+struct FailingReader;
+
+#[cfg(feature = "tables")]
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("the reader failed"))
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+/// Ensure that octavians round-trip through both serialized bases, read each other's form, and reject unknown bases.
+fn test_serde_representations() {
+    use repr::{Represented, SerdeRepr};
+    let x = Octavian::<i64>::one();
+    assert_eq!(
+        "[-2,-3,-4,-6,-5,-4,-3,-2]",
+        serde_json::to_string(&x).unwrap()
+    );
+    assert_eq!(
+        x,
+        serde_json::from_str::<Octavian<i64>>("[-2,-3,-4,-6,-5,-4,-3,-2]").unwrap()
+    );
+    assert_eq!(
+        r#"{"basis":"e8","coords":[-2,-3,-4,-6,-5,-4,-3,-2]}"#,
+        serde_json::to_string(&x.serialize_as(SerdeRepr::E8)).unwrap()
+    );
+    assert_eq!(
+        r#"{"basis":"octonion","coords":["1","0","0","0","0","0","0","0"]}"#,
+        serde_json::to_string(&x.serialize_as(SerdeRepr::Octonion)).unwrap()
+    );
+
+    let mut rng = TestRng(0x5e7de);
+    for _ in 0..200 {
+        let x = rng.octavian(1000);
+        for repr in [SerdeRepr::E8, SerdeRepr::Octonion] {
+            let json = serde_json::to_string(&x.serialize_as(repr)).unwrap();
+            let read: Represented = serde_json::from_str(&json).unwrap();
+            assert_eq!(read, x.serialize_as(repr));
+        }
+    }
+    let half: Represented = serde_json::from_str(
+        r#"{"basis":"octonion","coords":["1/2","1/2","1/2","1/2","0","0","0","0"]}"#,
+    )
+    .unwrap();
+    assert_eq!(half.repr, SerdeRepr::Octonion);
+    assert_eq!(half.octavian.norm(), 1);
+
+    let unknown = serde_json::from_str::<Represented>(r#"{"basis":"d4","coords":[]}"#).unwrap_err();
+    assert!(unknown.to_string().contains("unknown variant `d4`"));
+    let fractional = serde_json::from_str::<Represented>(
+        r#"{"basis":"octonion","coords":["1/2","0","0","0","0","0","0","0"]}"#,
+    )
+    .unwrap_err();
+    assert!(fractional
+        .to_string()
+        .contains("not those of an octavian integer"));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the binary format round-trips a large batch, widens narrower coefficients on request,
+/// and rejects corrupted or truncated files with an error.
+fn test_binary_round_trip() {
+    let mut rng = TestRng(0xb17a);
+    let elements: Vec<Octavian<i64>> = (0..100_000).map(|_| rng.octavian(i64::MAX / 2)).collect();
+    let mut bytes = Vec::new();
+    io::write_binary(&mut bytes, &elements).unwrap();
+    assert_eq!(bytes.len(), 16 + 64 * elements.len());
+    assert_eq!(
+        elements,
+        io::read_binary::<_, i64>(bytes.as_slice()).unwrap()
+    );
+
+    let units = Octavian::UNITS.to_vec();
+    let mut narrow = Vec::new();
+    io::write_binary(&mut narrow, &units).unwrap();
+    let widened: Vec<Octavian<i64>> = io::read_binary_widening(narrow.as_slice()).unwrap();
+    assert!(widened
+        .iter()
+        .zip(&units)
+        .all(|(w, u)| w.coefficients == u.coefficients.map(i64::from)));
+    assert!(matches!(
+        io::read_binary::<_, i64>(narrow.as_slice()),
+        Err(io::BinaryError::TypeMismatch {
+            found: 1,
+            expected: 4
+        })
+    ));
+    assert!(matches!(
+        io::read_binary_widening::<_, i8>(bytes.as_slice()),
+        Err(io::BinaryError::TypeMismatch {
+            found: 4,
+            expected: 1
+        })
+    ));
+    let error: error::AlcoError = io::read_binary::<_, i64>(narrow.as_slice())
+        .unwrap_err()
+        .into();
+    assert_eq!(
+        "invalid binary input: the file has coefficient type tag 1, expected 4",
+        error.to_string()
+    );
+    assert!(std::error::Error::source(&error).is_some());
+    // I/O failures compare equal when they have the same kind.
+    assert_eq!(
+        Err(io::BinaryError::Io(std::io::Error::other("elsewhere"))),
+        io::read_binary::<_, i64>(FailingReader)
+    );
+
+    let mut wrong_magic = narrow.clone();
+    wrong_magic[0] = b'X';
+    assert!(matches!(
+        io::read_binary::<_, i8>(wrong_magic.as_slice()),
+        Err(io::BinaryError::BadMagic)
+    ));
+    assert!(matches!(
+        io::read_binary::<_, i8>(&narrow[..10]),
+        Err(io::BinaryError::BadMagic)
+    ));
+    let mut version = narrow.clone();
+    version[4] = 9;
+    assert!(matches!(
+        io::read_binary::<_, i8>(version.as_slice()),
+        Err(io::BinaryError::UnsupportedVersion(9))
+    ));
+    let mut tag = narrow.clone();
+    tag[5] = 77;
+    assert!(matches!(
+        io::read_binary::<_, i8>(tag.as_slice()),
+        Err(io::BinaryError::UnknownType(77))
+    ));
+    assert!(matches!(
+        io::read_binary::<_, i8>(&narrow[..narrow.len() - 3]),
+        Err(io::BinaryError::Truncated {
+            expected: 240,
+            found: 239
+        })
+    ));
+    let mut huge_count = narrow.clone();
+    huge_count[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(
+        io::read_binary::<_, i8>(huge_count.as_slice()),
+        Err(io::BinaryError::Truncated {
+            expected: u64::MAX,
+            found: 240
+        })
+    ));
+    let mut trailing = narrow.clone();
+    trailing.push(0);
+    assert!(matches!(
+        io::read_binary::<_, i8>(trailing.as_slice()),
+        Err(io::BinaryError::TrailingData)
+    ));
+
+    // With the serde feature, the data after the magic, version and type tag is exactly bincode's encoding of the list.
+    #[cfg(feature = "serde")]
+    {
+        let encoded = bincode::serialize(&elements).unwrap();
+        assert_eq!(&bytes[8..], encoded.as_slice());
+        assert_eq!(
+            elements,
+            bincode::deserialize::<Vec<Octavian<i64>>>(&encoded).unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that decoding a lattice point perturbed by less than the packing radius recovers it,
+/// and that every decoded point is within the covering radius.
+fn test_closest_octavian() {
+    let mut rng = TestRng(0xc105e);
+    let unit = |rng: &mut TestRng| (rng.next_u64() % 2001) as f64 / 1000.0 - 1.0;
+    let neighbours: Vec<Octavian<i64>> = lattice::norm_shell(1)
+        .into_iter()
+        .chain(lattice::norm_shell(2))
+        .collect();
+    for i in 0..500 {
+        let p = rng.octavian(1000);
+        let point = Octavian::new(p.coefficients.map(|c| c as f64));
+        let x = Octavian::new([(); 8].map(|_| unit(&mut rng) * 3.0)) + point;
+        let closest = lattice::closest_octavian(&x).unwrap();
+        let difference = x - Octavian::new(closest.coefficients.map(|c| c as f64));
+        assert!(difference.norm() <= 0.5 + 1e-9);
+        // No lattice point near the decoded one is closer, checked on the first few samples.
+        if i < 50 {
+            for u in &neighbours {
+                let other = closest + *u;
+                let d = x - Octavian::new(other.coefficients.map(|c| c as f64));
+                assert!(d.norm() >= difference.norm() - 1e-9);
+            }
+        }
+
+        let mut e = Octavian::new([(); 8].map(|_| unit(&mut rng)));
+        while e.norm() >= 0.24 {
+            e = Octavian::new(e.coefficients.map(|c| c / 2.0));
+        }
+        assert_eq!(Some(p), lattice::closest_octavian(&(point + e)));
+    }
+    assert_eq!(
+        None,
+        lattice::closest_octavian(&Octavian::new([f64::NAN; 8]))
+    );
+    assert_eq!(None, lattice::closest_octavian(&Octavian::new([1e30; 8])));
+}
+
+#[test]
+#[cfg(feature = "wasm")]
+/// Ensure that the wasm wrappers give the same results as the functions they wrap.
+fn test_wasm_wrappers() {
+    use wasm::{cvp_decode, WasmOctavian};
+    let (u, v) = (Octavian::UNITS[17], Octavian::UNITS[200]);
+    let wide = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    let as_f64 = |x: Octavian<i8>| x.coefficients.map(f64::from);
+    let a = WasmOctavian::from_array(&as_f64(u)).unwrap();
+    let b = WasmOctavian::from_array(&as_f64(v)).unwrap();
+    assert_eq!(Octavian::from(a.mul(&b)), wide(u * v));
+    assert_eq!(a.coefficients(), as_f64(u).to_vec());
+    assert_eq!(a.norm(), 1.0);
+    assert_eq!(None, WasmOctavian::from_array(&[1.0; 7]));
+    assert_eq!(None, WasmOctavian::from_array(&[0.5; 8]));
+
+    let mut rng = TestRng(0x3a5);
+    for _ in 0..100 {
+        let x = rng.octavian(20);
+        let w = WasmOctavian::from(x);
+        assert_eq!(
+            w.nearest_unit().map(Octavian::from),
+            units::nearest_unit_exact(&x).map(|(u, _)| wide(u))
+        );
+        let point = x.coefficients.map(|c| c as f64 + 0.1);
+        assert_eq!(
+            cvp_decode(&point).map(Octavian::from),
+            lattice::closest_octavian(&Octavian::new(point))
+        );
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[wasm_bindgen_test::wasm_bindgen_test]
+/// Multiplies two units through the wasm wrappers, run headless with `wasm-pack test --headless`.
+fn test_wasm_multiply_units() {
+    let as_f64 = |x: Octavian<i8>| x.coefficients.map(f64::from);
+    let (u, v) = (Octavian::UNITS[17], Octavian::UNITS[200]);
+    let a = wasm::WasmOctavian::from_array(&as_f64(u)).unwrap();
+    let b = wasm::WasmOctavian::from_array(&as_f64(v)).unwrap();
+    assert_eq!(a.mul(&b).coefficients(), as_f64(u * v).to_vec());
+}
+
+#[test]
+#[cfg(feature = "ffi")]
+/// Ensure that the C functions agree with the crate when called through raw pointers,
+/// and that null pointers, overflow and undefined inputs are reported instead of panicking.
+fn test_ffi_round_trip() {
+    use ffi::*;
+    let mut rng = TestRng(0xff1);
+    for _ in 0..200 {
+        let (a, b) = (rng.octavian(1 << 20), rng.octavian(1 << 20));
+        let mut out = [0i64; 8];
+        let status = unsafe {
+            alco_mul(
+                a.coefficients.as_ptr(),
+                b.coefficients.as_ptr(),
+                out.as_mut_ptr(),
+            )
+        };
+        assert_eq!((status, out), (AlcoStatus::Ok, (a * b).coefficients));
+        let mut norm = 0i64;
+        let status = unsafe { alco_norm(a.coefficients.as_ptr(), &mut norm) };
+        assert_eq!((status, norm), (AlcoStatus::Ok, a.norm()));
+        let status = unsafe { alco_conj(a.coefficients.as_ptr(), out.as_mut_ptr()) };
+        assert_eq!((status, out), (AlcoStatus::Ok, a.conjugate().coefficients));
+        let point = a.coefficients.map(|c| c as f64 + 0.25);
+        let status = unsafe { alco_closest_lattice_point(point.as_ptr(), out.as_mut_ptr()) };
+        assert_eq!(
+            (status, Some(out)),
+            (
+                AlcoStatus::Ok,
+                lattice::closest_octavian(&Octavian::new(point)).map(|c| c.coefficients)
+            )
+        );
+    }
+
+    let one = Octavian::<i64>::one().coefficients;
+    let mut out = [7i64; 8];
+    let null = core::ptr::null();
+    unsafe {
+        assert_eq!(
+            alco_mul(null, one.as_ptr(), out.as_mut_ptr()),
+            AlcoStatus::NullPointer
+        );
+        assert_eq!(
+            alco_mul(one.as_ptr(), one.as_ptr(), core::ptr::null_mut()),
+            AlcoStatus::NullPointer
+        );
+        assert_eq!(alco_norm(null, &mut out[0]), AlcoStatus::NullPointer);
+        let huge = [1i64 << 40; 8];
+        assert_eq!(
+            alco_mul(huge.as_ptr(), huge.as_ptr(), out.as_mut_ptr()),
+            AlcoStatus::Overflow
+        );
+        assert_eq!(alco_norm(huge.as_ptr(), &mut out[0]), AlcoStatus::Overflow);
+        // Partial sums beyond 128 bits are reported in release builds too, rather than wrapped.
+        let extreme = [i64::MAX, i64::MAX, 0, 0, 1 << 32, 0, 1 << 32, 0];
+        assert_eq!(
+            alco_norm(extreme.as_ptr(), &mut out[0]),
+            AlcoStatus::Overflow
+        );
+        let extreme = [i64::MAX; 8];
+        assert_eq!(
+            alco_mul(extreme.as_ptr(), extreme.as_ptr(), out.as_mut_ptr()),
+            AlcoStatus::Overflow
+        );
+        let nan = [f64::NAN; 8];
+        assert_eq!(
+            alco_closest_lattice_point(nan.as_ptr(), out.as_mut_ptr()),
+            AlcoStatus::Undefined
+        );
+    }
+    // Failed calls leave the output untouched.
+    assert_eq!(out, [7; 8]);
+
+    let header = include_str!("../include/alco.h");
+    for name in [
+        "alco_mul",
+        "alco_norm",
+        "alco_conj",
+        "alco_closest_lattice_point",
+        "ALCO_STATUS_OVERFLOW",
+    ] {
+        assert!(header.contains(name));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the quaternion group Q8 renders as an 8 × 8 table with the expected products, in both layouts,
+/// and that products outside the named elements get the placeholder.
+fn test_multiplication_table_printer() {
+    use pretty::{format_multiplication_table, TableLabels, TableLayout, TableStyle};
+    let one = Octavian::<i8>::one();
+    let q8 = [
+        one,
+        -one,
+        Octavian::I,
+        -Octavian::I,
+        Octavian::J,
+        -Octavian::J,
+        Octavian::K,
+        -Octavian::K,
+    ];
+    let names: Vec<String> = ["1", "-1", "i", "-i", "j", "-j", "k", "-k"]
+        .map(String::from)
+        .to_vec();
+    let plain = format_multiplication_table(&q8, Some(&names), TableStyle::default());
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(lines.len(), 10);
+    assert_eq!(lines[0], " * |  1 -1  i -i  j -j  k -k");
+    assert_eq!(lines[1], "---+------------------------");
+    assert_eq!(lines[2], " 1 |  1 -1  i -i  j -j  k -k");
+    assert_eq!(lines[4], " i |  i -i -1  1  k -k -j  j");
+    assert_eq!(lines[6], " j |  j -j -k  k -1  1  i -i");
+    assert!(!plain.contains('?'));
+
+    let markdown = format_multiplication_table(
+        &q8,
+        Some(&names),
+        TableStyle {
+            layout: TableLayout::Markdown,
+            ..TableStyle::default()
+        },
+    );
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert_eq!(lines.len(), 10);
+    assert_eq!(
+        lines[0],
+        "|   * |   1 |  -1 |   i |  -i |   j |  -j |   k |  -k |"
+    );
+    assert_eq!(
+        lines[1],
+        "| --- | --- | --- | --- | --- | --- | --- | --- | --- |"
+    );
+    assert!(lines
+        .iter()
+        .all(|l| l.starts_with('|') && l.ends_with('|') && l.matches('|').count() == 10));
+
+    // Without -1, -i, -j and -k the list is not closed, so the missing products render as the placeholder.
+    let half = [one, Octavian::I, Octavian::J, Octavian::K];
+    let style = TableStyle {
+        placeholder: "·".to_string(),
+        ..TableStyle::default()
+    };
+    let names: Vec<String> = ["1", "i", "j", "k"].map(String::from).to_vec();
+    let table = format_multiplication_table(&half, Some(&names), style);
+    assert_eq!(table.lines().nth(3), Some("i | i · k ·"));
+
+    let indices = format_multiplication_table(
+        &half[..2],
+        None,
+        TableStyle {
+            labels: TableLabels::UnitIndex,
+            ..TableStyle::default()
+        },
+    );
+    let cells = |line: &str| -> Vec<String> {
+        line.split(['|', ' '])
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect()
+    };
+    let index = |x: Octavian<i8>| x.unit_index().unwrap().to_string();
+    let lines: Vec<&str> = indices.lines().collect();
+    assert_eq!(
+        cells(lines[0]),
+        ["*".to_string(), index(one), index(Octavian::I)]
+    );
+    assert_eq!(
+        cells(lines[3]),
+        [index(Octavian::I), index(Octavian::I), index(-one)]
+    );
+    let coordinates = format_multiplication_table(&[one], None, TableStyle::default());
+    assert_eq!(
+        coordinates,
+        "                        * | [-2,-3,-4,-6,-5,-4,-3,-2]\n\
+         --------------------------+--------------------------\n\
+         [-2,-3,-4,-6,-5,-4,-3,-2] | [-2,-3,-4,-6,-5,-4,-3,-2]"
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the DOT exports declare each node once, have one line per edge, and keep their header format.
+fn test_dot_export() {
+    use export::dot::DotStyle;
+    let generators = [Octavian::I, Octavian::J].map(|g| g.unit_index().unwrap());
+    let graph = cayley::unit_cayley_graph(&generators);
+    let dot = export::dot_cayley_graph(&graph, None, &DotStyle::default());
+    let nodes: Vec<&str> = dot.lines().filter(|l| l.contains("[label=")).collect();
+    let edges = dot.lines().filter(|l| l.contains(" -- ")).count();
+    assert_eq!(nodes.len(), 240);
+    assert_eq!(edges, graph.edge_count());
+    let ids: HashSet<&str> = nodes
+        .iter()
+        .map(|l| l.split_whitespace().next().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 240);
+    assert!(dot.starts_with("graph \"G\" {\n  u0 [label=\"0\"];\n"));
+    assert!(dot.ends_with(";\n}"));
+
+    let style = DotStyle {
+        name: "roots".to_string(),
+        graph_attributes: vec![("layout".to_string(), "neato".to_string())],
+        node_attributes: vec![("shape".to_string(), "point".to_string())],
+        edge_attributes: vec![("color".to_string(), "gray".to_string())],
+    };
+    let orthogonal = export::dot_root_adjacency(0, &style);
+    assert_eq!(
+        orthogonal.lines().take(5).collect::<Vec<_>>(),
+        [
+            "graph \"roots\" {",
+            "  graph [layout=\"neato\"];",
+            "  node [shape=\"point\"];",
+            "  edge [color=\"gray\"];",
+            "  c0 [label=\"0\"];",
+        ]
+    );
+    // Each root is orthogonal to 126 roots, so each class to 63 classes, and has inner product ±1 with 56 classes.
+    let count = |dot: &str| dot.lines().filter(|l| l.contains(" -- ")).count();
+    assert_eq!(count(&orthogonal), 120 * 63 / 2);
+    assert_eq!(count(&export::dot_root_adjacency(1, &style)), 120 * 56 / 2);
+    assert_eq!(
+        export::dot_root_adjacency(-1, &style),
+        export::dot_root_adjacency(1, &style)
+    );
+    let nodes = orthogonal.lines().filter(|l| l.contains("[label=")).count();
+    assert_eq!(nodes, 120);
+
+    let names: Vec<String> = (0..240).map(|i| format!("unit \"{}\"", i)).collect();
+    let labelled = export::dot_cayley_graph(&graph, Some(&names), &DotStyle::default());
+    assert!(labelled.contains("  u7 [label=\"unit \\\"7\\\"\"];"));
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+/// Ensure that unit quaternions round-trip through a quaternion subalgebra and that the embedding respects products.
+fn test_nalgebra_quaternions() {
+    use nalgebra::{Quaternion, UnitQuaternion, Vector3};
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    let embeddings = [
+        QuaternionEmbedding::new(widen(Octavian::I), widen(Octavian::J)).unwrap(),
+        QuaternionEmbedding::new(widen(Octavian::J), widen(Octavian::L)).unwrap(),
+    ];
+    let close = |a: &Octavian<f64>, b: &Octavian<f64>| (*a - *b).norm() < 1e-24;
+    let one = Octavian::new(Octavian::<i8>::one().coefficients.map(f64::from));
+    let mut rng = TestRng(0x9a7);
+    let mut angle = || (rng.next_u64() % 10_000) as f64 / 1000.0;
+    for embedding in &embeddings {
+        let identity =
+            linalg::octavian_from_unit_quaternion(&UnitQuaternion::identity(), embedding);
+        assert!(close(&identity, &one));
+        for _ in 0..100 {
+            let axis = Vector3::new(angle() - 5.0, angle() - 5.0, angle() - 5.0);
+            let p = UnitQuaternion::from_scaled_axis(axis);
+            let q = UnitQuaternion::from_euler_angles(angle(), angle(), angle());
+            let x = linalg::octavian_from_unit_quaternion(&p, embedding);
+            assert!((x.norm() - 1.0).abs() < 1e-12);
+            let back = linalg::unit_quaternion_from_octavian(&x, embedding).unwrap();
+            assert!((back.quaternion() - p.quaternion()).norm() < 1e-12);
+            let y = linalg::octavian_from_unit_quaternion(&q, embedding);
+            let product = linalg::octavian_from_unit_quaternion(&(p * q), embedding);
+            assert!(close(&(x * y), &product));
+        }
+    }
+    let h = HurwitzQuaternion::new([0.5, -0.5, 0.5, 1.5]);
+    let q: Quaternion<f64> = h.into();
+    assert_eq!((q.w, q.i, q.j, q.k), (0.5, -0.5, 0.5, 1.5));
+    assert_eq!(h, HurwitzQuaternion::from(q));
+    assert_eq!(HurwitzQuaternion::from(q * q), h * h);
+    assert_eq!(
+        None,
+        linalg::unit_quaternion_from_octavian(&Octavian::new([0.0; 8]), &embeddings[0])
+    );
+}
+
+#[test]
+/// Ensure that the Cayley–Dickson doubling formula over complex pairs agrees with the octavian product,
+/// that complex numbers land in the span of 1 and i, and that quadruples round-trip.
+fn test_cayley_dickson_quadruples() {
+    use cayley_dickson::{
+        from_complex, from_complex_quadruple, to_complex_quadruple, SubalgebraFrame,
+    };
+    use num::complex::Complex;
+    type Pair<T> = (T, T);
+    // (a, b)(c, d) = (ac - d̄b, da + bc̄) with (a, b) standing for a + b·j.
+    fn double<T: Copy>(
+        x: Pair<T>,
+        y: Pair<T>,
+        mul: impl Fn(T, T) -> T,
+        sub: impl Fn(T, T) -> T,
+        add: impl Fn(T, T) -> T,
+        conj: impl Fn(T) -> T,
+    ) -> Pair<T> {
+        let ((a, b), (c, d)) = (x, y);
+        (
+            sub(mul(a, c), mul(conj(d), b)),
+            add(mul(d, a), mul(b, conj(c))),
+        )
+    }
+    type Quaternion = Pair<Complex<f64>>;
+    let quaternion_mul = |x: Quaternion, y: Quaternion| {
+        double(
+            x,
+            y,
+            |a, b| a * b,
+            |a, b| a - b,
+            |a, b| a + b,
+            |a: Complex<f64>| a.conj(),
+        )
+    };
+    let quaternion_conj = |(a, b): Quaternion| (a.conj(), -b);
+    let octonion_mul = |x: Pair<Quaternion>, y: Pair<Quaternion>| {
+        double(
+            x,
+            y,
+            quaternion_mul,
+            |(a, b), (c, d)| (a - c, b - d),
+            |(a, b), (c, d)| (a + c, b + d),
+            quaternion_conj,
+        )
+    };
+
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    let frame =
+        SubalgebraFrame::new(widen(Octavian::I), widen(Octavian::J), widen(Octavian::L)).unwrap();
+    assert_eq!(
+        None,
+        SubalgebraFrame::new(widen(Octavian::I), widen(Octavian::J), widen(Octavian::K))
+    );
+    assert_eq!(frame.basis()[3], widen(Octavian::K));
+
+    let mut rng = TestRng(0xcd);
+    let mut complex = || {
+        let mut r = || (rng.next_u64() % 2001) as f64 / 100.0 - 10.0;
+        Complex::new(r(), r())
+    };
+    for _ in 0..200 {
+        let z = [complex(), complex(), complex(), complex()];
+        let w = [complex(), complex(), complex(), complex()];
+        let (x, y) = (
+            from_complex_quadruple(z, &frame),
+            from_complex_quadruple(w, &frame),
+        );
+        let back = to_complex_quadruple(&x, &frame);
+        assert!(back.iter().zip(&z).all(|(a, b)| (a - b).norm() < 1e-12));
+
+        let ((p0, p1), (q0, q1)) =
+            octonion_mul(((z[0], z[1]), (z[2], z[3])), ((w[0], w[1]), (w[2], w[3])));
+        let product = to_complex_quadruple(&(x * y), &frame);
+        assert!(product
+            .iter()
+            .zip(&[p0, p1, q0, q1])
+            .all(|(a, b)| (a - b).norm() < 1e-9));
+    }
+
+    let z = Complex::new(3.0, -2.0);
+    let x = from_complex(z, &frame);
+    let i = Octavian::new(Octavian::I.coefficients.map(f64::from));
+    let one = Octavian::new(Octavian::<i8>::one().coefficients.map(f64::from));
+    assert_eq!(x, one * 3.0 - i * 2.0);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that octavians compare across coefficient types in both directions,
+/// and that a failed `assert_octavian_eq!` names the first differing coefficient.
+fn test_cross_type_equality() {
+    for u in Octavian::UNITS {
+        let wide = Octavian::new(u.coefficients.map(i64::from));
+        assert!(u.eq_coeffs(&wide));
+        assert!(wide.eq_coeffs(&u));
+        assert!(Octavian::new(u.coefficients.map(i128::from))
+            .eq_coeffs(&Octavian::new(u.coefficients.map(i16::from))));
+        crate::assert_octavian_eq!(u, wide);
+        crate::assert_octavian_eq!(wide, u);
+    }
+    let product = Octavian::new(Octavian::I.coefficients.map(i64::from))
+        * Octavian::new(Octavian::J.coefficients.map(i64::from));
+    assert!(product.eq_coeffs(&Octavian::K));
+    assert!(!product.eq_coeffs(&Octavian::I));
+    let big = Octavian::new([300i64, 0, 0, 0, 0, 0, 0, 0]);
+    assert!(!big.eq_coeffs(&Octavian::new([44i8, 0, 0, 0, 0, 0, 0, 0])));
+    let halves = Octavian::new([0.5f64; 8]);
+    assert!(halves.eq_coeffs(&Octavian::new([0.5; 8])));
+
+    let a = Octavian::new([1i64, 2, 3, 4, 5, 6, 7, 8]);
+    let b = Octavian::new([1i8, 2, 3, -4, 5, 6, 7, 0]);
+    assert_eq!(a.first_difference(&b), Some(3));
+    let message = std::panic::catch_unwind(|| crate::assert_octavian_eq!(a, b))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert!(message.starts_with("octavians differ at coefficient 3: 4 != -4\n"));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that each variant of `AlcoError` is returned by some public function and displays a helpful message.
+fn test_alco_error() {
+    use error::AlcoError;
+    let message = |e: AlcoError| (e.to_string(), Box::<dyn std::error::Error>::from(e));
+
+    let short: &[i64] = &[1, 2, 3];
+    let e = Octavian::try_from(short).unwrap_err();
+    assert_eq!(
+        AlcoError::WrongLength {
+            expected: 8,
+            found: 3
+        },
+        e
+    );
+    assert_eq!("expected 8 coordinates, found 3", message(e).0);
+    let x = Octavian::<i64>::try_from(&[2i64, 4, 6, 8, 10, 12, 14, 16][..]).unwrap();
+
+    assert_eq!(Err(AlcoError::DivisionByZero), x.div_exact(0));
+    assert_eq!("division by zero", message(AlcoError::DivisionByZero).0);
+    assert_eq!(Err(AlcoError::NotDivisible), x.div_exact(4));
+    assert_eq!(
+        "the division is not exact",
+        message(AlcoError::NotDivisible).0
+    );
+    assert_eq!(Ok(Octavian::new([1, 2, 3, 4, 5, 6, 7, 8])), x.div_exact(2));
+
+    let two = Octavian::<i8>::one().scale(2);
+    assert_eq!(
+        Some(AlcoError::NotAUnit),
+        units::generated_subloop(&[Octavian::I, two]).err()
+    );
+    assert_eq!(Some(AlcoError::NotAUnit), units::unit_neighbors(&two).err());
+    assert!(message(AlcoError::NotAUnit).0.contains("not a unit"));
+
+    let half = Octavian::new([Ratio::new(1, 2); 8]);
+    assert_eq!(Err(AlcoError::NotIntegral), half.to_integral());
+    assert_eq!(
+        Ok(Octavian::new([2; 8])),
+        half.scale(Ratio::from(4)).to_integral()
+    );
+    assert!(message(AlcoError::NotIntegral)
+        .0
+        .contains("not an octavian integer"));
+
+    assert_eq!(
+        Ok(Octavian::<i64>::one()),
+        "-2, -3, -4, -6, -5, -4, -3, -2".parse()
+    );
+    let e = "1,2,x,4,5,6,7,8".parse::<Octavian<i64>>().unwrap_err();
+    assert_eq!(AlcoError::Parse("x".to_string()), e);
+    assert_eq!("\"x\" is not a valid coefficient", message(e).0);
+    assert_eq!(
+        Err(AlcoError::WrongLength {
+            expected: 8,
+            found: 9
+        }),
+        "1,2,3,4,5,6,7,8,9".parse::<Octavian<i64>>()
+    );
+
+    assert_eq!(
+        Ok(Octavian::<i8>::one()),
+        Octavian::<i64>::one().try_narrow::<i8>()
+    );
+    let e = Octavian::<i64>::one()
+        .scale(100)
+        .try_narrow::<i8>()
+        .unwrap_err();
+    assert_eq!(AlcoError::Overflow, e);
+    assert!(message(e).0.contains("does not fit"));
+
+    let e = units::unit_inverse_index(300).unwrap_err();
+    assert_eq!(
+        AlcoError::IndexOutOfRange {
+            index: 300,
+            len: 240
+        },
+        e
+    );
+    assert_eq!("index 300 is out of range for 240 entries", message(e).0);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that progress callbacks see every unit of work, that breaking stops the long-running searches early
+/// and marks their results incomplete, and that the functions without a callback are unchanged.
+fn test_progress_and_cancellation() {
+    use core::ops::ControlFlow;
+    use progress::Progress;
+
+    let calls = Cell::new(0u64);
+    let counting = |done: u64, total: Option<u64>| {
+        calls.set(calls.get() + 1);
+        assert!(total.is_none_or(|t| done <= t));
+        ControlFlow::Continue(())
+    };
+    let stop_at_once = |_: u64, _: Option<u64>| ControlFlow::Break(());
+    assert!(().report(0, None).is_continue());
+
+    // Norm at most 2: one slice for each doubled first octonion coordinate in -2..=2.
+    let ball = lattice::enumerate_norm_at_most(2);
+    assert_eq!(1 + 240 + 2160, ball.len());
+    assert_eq!(
+        lattice::norm_shell(2),
+        ball.iter()
+            .copied()
+            .filter(|x| x.norm() == 2)
+            .collect::<Vec<_>>()
+    );
+    let counted = lattice::enumerate_norm_at_most_with(2, &counting);
+    assert!(counted.complete);
+    assert_eq!(ball, counted.elements);
+    assert_eq!(5, calls.get());
+    let stopped = lattice::enumerate_norm_at_most_with(2, &stop_at_once);
+    assert!(!stopped.complete);
+    assert!(!stopped.elements.is_empty() && stopped.elements.len() < ball.len());
+    assert!(stopped
+        .elements
+        .windows(2)
+        .all(|w| w[0].coefficients < w[1].coefficients));
+    assert!(lattice::enumerate_norm_at_most(-1).is_empty());
+
+    let generators = [Octavian::I, Octavian::UNITS[1], Octavian::UNITS[3]];
+    let closure = closure::multiplicative_closure(&generators, 1000);
+    assert_eq!(240, closure.elements.len());
+    calls.set(0);
+    assert_eq!(
+        closure,
+        closure::multiplicative_closure_with(&generators, 1000, &counting)
+    );
+    assert!((1..=240).contains(&calls.get()));
+    let stopped = closure::multiplicative_closure_with(&generators, 1000, &stop_at_once);
+    assert!(!stopped.stabilized);
+    assert!(stopped.elements.len() < 240);
+    assert_eq!(
+        closure.elements[..stopped.elements.len()],
+        stopped.elements[..]
+    );
+
+    let root = Octavian::<i64>::one();
+    let orbit = weyl::weyl_orbit(&root);
+    assert_eq!(lattice::norm_shell(1), orbit);
+    calls.set(0);
+    let counted = weyl::weyl_orbit_with(&root, &counting);
+    assert_eq!((orbit, true), (counted.elements, counted.complete));
+    assert!((1..=240).contains(&calls.get()));
+    let stopped = weyl::weyl_orbit_with(&root, &stop_at_once);
+    assert!(!stopped.complete);
+    assert!(stopped.elements.len() < 240);
+    assert_eq!(
+        vec![Octavian::<i64>::zero()],
+        weyl::weyl_orbit(&Octavian::zero())
+    );
+}
+
+#[test]
+/// Ensure that the octonions over F_q have a multiplicative norm, the split octonion count of invertible elements,
+/// 4-dimensional annihilators of zero divisors, and the reduction of the octavians as a homomorphism.
+fn test_octonions_over_finite_fields() {
+    use finite_field::{FiniteField, OctonionAlgebraFq, OctonionFq};
+
+    assert!(FiniteField::new(4, 1).is_none());
+    assert!(FiniteField::new(2, 0).is_none());
+    assert!(FiniteField::new(2, 13).is_none());
+    for (p, k) in [(2, 1), (2, 2), (2, 3), (3, 1), (3, 2), (5, 1), (7, 2)] {
+        let f = FiniteField::new(p, k).unwrap();
+        let q = f.order();
+        assert_eq!(p.pow(k), q);
+        let mut powers = HashSet::new();
+        let mut x = 1;
+        for _ in 1..q {
+            powers.insert(x);
+            x = f.mul(x, f.generator());
+        }
+        assert_eq!(q as usize - 1, powers.len());
+        for a in 0..q {
+            assert_eq!(0, f.add(a, f.neg(a)));
+            assert_eq!(a, f.mul(a, 1));
+            assert_eq!(f.inv(a).map(|b| f.mul(a, b)), (a != 0).then_some(1));
+            for b in 0..q {
+                assert_eq!(f.add(a, b), f.add(b, a));
+                let c = (a + 3 * b) % q;
+                assert_eq!(f.mul(a, f.add(b, c)), f.add(f.mul(a, b), f.mul(a, c)));
+            }
+        }
+    }
+
+    // Norm multiplicativity and the anti-automorphism conj over F_4.
+    let f4 = OctonionAlgebraFq::new(FiniteField::new(2, 2).unwrap());
+    let mut rng = TestRng(0xf4f4);
+    let mut random = || OctonionFq {
+        coefficients: [(); 8].map(|_| (rng.next_u64() % 4) as u32),
+    };
+    for _ in 0..500 {
+        let (x, y) = (random(), random());
+        let xy = f4.mul(&x, &y);
+        assert_eq!(f4.field().mul(f4.norm(&x), f4.norm(&y)), f4.norm(&xy));
+        assert_eq!(
+            f4.mul(&f4.conjugate(&y), &f4.conjugate(&x)),
+            f4.conjugate(&xy)
+        );
+        assert_eq!(x, f4.mul(&f4.one(), &x));
+        if let Some(inverse) = f4.inverse(&x) {
+            assert_eq!(f4.one(), f4.mul(&x, &inverse));
+        }
+    }
+
+    // Split octonions over F_q have q^3 (q^4 - 1) (q - 1) invertible elements.
+    let split_units = |q: u128| q.pow(3) * (q.pow(4) - 1) * (q - 1);
+    for (p, k) in [(2, 1), (3, 1), (2, 2)] {
+        let algebra = OctonionAlgebraFq::new(FiniteField::new(p, k).unwrap());
+        let q = u128::from(algebra.field().order());
+        let counted = algebra.elements().filter(|x| algebra.norm(x) != 0).count();
+        assert_eq!(split_units(q), counted as u128);
+        assert_eq!(split_units(q), algebra.unit_group_order());
+    }
+    for (p, k) in [(5, 1), (2, 3), (3, 2)] {
+        let algebra = OctonionAlgebraFq::new(FiniteField::new(p, k).unwrap());
+        let q = u128::from(algebra.field().order());
+        assert_eq!(split_units(q), algebra.unit_group_order());
+    }
+
+    // Zero divisors, including over F_8, where x * conj(x) = 0 and the annihilator of x is 4-dimensional.
+    for algebra in [
+        f4.clone(),
+        OctonionAlgebraFq::new(FiniteField::new(2, 3).unwrap()),
+    ] {
+        let (x, y) = algebra.find_zero_divisor().unwrap();
+        assert_ne!(algebra.zero(), x);
+        assert_eq!(algebra.zero(), algebra.mul(&x, &y));
+        assert_eq!(None, algebra.inverse(&x));
+        let annihilator = algebra.right_annihilator(&x);
+        assert_eq!(4, annihilator.len());
+        for z in &annihilator {
+            assert_eq!(algebra.zero(), algebra.mul(&x, z));
+        }
+        assert!(algebra.right_annihilator(&algebra.one()).is_empty());
+        assert_eq!(8, algebra.right_annihilator(&algebra.zero()).len());
+    }
+
+    // Reduction modulo p is a ring homomorphism preserving the norm, also into F_4 through its prime subfield.
+    let mut rng = TestRng(0x0c7a);
+    for (p, k) in [(2, 1), (3, 1), (5, 1), (7, 1), (2, 2)] {
+        let algebra = OctonionAlgebraFq::new(FiniteField::new(p, k).unwrap());
+        let field = algebra.field();
+        for _ in 0..100 {
+            let (x, y) = (rng.octavian(20), rng.octavian(20));
+            let (a, b) = (algebra.reduce(&x), algebra.reduce(&y));
+            assert_eq!(algebra.reduce(&(x * y)), algebra.mul(&a, &b));
+            assert_eq!(algebra.reduce(&(x + y)), algebra.add(&a, &b));
+            assert_eq!(algebra.reduce(&x.conjugate()), algebra.conjugate(&a));
+            assert_eq!(field.from_integer(x.norm()), algebra.norm(&a));
+        }
+        assert_eq!(algebra.one(), algebra.reduce(&Octavian::one()));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the invariant harness used by the fuzz targets accepts genuine pairs, including ones whose identities
+/// overflow and are skipped, and reports a broken multiplication.
+fn test_check_invariants() {
+    use invariants::{check_invariants, check_invariants_with};
+
+    let mut rng = TestRng(0xf022);
+    for bound in [1, 30, 1 << 19, 1 << 40] {
+        for _ in 0..200 {
+            let (a, b) = (rng.octavian(bound), rng.octavian(bound));
+            assert_eq!(Ok(()), check_invariants(&a, &b));
+        }
+    }
+    for u in Octavian::UNITS.map(|u| Octavian::new(u.coefficients.map(i64::from))) {
+        assert_eq!(Ok(()), check_invariants(&u, &Octavian::one()));
+    }
+    let extreme = Octavian::new([i64::MIN, i64::MAX, 0, -1, i64::MAX, 1, i64::MIN, i64::MAX]);
+    assert_eq!(Ok(()), check_invariants(&extreme, &extreme));
+    assert_eq!(Ok(()), check_invariants(&extreme, &Octavian::one()));
+
+    // The commutative product (xy + yx) / 2 is not multiplicative for the norm.
+    let symmetrized = |x: &[i128; 8], y: &[i128; 8]| {
+        let xy = invariants::checked_mul(x, y)?;
+        let yx = invariants::checked_mul(y, x)?;
+        Some(core::array::from_fn(|i| (xy[i] + yx[i]) / 2))
+    };
+    let widen = |u: Octavian<i8>| Octavian::new(u.coefficients.map(i64::from));
+    let (i, j) = (widen(Octavian::I), widen(Octavian::J));
+    let error = check_invariants_with(&i, &j, symmetrized).unwrap_err();
+    assert!(error.starts_with("N(ab)"), "{}", error);
+    // The opposite product satisfies the algebraic identities but disagrees with `Mul`.
+    let opposite = |x: &[i128; 8], y: &[i128; 8]| invariants::checked_mul(y, x);
+    let error = check_invariants_with(&i, &j, opposite).unwrap_err();
+    assert!(error.starts_with("a * b"), "{}", error);
+}
+
+#[test]
+/// Ensure that the crate root re-exports the octavian type itself.
+fn test_root_reexport() {
+    let x: crate::Octavian<i64> = octavian::Octavian::one();
+    let y: octavian::Octavian<i64> = x;
+    assert_eq!(
+        std::any::TypeId::of::<crate::Octavian<i8>>(),
+        std::any::TypeId::of::<octavian::Octavian<i8>>()
+    );
+    assert_eq!(x, y);
+}
+
+#[test]
+/// Ensure that the shipped tables verify and that corrupted tables report their defects.
+fn test_verify_tables() {
+    use verify::{verify_tables, verify_tables_with, TableDefect};
+    assert_eq!(Ok(()), verify_tables());
+
+    let gram = Octavian::<i8>::GRAM_MATRIX;
+    let adjoint = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    #[cfg(feature = "tables")]
+    let units = Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS;
+    #[cfg(not(feature = "tables"))]
+    let units = [[0i8; 8]; 0];
+
+    let mut asymmetric = gram;
+    asymmetric[0][2] = 0;
+    let defects = verify_tables_with(&asymmetric, &adjoint, &units).unwrap_err();
+    assert_eq!(
+        TableDefect::GramNotSymmetric { row: 0, column: 2 },
+        defects[0]
+    );
+    assert!(matches!(defects[1], TableDefect::GramDeterminant(d) if d != 1));
+
+    // Conjugation takes its trace from the Gram matrix, so changing <one(), one()> breaks the involution.
+    let mut stretched = gram;
+    stretched[0][0] = 4;
+    let defects = verify_tables_with(&stretched, &adjoint, &units).unwrap_err();
+    assert!(defects
+        .iter()
+        .any(|d| matches!(d, TableDefect::ConjugateNotInvolution { .. })));
+
+    let mut wrong_product = adjoint;
+    wrong_product[1][1][0] += 1;
+    let defects = verify_tables_with(&gram, &wrong_product, &units).unwrap_err();
+    assert!(defects.contains(&TableDefect::NotLeftIdentity { basis_index: 0 }));
+    assert!(defects.contains(&TableDefect::NotRightIdentity { basis_index: 1 }));
+    assert!(!defects.contains(&TableDefect::NotLeftIdentity { basis_index: 1 }));
+    assert!(defects
+        .iter()
+        .any(|d| matches!(d, TableDefect::ConjugateNotAntiHomomorphism { .. })));
+
+    #[cfg(feature = "tables")]
+    {
+        let mut duplicated = units;
+        duplicated[5] = duplicated[4];
+        let defects = verify_tables_with(&gram, &adjoint, &duplicated).unwrap_err();
+        assert!(defects.contains(&TableDefect::DuplicateUnit {
+            first: 4,
+            second: 5
+        }));
+        assert!(defects.iter().all(|d| matches!(
+            d,
+            TableDefect::DuplicateUnit { .. }
+                | TableDefect::ProductNotUnit { .. }
+                | TableDefect::NegationNotUnit { .. }
+        )));
+
+        let mut scaled = units;
+        scaled[0] = scaled[0].map(|c| 2 * c);
+        let defects = verify_tables_with(&gram, &adjoint, &scaled).unwrap_err();
+        assert_eq!(
+            TableDefect::UnitNorm {
+                index: 0,
+                inner_product: 8
+            },
+            defects[0]
+        );
+        assert_eq!("unit 0 has <u, u> = 8", defects[0].to_string());
+    }
+}
+
+#[test]
+/// Ensure that octavians are built from and iterate over their coefficients, rejecting wrong lengths.
+fn test_iterator_adaptors() {
+    use error::AlcoError;
+    let line = "3, -1, 0, 2, 0, 0, 7, -4";
+    let x =
+        Octavian::<i64>::try_from_iter(line.split(',').map(|s| s.trim().parse().unwrap())).unwrap();
+    assert_eq!(Ok(x), line.parse());
+    assert_eq!(x, Octavian::try_from_iter(x.iter().copied()).unwrap());
+    assert_eq!(x, Octavian::try_from_iter(x.into_coefficients()).unwrap());
+    assert_eq!(x.coefficients, x.into_coefficients());
+    assert_eq!(17, x.iter().map(|c| c.abs()).sum::<i64>());
+
+    assert_eq!(
+        Err(AlcoError::WrongLength {
+            expected: 8,
+            found: 7
+        }),
+        Octavian::<i64>::try_from_iter(0..7)
+    );
+    assert_eq!(
+        Err(AlcoError::TooManyCoefficients { expected: 8 }),
+        Octavian::<i64>::try_from_iter(0..9)
+    );
+    let endless = Octavian::<i64>::try_from_iter(0..).unwrap_err();
+    assert_eq!(AlcoError::TooManyCoefficients { expected: 8 }, endless);
+    assert_eq!("expected 8 coordinates, found more", endless.to_string());
+    assert_eq!(
+        Err(AlcoError::WrongLength {
+            expected: 8,
+            found: 0
+        }),
+        Octavian::<i64>::try_from_iter(std::iter::empty())
+    );
+    assert_eq!(
+        Ok(Octavian::new([0, 1, 2, 3, 4, 5, 6, 7])),
+        Octavian::<i64>::try_from_iter(0..8)
+    );
+}
+
+#[test]
+/// Ensure that octavians format in each basis and rational style.
+fn test_formatting() {
+    use format::{FormatBasis, FormatOptions, RationalStyle};
+    let r = |n, d| Ratio::new(n, d);
+    let x = Octavian::<Ratio<i64>>::new([
+        r(1, 2),
+        r(-3, 4),
+        r(0, 1),
+        r(2, 1),
+        r(1, 3),
+        r(-2, 3),
+        r(0, 1),
+        r(5, 8),
+    ]);
+    assert_eq!("1/2, -3/4, 0, 2, 1/3, -2/3, 0, 5/8", x.to_string());
+    assert_eq!(
+        "0.5, -0.75, 0, 2, 0.333333, -0.666667, 0, 0.625",
+        format!("{:#}", x)
+    );
+    assert_eq!(
+        "0.50, -0.75, 0.00, 2.00, 0.33, -0.67, 0.00, 0.63",
+        format!("{:#.2}", x)
+    );
+    assert_eq!("1, -1, 0, 2, 0, -1, 0, 1", format!("{:#.0}", x));
+    assert_eq!("1/2, -3/4, 0, 2, 1/3, -2/3, 0, 5/8", format!("{:.2}", x));
+
+    let y = Octavian::new([0.1 + 0.2, -1.0 / 3.0, 0.0, 2.5, 1e-9, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        "0.300, -0.333, 0.000, 2.500, 0.000, 0.000, 0.000, 1.000",
+        format!("{:.3}", y)
+    );
+    assert_eq!(
+        "0.30000000000000004",
+        y.to_string().split(", ").next().unwrap()
+    );
+
+    let z = Octavian::<i64>::new([3, -1, 0, 2, 0, 0, 7, -4]);
+    for s in [
+        z.to_string(),
+        format!("{:#}", z),
+        format!("{:.3}", z),
+        format!("{:#.3}", z),
+    ] {
+        assert_eq!("3, -1, 0, 2, 0, 0, 7, -4", s);
+        assert_eq!(Ok(z), s.parse());
+    }
+
+    let sum = FormatOptions {
+        suppress_zeros: true,
+        separator: " + ".to_string(),
+        ..FormatOptions::default()
+    };
+    assert_eq!("3*b0 + -b1 + 2*b3 + 7*b6 + -4*b7", z.to_string_with(&sum));
+    assert_eq!("0", Octavian::<i64>::zero().to_string_with(&sum));
+    let octonion = FormatOptions {
+        basis: FormatBasis::Octonion,
+        ..sum.clone()
+    };
+    let e1 = Octavian::new(Octavian::<i8>::E1.coefficients.map(i64::from));
+    assert_eq!("e1", e1.to_string_with(&octonion));
+    assert_eq!("1", Octavian::<i64>::one().to_string_with(&octonion));
+    let half = Octavian::<i64>::new([1, 0, 0, 0, 0, 0, 0, 0]);
+    let coords = half.to_octonion_coords();
+    let expected: Vec<String> = coords
+        .iter()
+        .zip(["", "e1", "e2", "e3", "e4", "e5", "e6", "e7"])
+        .filter(|(c, _)| **c != Ratio::from(0))
+        .map(|(c, label)| match label {
+            "" => c.to_string(),
+            _ => format!("{}*{}", c, label),
+        })
+        .collect();
+    assert_eq!(expected.join(" + "), half.to_string_with(&octonion));
+    let decimal = FormatOptions {
+        style: RationalStyle::Decimal,
+        separator: ";".to_string(),
+        ..octonion.clone()
+    };
+    assert!(!half.to_string_with(&decimal).contains('/'));
+    assert_eq!(
+        half.to_string_with(&octonion)
+            .replace(" + ", ";")
+            .replace("1/2", "0.5"),
+        half.to_string_with(&decimal)
+    );
+}
+
+#[test]
+/// Ensure that the compact encoding round-trips and rejects malformed strings.
+fn test_compact_encoding() {
+    use error::AlcoError;
+    #[cfg(feature = "tables")]
+    for u in Octavian::UNITS {
+        let encoded = u.encode_compact();
+        assert_eq!(8, encoded.len());
+        assert_eq!(Ok(u), Octavian::decode_compact(&encoded));
+    }
+    let mut fallbacks = 0;
+    for x in lattice::norm_shell(2) {
+        let x = x.try_narrow::<i8>().unwrap();
+        let encoded = x.encode_compact();
+        fallbacks += usize::from(encoded.starts_with('x'));
+        assert_eq!(Ok(x), Octavian::decode_compact(&encoded));
+    }
+    // Twice a unit with a coefficient of magnitude above 4 needs the verbose form.
+    assert!(fallbacks > 0);
+
+    let large = Octavian::<i8>::new([12, 0, 0, 0, 0, 0, 0, -9]);
+    assert_eq!("x12.0.0.0.0.0.0.-9", large.encode_compact());
+    assert_eq!(Ok(large), Octavian::decode_compact("x12.0.0.0.0.0.0.-9"));
+    let extreme = Octavian::<i8>::new([i8::MIN, i8::MAX, -8, 7, 8, -9, 0, 1]);
+    assert_eq!(
+        Ok(extreme),
+        Octavian::decode_compact(&extreme.encode_compact())
+    );
+
+    assert_eq!("edcabcde", Octavian::<i8>::one().encode_compact());
+    assert_eq!("00000000", Octavian::<i8>::zero().encode_compact());
+    assert_eq!(
+        "87000001",
+        Octavian::<i8>::new([-8, 7, 0, 0, 0, 0, 0, 1]).encode_compact()
+    );
+    assert_eq!("0ffef000", Octavian::<i8>::E4.encode_compact());
+
+    for (s, error) in [
+        (
+            "",
+            AlcoError::WrongLength {
+                expected: 8,
+                found: 0,
+            },
+        ),
+        (
+            "edcabcd",
+            AlcoError::WrongLength {
+                expected: 8,
+                found: 7,
+            },
+        ),
+        (
+            "edcabcdee",
+            AlcoError::WrongLength {
+                expected: 8,
+                found: 9,
+            },
+        ),
+        ("EDCABCDE", AlcoError::Parse("E".to_string())),
+        ("edcabcdg", AlcoError::Parse("g".to_string())),
+        (
+            "x1.2.3",
+            AlcoError::WrongLength {
+                expected: 8,
+                found: 3,
+            },
+        ),
+        ("x1.2.3.4.5.6.7.200", AlcoError::Parse("200".to_string())),
+        ("x+1.0.0.0.0.0.0.0", AlcoError::Parse("+1".to_string())),
+        ("x01.0.0.0.0.0.0.0", AlcoError::Parse("01".to_string())),
+        ("x1..0.0.0.0.0.0", AlcoError::Parse(String::new())),
+        // Each octavian has one encoding, so the verbose form of a short one is rejected.
+        (
+            "x0.0.0.0.0.0.0.0",
+            AlcoError::Parse("x0.0.0.0.0.0.0.0".to_string()),
+        ),
+        (
+            "x-2.-3.-4.-6.-5.-4.-3.-2",
+            AlcoError::Parse("x-2.-3.-4.-6.-5.-4.-3.-2".to_string()),
+        ),
+    ] {
+        assert_eq!(Err(error), Octavian::decode_compact(s), "{:?}", s);
+    }
+}
+
+#[test]
+/// Ensure that the fancy notation writes terms with signs, units and basis names.
+fn test_fancy_formatting() {
+    use format::{FormatBasis, FormatOptions, Notation};
+    let options = |basis, notation| FormatOptions {
+        basis,
+        notation,
+        ..FormatOptions::default()
+    };
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    let one = Octavian::<i64>::one();
+    let b3 = Octavian::<i64>::new([0, 0, 0, 1, 0, 0, 0, 0]);
+    let minus_il = -widen(Octavian::<i8>::E5);
+    let zero = Octavian::<i64>::zero();
+
+    let fancy = options(FormatBasis::Lattice, Notation::Fancy);
+    assert_eq!(
+        "−2b₀ − 3b₁ − 4b₂ − 6b₃ − 5b₄ − 4b₅ − 3b₆ − 2b₇",
+        one.to_string_with(&fancy)
+    );
+    assert_eq!("b₃", b3.to_string_with(&fancy));
+    // E4 is a unit whose lattice coordinates are all negative or zero.
+    assert_eq!(
+        "−b₁ − b₂ − 2b₃ − b₄",
+        widen(Octavian::<i8>::E4).to_string_with(&fancy)
+    );
+    assert_eq!("0", zero.to_string_with(&fancy));
+
+    let ascii = options(FormatBasis::Lattice, Notation::Sum);
+    assert_eq!(
+        "-2b0 - 3b1 - 4b2 - 6b3 - 5b4 - 4b5 - 3b6 - 2b7",
+        one.to_string_with(&ascii)
+    );
+    assert_eq!("b3", b3.to_string_with(&ascii));
+    assert_eq!("0", zero.to_string_with(&ascii));
+
+    let octonion = options(FormatBasis::Octonion, Notation::Fancy);
+    assert_eq!("1", one.to_string_with(&octonion));
+    assert_eq!("−e₅", minus_il.to_string_with(&octonion));
+    assert_eq!("0", zero.to_string_with(&octonion));
+
+    let named = options(FormatBasis::OctonionNamed, Notation::Fancy);
+    assert_eq!("1", one.to_string_with(&named));
+    assert_eq!("−il", minus_il.to_string_with(&named));
+    assert_eq!("k", widen(Octavian::<i8>::E3).to_string_with(&named));
+    assert_eq!("0", zero.to_string_with(&named));
+    let named_ascii = options(FormatBasis::OctonionNamed, Notation::Sum);
+    assert_eq!("-il", minus_il.to_string_with(&named_ascii));
+    assert_eq!(
+        "-1 + i",
+        (widen(Octavian::<i8>::E1) - one).to_string_with(&named_ascii)
+    );
+
+    // b0 has half-integer octonion coordinates, bracketed so that they read as coefficients.
+    let b0 = Octavian::<i64>::new([1, 0, 0, 0, 0, 0, 0, 0]);
+    let sum = b0.to_string_with(&named_ascii);
+    assert!(sum.contains("(1/2)"), "{}", sum);
+    assert!(!sum.contains("--") && !sum.contains("+ -"), "{}", sum);
+}
+
+#[test]
+/// Ensure that octavians written as sums of basis terms parse back, in every basis and notation.
+fn test_parse_sum() {
+    use error::AlcoError;
+    use format::{FormatBasis, FormatOptions, Notation};
+    let mut rng = TestRng(0x5e7);
+    let elements: Vec<Octavian<i64>> = (0..200).map(|_| rng.octavian(5)).collect();
+    for basis in [
+        FormatBasis::Lattice,
+        FormatBasis::Octonion,
+        FormatBasis::OctonionNamed,
+    ] {
+        for notation in [Notation::Sum, Notation::Fancy] {
+            let options = FormatOptions {
+                basis,
+                notation,
+                ..FormatOptions::default()
+            };
+            for x in &elements {
+                assert_eq!(Ok(*x), x.to_string_with(&options).parse());
+            }
+        }
+        let suppressed = FormatOptions {
+            basis,
+            suppress_zeros: true,
+            separator: " + ".to_string(),
+            ..FormatOptions::default()
+        };
+        for x in &elements {
+            assert_eq!(Ok(*x), x.to_string_with(&suppressed).parse());
+        }
+    }
+
+    let widen = |x: Octavian<i8>| Octavian::new(x.coefficients.map(i64::from));
+    assert_eq!(Ok(Octavian::<i64>::one()), "1".parse());
+    assert_eq!(Ok(Octavian::<i64>::zero()), "0".parse());
+    assert_eq!(Ok(widen(Octavian::<i8>::E1)), "e1".parse());
+    assert_eq!(Ok(widen(Octavian::<i8>::E5)), "il".parse());
+    assert_eq!(Ok(-widen(Octavian::<i8>::E7)), " - kl ".parse());
+    assert_eq!(
+        Ok(widen(Octavian::<i8>::E3)),
+        "e1 + e2 - e1 - e2 + e3".parse()
+    );
+    assert_eq!(
+        "2*b0 + b7".parse::<Octavian<i64>>(),
+        "2b0+b7".parse::<Octavian<i64>>()
+    );
+    let half = Octavian::<f64>::new([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.5]);
+    assert_eq!(Ok(half), "0.5b7".parse());
+    assert_eq!(Ok(half), "(1/2)b7".parse());
+    assert_eq!(Ok(half.scale(2e-3)), "1e-3b7".parse());
+
+    assert_eq!(
+        Err(AlcoError::NotIntegral),
+        "(1/2)e1".parse::<Octavian<i64>>()
+    );
+    assert_eq!(
+        Err(AlcoError::Parse("x".to_string())),
+        "1 + xe1".parse::<Octavian<i64>>()
+    );
+    assert!("1 +".parse::<Octavian<i64>>().is_err());
+    assert!("".parse::<Octavian<i64>>().is_err());
+    assert!("0.5b7".parse::<Octavian<i64>>().is_err());
+    assert_eq!(
+        Err(AlcoError::Overflow),
+        "9223372036854775807b0 + b0".parse::<Octavian<i64>>()
+    );
+    assert_eq!(
+        Err(AlcoError::Overflow),
+        "170141183460469231731687303715884105727e1 + e1".parse::<Octavian<i64>>()
+    );
+}
+
+#[test]
+/// Ensure that the Cayley-Dickson construction reproduces the quaternions and octonions and finds sedenion zero divisors.
+fn test_cayley_dickson_algebras() {
+    use cayley_dickson::{
+        basis_pair_zero_divisors, AlgebraTable, CayleyDickson, CdElement, OctavianTable,
+    };
+    let e = CdElement::<i64, 4>::basis_element;
+    let (one, i, j, k) = (e(0), e(1), e(2), e(3));
+    for (x, y, product) in [
+        (i, j, k),
+        (j, k, i),
+        (k, i, j),
+        (j, i, -k),
+        (k, j, -i),
+        (i, k, -j),
+        (i, i, -one),
+        (j, j, -one),
+        (k, k, -one),
+    ] {
+        assert_eq!(product, x * y);
+    }
+    let mut rng = TestRng(0xcd4);
+    for _ in 0..200 {
+        let p = HurwitzQuaternion::new([(); 4].map(|_| rng.next_i64(50)));
+        let q = HurwitzQuaternion::new([(); 4].map(|_| rng.next_i64(50)));
+        assert_eq!(
+            CdElement::from(p * q),
+            CdElement::from(p) * CdElement::from(q)
+        );
+        assert_eq!(
+            p * q,
+            HurwitzQuaternion::from(CdElement::from(p) * CdElement::from(q))
+        );
+    }
+
+    // The doubled octonions are a composition algebra, and the sedenions are not.
+    for _ in 0..200 {
+        let x = CdElement::<i64, 8>::new([(); 8].map(|_| rng.next_i64(20)));
+        let y = CdElement::<i64, 8>::new([(); 8].map(|_| rng.next_i64(20)));
+        assert_eq!(x.norm() * y.norm(), (x * y).norm());
+        assert_eq!((x * y).conjugate(), y.conjugate() * x.conjugate());
+        assert_eq!(x, x * CdElement::one());
+    }
+    assert!(basis_pair_zero_divisors::<4>().is_empty());
+    assert!(basis_pair_zero_divisors::<8>().is_empty());
+    assert_eq!(
+        256,
+        <CayleyDickson as AlgebraTable<16>>::structure_constants().len()
+    );
+    let pairs = basis_pair_zero_divisors::<16>();
+    assert!(!pairs.is_empty());
+    for (x, y) in &pairs {
+        assert!(!x.is_zero() && !y.is_zero());
+        assert!((*x * *y).is_zero());
+        assert_eq!(4, x.norm() * y.norm());
+    }
+    let e16 = CdElement::<i64, 16>::basis_element;
+    assert!(pairs.contains(&(e16(3) + e16(10), e16(6) - e16(15))));
+
+    assert_eq!(
+        Octavian::<i8>::one().coefficients,
+        OctavianTable::identity()
+    );
+    // The doubled basis is the standard octonion basis in the Fano orientation, so the doubling,
+    // which never reads the adjoint tables, multiplies octavians in standard coordinates.
+    let standard = |x: &Octavian<i64>| CdElement::<Ratio<i64>, 8>::new(x.to_octonion_coords());
+    for _ in 0..200 {
+        let (x, y) = (rng.octavian(1000), rng.octavian(1000));
+        assert_eq!(standard(&(x * y)), standard(&x) * standard(&y));
+        assert_eq!(
+            (x * y).coefficients,
+            OctavianTable::multiply(&x.coefficients, &y.coefficients)
+        );
+    }
+    #[cfg(feature = "tables")]
+    for u in Octavian::UNITS {
+        for v in Octavian::UNITS {
+            // Intermediate sums overflow i8, so the table product is taken in i64.
+            let (x, y) = (u.coefficients.map(i64::from), v.coefficients.map(i64::from));
+            assert_eq!(
+                (u * v).coefficients.map(i64::from),
+                OctavianTable::multiply(&x, &y)
+            );
+        }
+    }
+}
+
+#[test]
+/// Ensure that the inner product, bilinear form and trace form agree.
+fn test_bilinear_forms() {
+    let basis = Octavian::<i64>::basis_vectors();
+    for x in &basis {
+        for y in &basis {
+            let ip = x.inner_product(y);
+            assert_eq!(ip, x.bilinear(y));
+            assert_eq!(ip, x.trace_form(y));
+            assert_eq!(ip, y.trace_form(x));
+            assert_eq!(y.bilinear(x), x.bilinear(y));
+        }
+        assert_eq!(1, x.norm());
+        assert_eq!(2 * x.norm(), x.trace_form(x));
+    }
+    let one = Octavian::<i64>::one();
+    assert_eq!(2, one.trace());
+    assert_eq!(2, one.trace_form(&one));
+
+    let mut rng = TestRng(0xb111);
+    for _ in 0..500 {
+        let (x, y) = (rng.octavian(1000), rng.octavian(1000));
+        let ip = x.inner_product(&y);
+        assert_eq!(ip, x.bilinear(&y));
+        assert_eq!(ip, x.trace_form(&y));
+        assert_eq!(x.trace_form(&y), y.trace_form(&x));
+        assert_eq!(x.bilinear(&y), y.bilinear(&x));
+        assert_eq!(x.trace_form(&x), 2 * x.norm());
+        assert_eq!(x.bilinear(&x), 2 * x.norm());
+    }
+
+    let half = Ratio::new(1, 2);
+    let x = Octavian::new([
+        half,
+        Ratio::from(3),
+        -half,
+        Ratio::from(0),
+        half,
+        half,
+        Ratio::from(-2),
+        half,
+    ]);
+    let y = Octavian::new([
+        Ratio::from(1),
+        -half,
+        half,
+        Ratio::from(2),
+        Ratio::from(0),
+        half,
+        half,
+        -half,
+    ]);
+    assert_eq!(x.inner_product(&y), x.bilinear(&y));
+    assert_eq!(x.inner_product(&y), x.trace_form(&y));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the determinant and spinor norm match the reflection words and are multiplicative.
+fn test_det_and_spinor_norm() {
+    // Multiplies squarefree representatives of classes in Q*/Q*², which are 1 or 2 here.
+    let class_product = |a: i8, b: i8| if a == b { 1 } else { a * b };
+    let simple = WeylElement::simple_reflections();
+    for s in &simple {
+        assert_eq!(-1, s.det());
+        // The simple roots have <r, r> = 2.
+        assert_eq!(2, s.spinor_norm());
+        assert_eq!(1, s.simple_reflection_word().len());
+    }
+    assert_eq!(1, WeylElement::identity().det());
+    assert_eq!(1, WeylElement::identity().spinor_norm());
+    assert!(WeylElement::identity().simple_reflection_word().is_empty());
+
+    let mut rng = TestRng(0x5914);
+    let mut elements = Vec::new();
+    for _ in 0..20 {
+        let mut w = WeylElement::identity();
+        for _ in 0..rng.next_u64() % 30 {
+            w = w.then(&simple[(rng.next_u64() % 8) as usize]);
+        }
+        elements.push(w);
+    }
+    for w in &elements {
+        let word = w.simple_reflection_word();
+        let recomposed = word
+            .iter()
+            .fold(WeylElement::identity(), |v, &i| v.then(&simple[i as usize]));
+        assert_eq!(*w, recomposed);
+        assert_eq!(if word.len() % 2 == 0 { 1 } else { -1 }, w.det());
+        for v in &elements {
+            let product = w.then(v);
+            assert_eq!(w.det() * v.det(), product.det());
+            assert_eq!(
+                class_product(w.spinor_norm(), v.spinor_norm()),
+                product.spinor_norm()
+            );
+        }
+    }
+
+    let mut minus = [[0i64; 8]; 8];
+    for (i, row) in minus.iter_mut().enumerate() {
+        row[i] = -1;
+    }
+    let minus_identity = WeylElement::new(minus).unwrap();
+    assert_eq!(120, minus_identity.simple_reflection_word().len());
+    assert_eq!(8, weyl::reflection_decomposition(&minus_identity).len());
+    assert_eq!(1, minus_identity.det());
+    assert_eq!(1, minus_identity.spinor_norm());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that Weyl group elements decompose into reflections that recompose them.
+fn test_reflection_decomposition() {
+    use weyl::reflection_decomposition;
+    let recompose = |roots: &[Octavian<i64>]| {
+        roots.iter().fold(WeylElement::identity(), |w, r| {
+            let r = r.try_narrow::<i8>().unwrap();
+            w.then(&WeylElement::reflection(&r).unwrap())
+        })
+    };
+    assert!(reflection_decomposition(&WeylElement::identity()).is_empty());
+    for u in Octavian::UNITS.iter().step_by(7) {
+        let roots = reflection_decomposition(&WeylElement::reflection(u).unwrap());
+        assert_eq!(1, roots.len());
+        let u = Octavian::new(u.coefficients.map(i64::from));
+        assert!(roots[0] == u || roots[0] == -u);
+    }
+
+    let simple = WeylElement::simple_reflections();
+    let mut rng = TestRng(0x4ef1);
+    for _ in 0..30 {
+        let mut w = WeylElement::identity();
+        for _ in 0..rng.next_u64() % 40 {
+            w = w.then(&simple[(rng.next_u64() % 8) as usize]);
+        }
+        let roots = reflection_decomposition(&w);
+        assert!(roots.len() <= 8);
+        assert_eq!(w.moved_rank(), roots.len());
+        assert_eq!(w, recompose(&roots));
+        assert!(roots.iter().all(|r| r.norm() == 1));
+        assert_eq!(w.simple_reflection_word().len() % 2, roots.len() % 2);
+    }
+
+    let mut minus = [[0i64; 8]; 8];
+    for (i, row) in minus.iter_mut().enumerate() {
+        row[i] = -1;
+    }
+    let minus_identity = WeylElement::new(minus).unwrap();
+    let roots = reflection_decomposition(&minus_identity);
+    assert_eq!(minus_identity, recompose(&roots));
+    // The reflections of -1 are in 8 mutually orthogonal roots.
+    for (i, r) in roots.iter().enumerate() {
+        for s in &roots[i + 1..] {
+            assert_eq!(0, r.inner_product(s));
+        }
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the root graphs are regular with the neighbours given by the inner products.
+fn test_root_graph() {
+    use root_graph::{root_class_graph, root_graph};
+    use units::unit_neighbors;
+
+    let partition = unit_neighbors(&Octavian::UNITS[0]).unwrap();
+    for (value, class) in [
+        (1, &partition.acute),
+        (0, &partition.orthogonal),
+        (-1, &partition.obtuse),
+    ] {
+        let graph = root_graph(value);
+        assert_eq!(240, graph.vertex_count());
+        assert_eq!(Some(class.len()), graph.regularity());
+        let neighbors: Vec<u16> = graph.neighbors(0).iter().map(|&v| v as u16).collect();
+        assert_eq!(class, &neighbors);
+    }
+    assert_eq!(vec![56; 240], root_graph(-1).degree_sequence());
+    assert_eq!(Some(0), root_graph(3).regularity());
+
+    let classes = root_class_graph(-1);
+    assert_eq!(classes, root_class_graph(1));
+    assert_eq!(
+        Some((120, 56, 28, 24)),
+        classes.strongly_regular_parameters()
+    );
+    let orthogonal = root_class_graph(0);
+    assert_eq!(
+        Some((120, 63, 30, 36)),
+        orthogonal.strongly_regular_parameters()
+    );
+    for a in 0..120 {
+        for b in 0..120 {
+            assert_eq!(
+                a != b && !classes.is_adjacent(a, b),
+                orthogonal.is_adjacent(a, b)
+            );
+        }
+    }
+    // The 240-root graph is not strongly regular: r and -r have no common neighbours, unlike other non-adjacent pairs.
+    assert_eq!(None, root_graph(1).strongly_regular_parameters());
+
+    let cliques = classes.cliques(3);
+    assert_eq!(120 + 120 * 56 / 2 + 120 * 56 * 28 / 6, cliques.len());
+    assert!(cliques
+        .windows(2)
+        .all(|w| (w[0].len(), &w[0]) < (w[1].len(), &w[1])));
+    for clique in &cliques {
+        for (i, &a) in clique.iter().enumerate() {
+            assert!(clique[i + 1..]
+                .iter()
+                .all(|&b| a < b && classes.is_adjacent(a, b)));
+        }
+    }
+
+    // A strongly regular graph has eigenvalue k once and the two roots of x² - (λ - μ)x - (k - μ).
+    let spectrum = classes.spectrum();
+    assert_eq!(120, spectrum.len());
+    assert!((spectrum[0] - 56.0).abs() < 1e-8);
+    assert!(spectrum[1..36].iter().all(|x| (x - 8.0).abs() < 1e-8));
+    assert!(spectrum[36..].iter().all(|x| (x + 4.0).abs() < 1e-8));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the Gosset polytope has the known numbers of vertices, edges and facets.
+fn test_gosset_polytope_statistics() {
+    let stats = polytope::gosset_polytope_statistics();
+    assert_eq!(240, stats.vertices);
+    assert_eq!(6720, stats.edges);
+    assert_eq!(17280, stats.simplex_facets);
+    assert_eq!(2160, stats.orthoplex_facets);
+    // Each 6-face lies in two facets, and 4_21 has 207360 of them.
+    assert_eq!(
+        2 * 207360,
+        8 * stats.simplex_facets + 128 * stats.orthoplex_facets
+    );
+}
+
+#[test]
+/// Ensure that the Smith normal form factors a matrix with unimodular transforms.
+fn test_smith_normal_form() {
+    // The factors can have large entries, so the product is formed in i128.
+    let multiply = |a: &[[i64; 8]; 8], b: &[[i64; 8]; 8]| -> [[i128; 8]; 8] {
+        core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                (0..8)
+                    .map(|k| i128::from(a[i][k]) * i128::from(b[k][j]))
+                    .sum()
+            })
+        })
+    };
+    let identity = Octavian::<i64>::basis_vectors().map(|x| x.coefficients);
+    assert_eq!(
+        (identity, identity, identity),
+        lattice::smith_normal_form(&identity)
+    );
+
+    let mut rng = TestRng(0x5a1f);
+    for rank in [8, 8, 8, 5] {
+        let mut m = [[0i64; 8]; 8];
+        for row in m.iter_mut().take(rank) {
+            *row = core::array::from_fn(|_| rng.next_i64(2));
+        }
+        let (u, d, v) = lattice::smith_normal_form(&m);
+        let ud = multiply(&u, &d).map(|row| row.map(|x| i64::try_from(x).unwrap()));
+        assert_eq!(m.map(|row| row.map(i128::from)), multiply(&ud, &v));
+        for (i, row) in d.iter().enumerate() {
+            assert!(row.iter().enumerate().all(|(j, &x)| x == 0 || i == j));
+            assert!(row[i] >= 0);
+        }
+        let divisors: Vec<i64> = (0..8).map(|i| d[i][i]).collect();
+        for pair in divisors.windows(2) {
+            assert!(if pair[0] == 0 {
+                pair[1] == 0
+            } else {
+                pair[1] % pair[0] == 0
+            });
+        }
+        // With M = U · D · V this forces det U and det V to be ±1 when M is invertible.
+        assert_eq!(
+            lattice::determinant(&m).abs(),
+            divisors.iter().map(|&x| i128::from(x)).product()
+        );
+    }
+
+    let basis = Octavian::<i64>::basis_vectors();
+    assert_eq!(Some(1), lattice::sublattice_index(&basis));
+    let tripled = basis.map(|x| x.scale(3));
+    assert_eq!(Some(3u64.pow(8)), lattice::sublattice_index(&tripled));
+    assert_eq!(Some([3; 8]), lattice::elementary_divisors(&tripled));
+    assert_eq!(None, lattice::sublattice_index(&basis[..7]));
+    assert_eq!(
+        None,
+        lattice::elementary_divisors(&[basis[0], basis[0].scale(2)])
+    );
+    // Doubling one basis vector and adding redundant generators leaves E8 / L = Z / 2.
+    let mut doubled = basis.to_vec();
+    doubled[3] = doubled[3].scale(2);
+    doubled.push(basis[0] + basis[1].scale(4));
+    assert_eq!(
+        Some([1, 1, 1, 1, 1, 1, 1, 2]),
+        lattice::elementary_divisors(&doubled)
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that power sums over the units and Weyl orbit sums take their known values.
+fn test_orbit_sums() {
+    use units::power_sum_over_units;
+    use weyl::orbit_sum_monomial;
+
+    let mut rng = TestRng(0x0b17);
+    for _ in 0..20 {
+        let v = rng.octavian(4);
+        let q = i128::from(v.inner_product(&v));
+        for k in [1, 3, 5, 7, 9] {
+            assert_eq!(0, power_sum_over_units(&v, k).unwrap());
+        }
+        assert_eq!(240, power_sum_over_units(&v, 0).unwrap());
+        assert_eq!(
+            120 * i128::from(v.norm()),
+            power_sum_over_units(&v, 2).unwrap()
+        );
+        assert_eq!(60 * q, power_sum_over_units(&v, 2).unwrap());
+        assert_eq!(36 * q * q, power_sum_over_units(&v, 4).unwrap());
+        assert_eq!(30 * q * q * q, power_sum_over_units(&v, 6).unwrap());
+    }
+    // The roots are not an 8-design: the vectors of norm 4 make up two Weyl orbits, 2r for the roots r and
+    // 17280 others, whose eighth power sums differ.
+    let eighth: HashSet<i128> = lattice::norm_shell(4)
+        .iter()
+        .map(|v| power_sum_over_units(v, 8).unwrap())
+        .collect();
+    assert_eq!(2, eighth.len());
+    // Overflow is reported rather than wrapped, whether in the sum or in a single power.
+    // Here each ⟨u, v⟩² is at most 2^124, but their sum is 60 ⟨v, v⟩ = 60 · 2^123.
+    let huge = Octavian::<i64>::new([1 << 61, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(None, power_sum_over_units(&huge, 2));
+    assert_eq!(None, power_sum_over_units(&Octavian::one(), 200));
+    assert_eq!(Some(240), power_sum_over_units(&huge, 0));
+
+    let roots = orbit_sum_monomial([1, 0, 0, 0, 0, 0, 0, 0], None);
+    assert!(roots.complete);
+    let mut units: Vec<Octavian<i64>> = Octavian::<i64>::unit_vectors().to_vec();
+    units.sort_unstable_by_key(|u| u.coefficients);
+    assert_eq!(units, roots.elements);
+    // The orbit sum is invariant: each simple reflection permutes its exponent vectors.
+    for reflection in WeylElement::simple_reflections() {
+        let mut image: Vec<Octavian<i64>> =
+            roots.elements.iter().map(|e| reflection.apply(e)).collect();
+        image.sort_unstable_by_key(|e| e.coefficients);
+        assert_eq!(roots.elements, image);
+    }
+    let doubled: Vec<Octavian<i64>> = units.iter().map(|u| u.scale(2)).collect();
+    assert_eq!(
+        doubled,
+        orbit_sum_monomial([2, 0, 0, 0, 0, 0, 0, 0], None).elements
+    );
+    assert_eq!(
+        vec![Octavian::zero()],
+        orbit_sum_monomial([0; 8], None).elements
+    );
+
+    let capped = orbit_sum_monomial([1, 0, 0, 0, 0, 0, 0, 0], Some(100));
+    assert!(!capped.complete);
+    assert_eq!(100, capped.elements.len());
+    assert!(capped.elements.iter().all(|e| roots.elements.contains(e)));
+    assert!(orbit_sum_monomial([1, 0, 0, 0, 0, 0, 0, 0], Some(241)).complete);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that Weyl isometries between sublattices are found when they exist and rejected otherwise.
+fn test_find_isometry() {
+    use core::ops::ControlFlow;
+    use error::AlcoError;
+    use weyl::{find_isometry, find_isometry_with};
+    let span = |basis: &[Octavian<i64>]| {
+        let rows: Vec<[i64; 8]> = basis.iter().map(|x| x.coefficients).collect();
+        lattice::hermite_normal_form(&rows)
+    };
+
+    let e8 = Octavian::<i64>::basis_vectors();
+    let mut sublattice = e8;
+    sublattice[3] = sublattice[3].scale(2);
+    let simple = WeylElement::simple_reflections();
+    let w = [5, 3, 0, 7, 3, 1, 4, 2, 6]
+        .iter()
+        .fold(WeylElement::identity(), |w, &i| w.then(&simple[i]));
+    // Another basis of the image of the sublattice under w, related to the first by a unimodular change.
+    let mut image = sublattice.map(|x| w.apply(&x));
+    image[0] += image[1];
+    image[5] -= image[2].scale(2);
+    image[7] = image[7] + image[3] + image[0];
+    image.swap(2, 6);
+
+    let found = find_isometry(&sublattice, &image).unwrap().unwrap();
+    let mapped: Vec<Octavian<i64>> = sublattice.iter().map(|x| found.apply(x)).collect();
+    assert_eq!(span(&image), span(&mapped));
+    assert!(find_isometry(&e8, &e8).unwrap().is_some());
+    assert!(find_isometry(&image, &sublattice).unwrap().is_some());
+
+    // Different determinants give no isometry, while a missing vector or a dependent basis is an error.
+    assert_eq!(Ok(None), find_isometry(&e8, &image));
+    assert_eq!(Err(AlcoError::NotABasis), find_isometry(&e8[..7], &e8[..7]));
+    // The index 2 sublattices fall into two Weyl orbits, which hold different numbers of roots.
+    let doubled = |i: usize| {
+        let mut basis = e8;
+        basis[i] = basis[i].scale(2);
+        basis
+    };
+    assert!(find_isometry(&doubled(0), &doubled(1)).unwrap().is_some());
+    assert_eq!(Ok(None), find_isometry(&doubled(0), &doubled(2)));
+    // A bounded search stops without deciding, unless the invariants decide before it starts.
+    let stop_at_once = |_: u64, _: Option<u64>| ControlFlow::Break(());
+    let bounded = find_isometry_with(&sublattice, &image, &stop_at_once).unwrap();
+    assert!(!bounded.complete);
+    assert!(bounded.elements.is_empty());
+    let rejected = find_isometry_with(&doubled(0), &doubled(2), &stop_at_once).unwrap();
+    assert!(rejected.complete);
+    assert!(rejected.elements.is_empty());
+    let generous = |tried: u64, _: Option<u64>| {
+        if tried < 1000 {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    };
+    let within = find_isometry_with(&sublattice, &image, &generous).unwrap();
+    assert!(within.complete);
+    assert_eq!(vec![found], within.elements);
+    let mut dependent = e8;
+    dependent[7] = dependent[0];
+    assert_eq!(
+        Err(AlcoError::NotABasis),
+        find_isometry(&dependent, &dependent)
+    );
+}
+
+#[test]
+/// Ensure that the left adjoint eigenvalues and singular values follow from the trace and norm.
+fn test_left_adjoint_eigenvalues() {
+    use num::complex::Complex;
+    let to_f64 = |x: Octavian<i64>| Octavian::new(x.coefficients.map(|c| c as f64));
+    let close = |a: Complex<f64>, b: Complex<f64>| (a - b).norm() < 1e-10;
+
+    for lambda in Octavian::<f64>::one().left_adjoint_eigenvalues() {
+        assert!(close(Complex::new(1.0, 0.0), lambda));
+    }
+    assert_eq!(
+        [1.0; 8],
+        Octavian::<f64>::one().left_adjoint_singular_values()
+    );
+    // i has norm 1, so 3i has eigenvalues ±3i.
+    let i = to_f64(Octavian::new(Octavian::<i8>::I.coefficients.map(i64::from)).scale(3));
+    let eigenvalues = i.left_adjoint_eigenvalues();
+    assert!(eigenvalues[..4]
+        .iter()
+        .all(|&l| close(Complex::new(0.0, 3.0), l)));
+    assert!(eigenvalues[4..]
+        .iter()
+        .all(|&l| close(Complex::new(0.0, -3.0), l)));
+
+    let mut rng = TestRng(0xe19e);
+    for _ in 0..20 {
+        let x = to_f64(rng.octavian(5));
+        let (norm, s) = (x.norm(), x.trace() / 2.0);
+        let eigenvalues = x.left_adjoint_eigenvalues();
+        let product: f64 = eigenvalues.iter().map(|l| l.norm()).product();
+        assert!((product - norm.powi(4)).abs() < 1e-9 * norm.powi(4).max(1.0));
+        for lambda in eigenvalues {
+            assert!((lambda.re - s).abs() < 1e-9);
+            // Each eigenvalue is a root of t² - trace(x) t + norm(x).
+            assert!((lambda * lambda - lambda * x.trace() + norm).norm() < 1e-8 * norm.max(1.0));
+        }
+        for sigma in x.left_adjoint_singular_values() {
+            assert!((sigma * sigma - norm).abs() < 1e-9 * norm.max(1.0));
+        }
+    }
+}
+
+#[test]
+/// Ensure that the minimal polynomial of the left adjoint annihilates it.
+fn test_left_adjoint_min_poly() {
+    let ratios = |coefficients: &[i64]| -> Vec<Ratio<i64>> {
+        coefficients.iter().map(|&c| Ratio::from(c)).collect()
+    };
+    let mut rng = TestRng(0x3170);
+    let mut samples: Vec<Octavian<i64>> = (0..20).map(|_| rng.octavian(6)).collect();
+    samples.extend(Octavian::<i64>::basis_vectors());
+    samples.push(Octavian::<i64>::one().scale(3) + Octavian::<i64>::basis_vectors()[5]);
+    for x in samples {
+        let min_poly = x.left_adjoint_min_poly();
+        assert_eq!(ratios(&x.char_poly()), min_poly);
+        // The polynomial annihilates the matrix exactly.
+        let l = x.left_adjoint_matrix().map(|row| row.map(i128::from));
+        let [n, t, _] = x.char_poly().map(i128::from);
+        for i in 0..8 {
+            for j in 0..8 {
+                let square: i128 = (0..8).map(|k| l[i][k] * l[k][j]).sum();
+                assert_eq!(0, square + t * l[i][j] + if i == j { n } else { 0 });
+            }
+        }
+    }
+    for s in [-4, 0, 1, 7] {
+        let scalar = Octavian::<i64>::one().scale(s);
+        assert_eq!(ratios(&[-s, 1]), scalar.left_adjoint_min_poly());
+    }
+    assert_eq!([1, 2, 1], Octavian::<i64>::one().scale(-1).char_poly());
+}
+
+#[test]
+/// Ensure that the Fano plane triples reproduce the octonion multiplication.
+fn test_fano_structure_constants() {
+    use fano::{mul_standard_basis, mul_via_fano, FANO_TRIPLES};
+    let basis = Octavian::<i64>::OCTONION_BASIS.map(|row| Octavian::new(row.map(i64::from)));
+    for i in 0..8 {
+        for j in 0..8 {
+            let (sign, k) = mul_standard_basis(i, j).unwrap();
+            assert_eq!(basis[k].scale(i64::from(sign)), basis[i] * basis[j]);
+            assert_eq!(basis[i] * basis[j], mul_via_fano(&basis[i], &basis[j]));
+        }
+    }
+
+    let mut lines = 0;
+    for [a, b, c] in FANO_TRIPLES {
+        for (x, y, z) in [(a, b, c), (b, c, a), (c, a, b)] {
+            assert_eq!(Some((1, z)), mul_standard_basis(x, y));
+            assert_eq!(Some((-1, z)), mul_standard_basis(y, x));
+        }
+        lines |= 1 << a | 1 << b | 1 << c;
+    }
+    assert_eq!(0b1111_1110, lines);
+    assert_eq!(None, mul_standard_basis(8, 0));
+    assert_eq!(None, mul_standard_basis(3, 8));
+    // Each of the 21 pairs of distinct imaginary units lies on exactly one line.
+    for i in 1..8 {
+        for j in i + 1..8 {
+            let on = |line: &&[usize; 3]| line.contains(&i) && line.contains(&j);
+            assert_eq!(1, FANO_TRIPLES.iter().filter(on).count());
+        }
+    }
+
+    let mut rng = TestRng(0xfa70);
+    for _ in 0..50 {
+        let (x, y) = (rng.octavian(5), rng.octavian(5));
+        assert_eq!(x * y, mul_via_fano(&x, &y));
+    }
+    let halves = [0.5, -1.5, 0.0, 2.0, 1.0, -0.5, 3.0, 0.25];
+    let product = fano::mul_standard(&halves, &[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(halves, product);
+}
+
+#[cfg(feature = "dual")]
+#[test]
+/// Ensure that dual numbers differentiate the norm and the product.
+fn test_dual_norm_derivative() {
+    use dual::{constant_octavian, derivative, norm_derivative, Dual};
+    let to_f64 = |x: Octavian<i64>| Octavian::new(x.coefficients.map(|c| c as f64));
+    let mut rng = TestRng(0xd0a1);
+    for _ in 0..20 {
+        let (x, y, z) = (
+            to_f64(rng.octavian(3)),
+            to_f64(rng.octavian(3)),
+            to_f64(rng.octavian(3)),
+        );
+        let t = rng.next_i64(4) as f64 / 4.0;
+        let analytic = x.inner_product(&y) + 2.0 * t * y.norm();
+        assert!((norm_derivative(&x, &y, t) - analytic).abs() < 1e-9);
+
+        // The product rule: d/dt (x + t y)(x + t y) = y x + x y + 2t y y.
+        let (xd, yd) = (constant_octavian(&x), constant_octavian(&y));
+        let square = |t: Dual<f64>| {
+            let w = xd + yd.scale(t);
+            w * w
+        };
+        let expected = y * x + x * y + (y * y).scale(2.0 * t);
+        for i in 0..8 {
+            let derivative_i = derivative(|t| square(t).coefficients[i], t);
+            assert!((derivative_i - expected.coefficients[i]).abs() < 1e-9);
+        }
+        assert_eq!(
+            (x + y.scale(t)) * (x + y.scale(t)),
+            Octavian::new(square(Dual::constant(t)).coefficients.map(|c| c.re))
+        );
+
+        // d/dt ⟨x + t y, z⟩ = ⟨y, z⟩.
+        let zd = constant_octavian(&z);
+        let slope = derivative(|t| (xd + yd.scale(t)).inner_product(&zd), t);
+        assert!((slope - y.inner_product(&z)).abs() < 1e-9);
+    }
+    assert_eq!(
+        Dual::new(6.0, 1.0),
+        Dual::new(3.0, 0.5) * Dual::constant(2.0)
+    );
+    assert_eq!(
+        Dual::new(0.5, 0.25),
+        Dual::constant(1.0) / Dual::new(2.0, -1.0)
+    );
+}
+
+#[cfg(feature = "symbolic")]
+#[test]
+/// Ensure that the symbolic identities hold for the tables and fail for perturbed ones.
+fn test_symbolic_identities() {
+    use symbolic::{
+        mul_with_table, symbolic_x, verify_identities, verify_identities_with, Identity, MultiPoly,
+    };
+    assert_eq!(Ok(()), verify_identities());
+    let table = Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES;
+    assert_eq!(
+        Ok(()),
+        verify_identities_with(|x, y| mul_with_table(&table, x, y))
+    );
+
+    let mut perturbed = table;
+    perturbed[3][2][5] += 1;
+    let failed = verify_identities_with(|x, y| mul_with_table(&perturbed, x, y)).unwrap_err();
+    assert!(failed.contains(&Identity::NormMultiplicative));
+
+    let x = symbolic_x();
+    assert_eq!(Some(2), x.norm().degree());
+    assert_eq!(x.norm().as_constant(), None);
+    let (x1, y2) = (MultiPoly::variable(0), MultiPoly::variable(9));
+    let p = MultiPoly::constant(3) * x1 * y2 - x1 * x1 + MultiPoly::constant(1);
+    assert_eq!("1 + 3 x1 y2 - x1^2", p.to_string());
+    assert_eq!(p, p + x1 - x1);
+    assert_eq!(MultiPoly::constant(2) * p / MultiPoly::constant(2), p);
+}
+
+#[cfg(feature = "interval")]
+#[test]
+/// Ensure that interval products enclose the exact rational products.
+fn test_interval_arithmetic() {
+    use interval::Interval;
+    use num_traits::FromPrimitive;
+    let contains = |i: &Interval, x: &Ratio<i64>| {
+        let exact = Ratio::new(BigInt::from(*x.numer()), BigInt::from(*x.denom()));
+        Ratio::from_float(i.lo()).unwrap() <= exact && exact <= Ratio::from_float(i.hi()).unwrap()
+    };
+    let enclosure = |x: &Ratio<i64>| {
+        Interval::from_i64(*x.numer()).unwrap() / Interval::from_i64(*x.denom()).unwrap()
+    };
+    let mut rng = TestRng(0x1a7e);
+    let rational = |rng: &mut TestRng| {
+        Octavian::new([(); 8].map(|_| Ratio::new(rng.next_i64(50), rng.next_i64(6).abs() + 1)))
+    };
+    for _ in 0..50 {
+        let (x, y) = (rational(&mut rng), rational(&mut rng));
+        let (xi, yi) = (
+            Octavian::new(x.coefficients.map(|c| enclosure(&c))),
+            Octavian::new(y.coefficients.map(|c| enclosure(&c))),
+        );
+        for (i, e) in (xi * yi).coefficients.iter().zip((x * y).coefficients) {
+            assert!(contains(i, &e));
+        }
+        assert!(contains(&xi.norm(), &x.norm()));
+        assert!(contains(
+            &(xi + yi).inner_product(&yi),
+            &(x + y).inner_product(&y)
+        ));
+    }
+
+    // Point intervals of integers multiply exactly, like f64.
+    for _ in 0..20 {
+        let (x, y) = (rng.octavian(100), rng.octavian(100));
+        let point =
+            |x: Octavian<i64>| Octavian::new(x.coefficients.map(|c| Interval::point(c as f64)));
+        let product = (x * y).coefficients.map(|c| c as f64);
+        assert_eq!(
+            Octavian::new(product.map(Interval::point)),
+            point(x) * point(y)
+        );
+    }
+    let third = Interval::point(1.0) / Interval::point(3.0);
+    assert!(third.lo() < third.hi() && third.contains(1.0 / 3.0));
+    assert_eq!(None, Interval::new(1.0, 0.0));
+    assert_eq!(
+        Interval::point(0.0),
+        Interval::point(0.0) * Interval::point(1e-300)
+    );
+}
+
+#[cfg(all(feature = "interval", feature = "tables"))]
+#[test]
+/// Ensure that the interval candidates contain every closest octavian.
+fn test_closest_octavian_candidates() {
+    use interval::Interval;
+    use num_traits::FromPrimitive;
+    let mut rng = TestRng(0xc7b0);
+    for i in 0..20 {
+        let x: Octavian<Ratio<i64>> =
+            Octavian::new([(); 8].map(|_| Ratio::new(rng.next_i64(40), [1, 2, 3, 4, 6][i % 5])));
+        let float = Octavian::new(
+            x.coefficients
+                .map(|c| *c.numer() as f64 / *c.denom() as f64),
+        );
+        // The exact nearest octavians, among those near the decoding of the rounded point.
+        let center = lattice::closest_octavian(&float).unwrap();
+        let distances: Vec<(Octavian<i64>, Ratio<i64>)> = lattice::enumerate_norm_at_most(3)
+            .into_iter()
+            .map(|v| {
+                let p = center + v;
+                let d = x - Octavian::new(p.coefficients.map(Ratio::from_integer));
+                (p, d.inner_product(&d))
+            })
+            .collect();
+        let min = distances.iter().map(|(_, d)| *d).min().unwrap();
+        let exact: Vec<Octavian<i64>> = distances
+            .iter()
+            .filter(|(_, d)| *d == min)
+            .map(|&(p, _)| p)
+            .collect();
+
+        let widen = if i % 2 == 0 { 0.0 } else { 0.01 };
+        let target = Octavian::new(x.coefficients.map(|c| {
+            let e =
+                Interval::from_i64(*c.numer()).unwrap() / Interval::from_i64(*c.denom()).unwrap();
+            Interval::new(e.lo() - widen, e.hi() + widen).unwrap()
+        }));
+        let candidates = lattice::closest_octavian_candidates(&target).unwrap();
+        for p in &exact {
+            assert!(candidates.contains(p));
+        }
+    }
+
+    // Midway between 0 and a root both are nearest, while a generic point has one nearest octavian.
+    let root = Octavian::new(Octavian::UNITS[0].coefficients.map(i64::from));
+    let half = Octavian::new(root.coefficients.map(|c| Interval::point(c as f64 / 2.0)));
+    let candidates = lattice::closest_octavian_candidates(&half).unwrap();
+    assert!(candidates.contains(&root) && candidates.contains(&Octavian::zero()));
+    for _ in 0..20 {
+        let x = Octavian::new([(); 8].map(|_| rng.next_i64(1000) as f64 / 97.0));
+        let point = Octavian::new(x.coefficients.map(Interval::point));
+        assert_eq!(
+            Some(vec![lattice::closest_octavian(&x).unwrap()]),
+            lattice::closest_octavian_candidates(&point)
+        );
+    }
+    assert_eq!(
+        None,
+        lattice::closest_octavian_candidates(&Octavian::new([Interval::point(f64::INFINITY); 8]))
+    );
+    // Coordinates that fit in i64 can still give inner products beyond i128, which are reported rather than wrapped.
+    assert_eq!(
+        None,
+        lattice::closest_octavian_candidates(&Octavian::new([Interval::point(9.0e18); 8]))
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the right adjoint matrix multiplies on the right.
+fn test_right_adjoint_matrix() {
+    let units = units::units::<i64>();
+    for a in &units {
+        for b in &units {
+            let product = Octavian::new(octavian::apply_matrix(
+                &b.right_adjoint_matrix(),
+                &a.coefficients,
+            ));
+            assert_eq!(*a * *b, product);
+        }
+    }
+    // The left and right adjoints agree exactly for the units ±1, since the other units do not commute with everything.
+    let differ = units
+        .iter()
+        .filter(|u| u.left_adjoint_matrix() != u.right_adjoint_matrix())
+        .count();
+    assert_eq!(238, differ);
+    let mut rng = TestRng(0x7a9d);
+    for _ in 0..20 {
+        let (x, y) = (rng.octavian(10), rng.octavian(10));
+        assert_eq!(x * y, y.right_multiplier().apply(&x));
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that checked division recovers exact factors and rejects inexact quotients.
+fn test_checked_div() {
+    let units = units::units::<i64>();
+    for u in &units {
+        for v in &units {
+            let product = *u * *v;
+            assert_eq!(Some(*u), product.checked_div_right(v));
+            assert_eq!(Some(*v), product.checked_div_left(u));
+        }
+    }
+
+    let mut rng = TestRng(0xd1f1);
+    for _ in 0..100 {
+        let (x, y) = (rng.octavian(20), rng.octavian(20));
+        if y == Octavian::zero() {
+            continue;
+        }
+        assert_eq!(Some(x), (x * y).checked_div_right(&y));
+        assert_eq!(Some(x), (y * x).checked_div_left(&y));
+    }
+    // A unit is only divisible by units.
+    let two = Octavian::<i64>::one().scale(2);
+    assert_eq!(None, Octavian::<i64>::one().checked_div_left(&two));
+    assert_eq!(None, units[0].checked_div_right(&(units[0] + units[1])));
+    assert_eq!(None, units[0].checked_div_right(&Octavian::zero()));
+
+    // Rational and floating-point coefficients divide exactly by any nonzero divisor.
+    let ratio = |x: Octavian<i64>| Octavian::new(x.coefficients.map(Ratio::from));
+    let half = Octavian::new(
+        Octavian::<i64>::one()
+            .coefficients
+            .map(|c| Ratio::new(c, 2)),
+    );
+    assert_eq!(
+        Some(half),
+        ratio(Octavian::one()).checked_div_left(&ratio(two))
+    );
+    assert_eq!(
+        Some(half),
+        ratio(Octavian::one()).checked_div_right(&ratio(two))
+    );
+    let (x, y) = (ratio(rng.octavian(20)), ratio(units[0] + units[1]));
+    assert_eq!(Some(x), (y * x).checked_div_left(&y));
+    assert_eq!(Some(x), (x * y).checked_div_right(&y));
+    assert_eq!(None, x.checked_div_left(&Octavian::zero()));
+    let float = |x: Octavian<i64>| Octavian::new(x.coefficients.map(|c| c as f64));
+    assert_eq!(
+        Some(float(Octavian::one()).scale(0.5)),
+        float(Octavian::one()).checked_div_left(&float(two))
+    );
+
+    // For i8 the product with the conjugate of the divisor overflows, but the quotient fits.
+    let q = Octavian::<i8>::new([60, -50, 40, 30, -20, 10, 0, 55]);
+    let d = Octavian::<i8>::one().scale(2);
+    let a = q.scale(2);
+    assert_eq!(Some(q), a.checked_div_left(&d));
+    assert_eq!(Some(q), a.checked_div_right(&d));
+    assert_eq!(None, a.checked_div_left(&d.scale(3)));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that units invert to their conjugates and non-units have no inverse.
+fn test_inv() {
+    use num_traits::Inv;
+    let one = Octavian::<i64>::one();
+    for u in Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS {
+        let u = Octavian::new(u.map(i64::from));
+        let inverse = u.inv();
+        assert_eq!(one, inverse * u);
+        assert_eq!(one, u * inverse);
+        assert_eq!(u.conjugate(), inverse);
+        assert_eq!(Some(inverse), u.checked_inv());
+    }
+    assert_eq!(None, one.scale(2).checked_inv());
+    assert_eq!(None, Octavian::<i64>::zero().checked_inv());
+
+    let mut rng = TestRng(0x1e7);
+    for _ in 0..20 {
+        let x = rng.octavian(10);
+        let x = Octavian::new(x.coefficients.map(|c| c as f64));
+        if x.norm() == 0.0 {
+            continue;
+        }
+        let product = x.inv() * x - Octavian::one();
+        assert!(product.norm() < 1e-20);
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+#[should_panic(expected = "no inverse")]
+/// Ensure that inverting a non-unit panics.
+fn test_inv_non_unit() {
+    use num_traits::Inv;
+    let _ = Octavian::<i64>::one().scale(2).inv();
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that left division with remainder leaves a remainder of at most half the norm of the divisor.
+fn test_div_rem_left() {
+    let check = |a: &Octavian<i64>, d: &Octavian<i64>| {
+        let (q, r) = a.div_rem_left(d).unwrap();
+        assert_eq!(*a, *d * q + r);
+        assert!(2 * r.norm_i128().unwrap() <= d.norm_i128().unwrap());
+    };
+    let mut rng = TestRng(0xe0c1);
+    for _ in 0..2000 {
+        let (a, d) = (rng.octavian(1000), rng.octavian(30));
+        if d != Octavian::zero() {
+            check(&a, &d);
+        }
+    }
+    // All pairs of octavians with coefficients 0 and 1.
+    let small: Vec<Octavian<i64>> = (0..256)
+        .map(|bits| Octavian::new(core::array::from_fn(|i| (bits >> i) & 1)))
+        .collect();
+    for a in &small {
+        for d in small.iter().skip(1) {
+            check(a, d);
+        }
+    }
+    let unit = Octavian::new(Octavian::UNITS[7].coefficients.map(i64::from));
+    let x = rng.octavian(50);
+    assert_eq!(
+        Ok((x * unit, Octavian::zero())),
+        (unit * (x * unit)).div_rem_left(&unit)
+    );
+    assert_eq!(
+        Err(error::AlcoError::DivisionByZero),
+        x.div_rem_left(&Octavian::zero())
+    );
+    // The products are checked in i128, so huge operands report overflow in release builds too.
+    let huge = Octavian::new([i64::MAX; 8]);
+    assert_eq!(Err(error::AlcoError::Overflow), huge.div_rem_left(&huge));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that right division with remainder leaves a remainder of at most half the norm of the divisor.
+fn test_div_rem_right() {
+    let check = |a: &Octavian<i64>, d: &Octavian<i64>| {
+        let (q, r) = a.div_rem_right(d).unwrap();
+        assert_eq!(*a, q * *d + r);
+        assert!(2 * r.norm_i128().unwrap() <= d.norm_i128().unwrap());
+        (q, r)
+    };
+    let mut rng = TestRng(0xe0c2);
+    for _ in 0..1000 {
+        let (a, d) = (rng.octavian(1000), rng.octavian(30));
+        if d == Octavian::zero() {
+            continue;
+        }
+        check(&a, &d);
+        // Exact multiples leave no remainder, and the division recovers the factor.
+        let q = rng.octavian(100);
+        assert_eq!((q, Octavian::zero()), check(&(q * d), &d));
+    }
+    let small: Vec<Octavian<i64>> = (0..256)
+        .map(|bits| Octavian::new(core::array::from_fn(|i| (bits >> i) & 1)))
+        .collect();
+    for a in &small {
+        for d in small.iter().skip(1) {
+            check(a, d);
+        }
+    }
+    // The two divisions differ in general, since the octavians are not commutative.
+    let (a, d) = (
+        rng.octavian(50),
+        Octavian::new(Octavian::UNITS[7].coefficients.map(i64::from)),
+    );
+    assert_ne!(
+        a.div_rem_left(&d).unwrap().0,
+        a.div_rem_right(&d).unwrap().0
+    );
+    assert_eq!(
+        Err(error::AlcoError::DivisionByZero),
+        a.div_rem_right(&Octavian::zero())
+    );
+    let huge = Octavian::new([i64::MAX; 8]);
+    assert_eq!(Err(error::AlcoError::Overflow), huge.div_rem_right(&huge));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that greatest common left divisors divide both inputs, with cofactors that recombine them.
+fn test_gcld() {
+    use octavian::{gcld, gcld_with_cofactors};
+    let zero = Octavian::<i64>::zero();
+    let mut rng = TestRng(0x9c1d);
+    let x = rng.octavian(10);
+    assert_eq!(Some(zero), gcld(&zero, &zero));
+    assert_eq!(Some(x), gcld(&x, &zero));
+    assert_eq!(Some(x), gcld(&zero, &x));
+    assert_eq!(Some((zero, zero, zero)), gcld_with_cofactors(&zero, &zero));
+
+    for p in [2, 3, 5, 7, 11] {
+        let one = Octavian::<i64>::one().scale(p);
+        for x in lattice::norm_shell(p).iter().step_by(97) {
+            assert_eq!(Some(*x), gcld(&one, x));
+            assert_eq!(
+                Some((*x, x.conjugate(), Octavian::one())),
+                gcld_with_cofactors(&one, x)
+            );
+            // A multiple of x that is not a multiple of p shares a divisor of norm p with it, which the Euclidean
+            // algorithm finds unless nonassociativity loses it on the way.
+            let y = *x * rng.octavian(3);
+            if y == zero || y.checked_div_left(&one).is_some() {
+                continue;
+            }
+            if let Some(g) = gcld(&one, &y) {
+                assert_eq!(p, g.norm());
+            }
+        }
+    }
+
+    // A returned divisor always left-divides both inputs, and the cofactors reconstruct them.
+    let mut divided = 0;
+    for _ in 0..200 {
+        let (a, b) = (rng.octavian(20), rng.octavian(20));
+        match gcld_with_cofactors(&a, &b) {
+            Some((g, s, t)) => {
+                assert_eq!((a, b), (g * s, g * t));
+                assert_eq!(Some(g), gcld(&a, &b));
+                divided += 1;
+            }
+            None => assert_eq!(None, gcld(&a, &b)),
+        }
+    }
+    // Random pairs are mostly coprime, with a unit as the divisor.
+    assert!(divided > 150);
+    let huge = Octavian::new([i64::MAX; 8]);
+    assert_eq!(None, gcld(&huge, &huge));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that greatest common right divisors right-divide both inputs and split rational primes.
+fn test_gcrd() {
+    use octavian::gcrd;
+    let zero = Octavian::<i64>::zero();
+    let mut rng = TestRng(0x6c2d);
+    let x = rng.octavian(10);
+    assert_eq!(Some(zero), gcrd(&zero, &zero));
+    assert_eq!(Some(x), gcrd(&x, &zero));
+    for p in [2, 3, 5, 7] {
+        let one = Octavian::<i64>::one().scale(p);
+        for x in lattice::norm_shell(p).iter().step_by(89) {
+            assert_eq!(Some(*x), gcrd(&one, x));
+        }
+    }
+    for _ in 0..100 {
+        let (a, b) = (rng.octavian(20), rng.octavian(20));
+        if let Some(g) = gcrd(&a, &b) {
+            assert_eq!(a, a.checked_div_right(&g).unwrap() * g);
+            assert_eq!(b, b.checked_div_right(&g).unwrap() * g);
+        }
+    }
+    assert_eq!(None, gcrd(&Octavian::new([i64::MAX; 8]), &x));
+}
+
+#[test]
+/// Ensure that factorizations are right-nested products of octavians with the prime factors of the norm in order.
+fn test_factor() {
+    use factor::{factor, MAX_PRIME};
+    let check = |a: Octavian<i64>| {
+        let factors = factor(&a).unwrap();
+        let product = factors
+            .iter()
+            .rev()
+            .copied()
+            .reduce(|rest, f| f * rest)
+            .unwrap();
+        assert_eq!(a, product);
+        let norms: Vec<i64> = factors.iter().map(|f| f.norm()).collect();
+        assert!(norms.windows(2).all(|w| w[0] <= w[1]));
+        if a.norm() > 1 {
+            let is_prime = |n: i64| n > 1 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert!(norms.iter().all(|&n| is_prime(n)));
+            assert_eq!(a.norm(), norms.iter().product::<i64>());
+        }
+        factors
+    };
+
+    let one = Octavian::<i64>::one();
+    assert_eq!(vec![one], check(one));
+    assert_eq!(vec![-one], check(-one));
+    // n has norm n², so a rational integer with k prime factors has 2k factors,
+    // found for primes 2, 1 mod 4 and 3 mod 4 alike although n divides every coefficient.
+    for (n, k) in [(2, 1), (3, 1), (4, 2), (12, 3), (49, 2), (101, 1), (360, 6)] {
+        assert_eq!(2 * k, check(one.scale(n)).len());
+    }
+    let mut rng = TestRng(0xfac7);
+    for _ in 0..200 {
+        let a = rng.octavian(30);
+        if a != Octavian::zero() {
+            check(a);
+        }
+    }
+    // Larger coefficients reach primes up to the bound, where the lattice search must still be exact.
+    let mut factored = 0;
+    for _ in 0..50 {
+        let a = rng.octavian(3000);
+        match factor(&a) {
+            Ok(_) => {
+                check(a);
+                factored += 1;
+            }
+            Err(e) => assert_eq!(error::AlcoError::PrimeTooLarge, e),
+        }
+    }
+    assert!(factored > 25);
+    let (x, y) = (lattice::norm_shell(3)[5], lattice::norm_shell(2)[7]);
+    let norms: Vec<i64> = check(x * y).iter().map(|f| f.norm()).collect();
+    assert_eq!(vec![2, 3], norms);
+
+    assert_eq!(Err(error::AlcoError::Zero), factor(&Octavian::zero()));
+    assert_eq!(
+        Err(error::AlcoError::PrimeTooLarge),
+        factor(&one.scale(MAX_PRIME + 43))
+    );
+    assert_eq!(
+        Err(error::AlcoError::Overflow),
+        factor(&Octavian::new([i64::MAX; 8]))
+    );
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that exactly the 240 units are recognised, whatever the coefficient type.
+fn test_is_unit() {
+    for u in Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS {
+        assert!(Octavian::new(u).is_unit());
+        assert!(Octavian::new(u.map(i64::from)).is_unit());
+        assert!(Octavian::new(u.map(f64::from)).is_unit());
+    }
+    let one = Octavian::<i8>::one();
+    assert!(!(one + one).is_unit());
+    assert!(!Octavian::<i8>::zero().is_unit());
+    assert!(!Octavian::new([100i8; 8]).is_unit());
+    // Huge coefficients are rejected without overflowing the norm.
+    let huge = Octavian::new([i64::MAX / 2, 1, 0, 0, 0, 0, 0, -i64::MAX / 2]);
+    assert!(!huge.is_unit());
+    assert_eq!(
+        i64::from(Octavian::<i64>::UNIT_NORM),
+        Octavian::<i64>::one().norm()
+    );
+
+    // Among the octavians of norm at most 2 only the units pass.
+    let small = lattice::enumerate_norm_at_most(2);
+    assert_eq!(240, small.iter().filter(|x| x.is_unit()).count());
+    let x = Octavian::<f64>::one().scale(2.0);
+    assert!(!x.is_unit());
+    assert!(x.scale(0.5).is_unit());
+}
+
+#[test]
+/// Ensure that the `Zero` implementation is the additive identity.
+fn test_zero_trait() {
+    use num_traits::Zero;
+    fn total<S: Zero + Copy>(terms: &[S]) -> S {
+        terms.iter().fold(S::zero(), |sum, &x| sum + x)
+    }
+    assert!(Octavian::<i32>::zero().is_zero());
+    assert!(<Octavian<f64> as Zero>::zero().is_zero());
+    let mut rng = TestRng(0x2e50);
+    let terms: Vec<Octavian<i64>> = (0..10).map(|_| rng.octavian(100)).collect();
+    for &x in &terms {
+        assert_eq!(x, x + Zero::zero());
+        assert_eq!(x.is_zero(), x == Octavian::zero());
+    }
+    let sum = terms.iter().fold(Octavian::zero(), |s, &x| s + x);
+    assert_eq!(sum, total(&terms));
+    let mut x = terms[0];
+    x.set_zero();
+    assert!(x.is_zero());
+    assert!(!Octavian::<i64>::one().is_zero());
+}
+
+#[test]
+/// Ensure that the `One` implementation and the `ONE` constants are the multiplicative identity.
+fn test_one_trait() {
+    use num_traits::One;
+    fn product<S: One + Copy>(factors: &[S]) -> S {
+        factors.iter().fold(S::one(), |p, &x| p * x)
+    }
+    let converted = |x: [i8; 8]| Octavian::new(x.map(i64::from));
+    assert_eq!(
+        converted(Octavian::<i8>::ONE.coefficients),
+        Octavian::<i64>::ONE
+    );
+    assert_eq!(Octavian::<i16>::ONE, Octavian::one());
+    assert_eq!(Octavian::<i32>::ONE, Octavian::one());
+    assert_eq!(Octavian::<i128>::ONE, Octavian::one());
+    assert_eq!(Octavian::<f32>::ONE, Octavian::one());
+    assert_eq!(Octavian::<f64>::ONE, Octavian::one());
+    assert_eq!(
+        Octavian::new([2, 3, 4, 6, 5, 4, 3, 2].map(|x| -Ratio::from_integer(x))),
+        Octavian::<Ratio<i64>>::one()
+    );
+    assert!(Octavian::<i64>::ONE.is_one());
+    assert!(!Octavian::<i64>::zero().is_one());
+
+    let mut rng = TestRng(0x0e1);
+    let factors: Vec<Octavian<i64>> = (0..4).map(|_| rng.octavian(3)).collect();
+    assert_eq!(
+        factors.iter().fold(Octavian::<i64>::ONE, |p, &x| p * x),
+        product(&factors)
+    );
+    for &x in &factors {
+        assert_eq!(x, Octavian::<i64>::ONE * x);
+        assert_eq!(x.conjugate(), Octavian::<i64>::ONE.scale(x.trace()) - x);
+    }
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that the assignment operators agree with the binary ones.
+fn test_assign_operators() {
+    let units = units::units::<i64>();
+    let scalars = [-7, -3, -2, -1, 1, 2, 5];
+    for (i, &u) in units.iter().enumerate() {
+        let v = units[(7 * i + 3) % 240];
+        let w = u.scale(3) + v;
+        let mut x = w;
+        x += v;
+        assert_eq!(w + v, x);
+        let mut x = w;
+        x -= v;
+        assert_eq!(w - v, x);
+        let mut x = w;
+        x *= v;
+        assert_eq!(w * v, x);
+        for t in scalars {
+            let mut x = w;
+            x *= t;
+            assert_eq!(w * t, x);
+            let mut x = w;
+            x /= t;
+            assert_eq!(w / t, x);
+        }
+    }
+    // Scalar division truncates integer coefficients, and is exact on multiples.
+    let x = Octavian::new([7i64, -7, 3, -3, 0, 1, -1, 6]);
+    assert_eq!(Octavian::new([3, -3, 1, -1, 0, 0, 0, 3]), x / 2);
+    assert_eq!(Ok(x), (x * -4).div_exact(-4));
+    let mut y = Octavian::new(x.coefficients.map(|c| c as f64));
+    y /= -0.5;
+    assert_eq!(Octavian::new(x.coefficients.map(|c| c as f64 * -2.0)), y);
+}
@@ -0,0 +1,955 @@
+use crate::error::AlcoError;
+use crate::lattice::norm_shell;
+use crate::octavian::{Coefficient, Octavian};
+use crate::quaternion::QuaternionEmbedding;
+use core::ops::Neg;
+use num_traits::{FromPrimitive, Num};
+#[cfg(feature = "par")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+impl Octavian<i8> {
+    /// The 240 unit octavians, in the same order as `OCTAVIAN_UNITS_COEFFICIENTS`.
+    pub const UNITS: [Octavian<i8>; 240] = {
+        let mut units = [Octavian::new([0; 8]); 240];
+        let mut i = 0;
+        while i < 240 {
+            units[i] = Octavian::new(Self::OCTAVIAN_UNITS_COEFFICIENTS[i]);
+            i += 1;
+        }
+        units
+    };
+
+    /// Returns the position of a unit octavian in `Octavian::UNITS`, or `None` if it is not a unit.
+    /// The unit table is sorted, so this is a binary search and does not allocate.
+    pub fn unit_index(&self) -> Option<u16> {
+        Self::UNITS
+            .binary_search_by(|u| u.coefficients.cmp(&self.coefficients))
+            .ok()
+            .map(|i| i as u16)
+    }
+
+    /// Returns the unit octavian at a given position in `Octavian::UNITS`.
+    /// Returns `AlcoError::IndexOutOfRange` if `i` is not below 240.
+    pub fn unit_from_index(i: u16) -> Result<Self, AlcoError> {
+        check_unit_index(i)?;
+        Ok(Self::UNITS[i as usize])
+    }
+
+    /// Returns the index of the class {u, -u} of a unit octavian among `unit_sign_classes()`, or `None` if it is not a unit.
+    /// Since `UNITS` is sorted and closed under negation, the class index is the smaller of the indices of u and -u.
+    pub fn projective_index(&self) -> Option<u16> {
+        let i = self.unit_index()?;
+        let j = (-*self).unit_index()?;
+        Some(i.min(j))
+    }
+
+    /// Returns the canonical representative of the class {u, -u} of a unit octavian, the lexicographically larger of the two.
+    pub fn projective_representative(&self) -> Option<Self> {
+        self.unit_index()?;
+        Some(if self.coefficients > (-*self).coefficients {
+            *self
+        } else {
+            -*self
+        })
+    }
+
+    /// Returns the inverse of a unit octavian, or `None` if it is not a unit.
+    pub fn unit_inverse(&self) -> Option<Self> {
+        let i = self.unit_index()?;
+        Some(Self::UNITS[inverse_index(i) as usize])
+    }
+}
+
+/// The 240 unit octavians with coefficients cast to `T`, in the same order as `Octavian::UNITS`.
+pub fn units<T>() -> [Octavian<T>; 240]
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    Octavian::<T>::unit_vectors()
+}
+
+pub use crate::tables::{unit_multiplication_table, unit_set};
+
+/// Returns the index of the inverse of the unit with index `i`, which is its conjugate.
+/// Returns `AlcoError::IndexOutOfRange` if `i` is not below 240.
+pub fn unit_inverse_index(i: u16) -> Result<u16, AlcoError> {
+    check_unit_index(i)?;
+    Ok(inverse_index(i))
+}
+
+/// Returns `AlcoError::IndexOutOfRange` unless `i` is the index of one of the 240 units.
+pub(crate) fn check_unit_index(i: u16) -> Result<(), AlcoError> {
+    if usize::from(i) < Octavian::<i8>::UNITS.len() {
+        Ok(())
+    } else {
+        Err(AlcoError::IndexOutOfRange {
+            index: usize::from(i),
+            len: Octavian::<i8>::UNITS.len(),
+        })
+    }
+}
+
+/// Returns the index of the inverse of the unit with index `i`, which the caller has checked.
+pub(crate) fn inverse_index(i: u16) -> u16 {
+    crate::tables::unit_inverses()[i as usize]
+}
+
+/// Returns the indices of the subloop generated by the units with the given indices, in increasing order.
+/// The closure includes the identity and the inverses of the generators.
+/// Returns `AlcoError::IndexOutOfRange` if a generator is not below 240.
+pub fn generated_subloop_indices(generators: &[u16]) -> Result<Vec<u16>, AlcoError> {
+    generators.iter().try_for_each(|&g| check_unit_index(g))?;
+    Ok(subloop_indices(generators))
+}
+
+/// Returns the indices of the subloop generated by units whose indices the caller has checked.
+fn subloop_indices(generators: &[u16]) -> Vec<u16> {
+    generated_subloop_in(unit_multiplication_table(), generators)
+}
+
+/// Returns the closure of the generators, the identity and the inverses of the generators under the given multiplication table.
+fn generated_subloop_in(table: &[[u16; 240]; 240], generators: &[u16]) -> Vec<u16> {
+    let mut member = [false; 240];
+    let mut elements = Vec::new();
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let mut frontier: Vec<u16> = vec![identity];
+    frontier.extend(generators);
+    frontier.extend(generators.iter().map(|&g| inverse_index(g)));
+    while let Some(x) = frontier.pop() {
+        if member[x as usize] {
+            continue;
+        }
+        member[x as usize] = true;
+        elements.push(x);
+        for &y in &elements {
+            for p in [table[x as usize][y as usize], table[y as usize][x as usize]] {
+                if !member[p as usize] {
+                    frontier.push(p);
+                }
+            }
+        }
+    }
+    elements.sort_unstable();
+    elements
+}
+
+/// Tests whether the units with the given indices associate, that is (xy)z = x(yz) for all x, y, z among them.
+/// Returns `AlcoError::IndexOutOfRange` if an index is not below 240.
+pub fn is_associative_indices(elements: &[u16]) -> Result<bool, AlcoError> {
+    elements.iter().try_for_each(|&x| check_unit_index(x))?;
+    Ok(is_associative_in(unit_multiplication_table(), elements))
+}
+
+/// Tests whether the given elements associate under the given multiplication table.
+fn is_associative_in(table: &[[u16; 240]; 240], elements: &[u16]) -> bool {
+    elements.iter().all(|&x| {
+        elements.iter().all(|&y| {
+            let xy = table[x as usize][y as usize] as usize;
+            elements.iter().all(|&z| {
+                table[xy][z as usize] == table[x as usize][table[y as usize][z as usize] as usize]
+            })
+        })
+    })
+}
+
+/// Returns the subloop generated by the given units in canonical sorted order, together with whether it is associative (a group).
+/// Returns `AlcoError::NotAUnit` if any generator is not a unit octavian.
+pub fn generated_subloop(
+    generators: &[Octavian<i8>],
+) -> Result<(Vec<Octavian<i8>>, bool), AlcoError> {
+    let indices = generators
+        .iter()
+        .map(|g| g.unit_index().ok_or(AlcoError::NotAUnit))
+        .collect::<Result<Vec<u16>, AlcoError>>()?;
+    let subloop = subloop_indices(&indices);
+    let is_associative = is_associative_in(unit_multiplication_table(), &subloop);
+    Ok((
+        subloop
+            .into_iter()
+            .map(|i| Octavian::UNITS[i as usize])
+            .collect(),
+        is_associative,
+    ))
+}
+
+/// Returns the order of the unit with index `i`, the least `n > 0` with `UNITS[i]^n = one()`.
+/// Powers are unambiguous because octonion loops are power-associative.
+/// Returns `AlcoError::IndexOutOfRange` if `i` is not below 240.
+pub fn unit_order(i: u16) -> Result<u32, AlcoError> {
+    check_unit_index(i)?;
+    Ok(order_of(i))
+}
+
+/// Returns the order of the unit with index `i`, which the caller has checked.
+fn order_of(i: u16) -> u32 {
+    let table = unit_multiplication_table();
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let mut power = i;
+    let mut order = 1;
+    while power != identity {
+        power = table[power as usize][i as usize];
+        order += 1;
+    }
+    order
+}
+
+/// Returns every subgroup of the unit loop isomorphic to the quaternion group Q8, as sorted index octuples.
+/// Each such subgroup is generated by two of its elements of order 4, so only pairs of order-4 units are searched.
+pub fn find_subloops_isomorphic_to_q8() -> Vec<[u16; 8]> {
+    let order_four: Vec<u16> = (0..240).filter(|&i| order_of(i) == 4).collect();
+    let mut found = HashSet::new();
+    for (n, &a) in order_four.iter().enumerate() {
+        for &b in &order_four[n + 1..] {
+            if b == inverse_index(a) {
+                continue;
+            }
+            let subloop = subloop_indices(&[a, b]);
+            if let Ok(octuple) = <[u16; 8]>::try_from(subloop) {
+                found.insert(octuple);
+            }
+        }
+    }
+    let mut result: Vec<[u16; 8]> = found.into_iter().collect();
+    result.sort_unstable();
+    result
+}
+
+/// The isomorphism type of a finite group of unit octavians, identified by its order spectrum and center.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum GroupType {
+    /// The cyclic group of the given order.
+    Cyclic(usize),
+    /// The quaternion group Q8 of order 8.
+    Quaternion,
+    /// The dicyclic (binary dihedral) group of the given order, other than Q8.
+    Dicyclic(usize),
+    /// The binary tetrahedral group 2T of order 24, the unit group of the Hurwitz quaternions.
+    BinaryTetrahedral,
+    /// A group not matching any of the other types.
+    Other,
+}
+
+/// A subloop of the unit loop, together with its order, center size and (if associative) group type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubloopInfo {
+    /// The indices of the elements of the subloop, in increasing order.
+    pub elements: Vec<u16>,
+    /// The number of elements of the subloop.
+    pub order: usize,
+    /// The number of elements commuting with every element of the subloop.
+    pub center_size: usize,
+    /// The isomorphism type of the subloop, assuming it is a group.
+    pub kind: GroupType,
+}
+
+impl SubloopInfo {
+    /// Collects the invariants of the subloop with the given elements.
+    pub fn new(elements: Vec<u16>) -> Self {
+        let table = unit_multiplication_table();
+        let order = elements.len();
+        let center_size = elements
+            .iter()
+            .filter(|&&x| {
+                elements
+                    .iter()
+                    .all(|&y| table[x as usize][y as usize] == table[y as usize][x as usize])
+            })
+            .count();
+        let mut spectrum: Vec<u32> = elements.iter().map(|&x| order_of(x)).collect();
+        spectrum.sort_unstable();
+        let count = |n: u32| spectrum.iter().filter(|&&o| o == n).count();
+        let kind = if spectrum.last() == Some(&(order as u32)) {
+            GroupType::Cyclic(order)
+        } else if order == 8 && count(4) == 6 && center_size == 2 {
+            GroupType::Quaternion
+        } else if order == 24 && count(3) == 8 && count(4) == 6 && count(6) == 8 {
+            GroupType::BinaryTetrahedral
+        } else if order.is_multiple_of(4)
+            && count(2) == 1
+            && center_size == 2
+            && count(order as u32 / 2) > 0
+        {
+            GroupType::Dicyclic(order)
+        } else {
+            GroupType::Other
+        };
+        SubloopInfo {
+            elements,
+            order,
+            center_size,
+            kind,
+        }
+    }
+}
+
+/// Returns the maximal associative subloops (subgroups) of the unit loop, ordered by decreasing order.
+///
+/// Every subgroup spans an associative subalgebra of the octonions, hence lies in the units of a quaternion order,
+/// and the finite subgroups of the quaternion units are all generated by two elements.
+/// So the subgroups are found by closing every pair of units, and the maximal ones are those not contained in another.
+pub fn maximal_associative_subloops() -> Vec<SubloopInfo> {
+    let pairs = |a: u16| (a..240).map(move |b| subloop_indices(&[a, b]));
+    #[cfg(feature = "par")]
+    let closures = (0..240u16).into_par_iter().flat_map_iter(pairs);
+    #[cfg(not(feature = "par"))]
+    let closures = (0..240u16).flat_map(pairs);
+    let mut subgroups: Vec<Vec<u16>> = closures.collect::<HashSet<_>>().into_iter().collect();
+    subgroups.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    let mut maximal: Vec<Vec<u16>> = Vec::new();
+    for g in subgroups {
+        let contained = maximal
+            .iter()
+            .any(|h| h.len() > g.len() && g.iter().all(|x| h.binary_search(x).is_ok()));
+        if !contained {
+            maximal.push(g);
+        }
+    }
+    maximal.into_iter().map(SubloopInfo::new).collect()
+}
+
+/// Counts the ordered pairs of units that commute, returned as (commuting pairs, all pairs).
+pub fn unit_commuting_fraction() -> (u64, u64) {
+    let table = unit_multiplication_table();
+    let mut count = 0;
+    for (x, row) in table.iter().enumerate() {
+        count += (0..240).filter(|&y| row[y] == table[y][x]).count() as u64;
+    }
+    (count, 240 * 240)
+}
+
+/// Counts the associating triples among the units with first element `x`, that is (xy)z = x(yz).
+fn associating_triples_from(x: usize) -> u64 {
+    let table = unit_multiplication_table();
+    let mut count = 0;
+    for (y, &xy) in table[x].iter().enumerate() {
+        let row = &table[xy as usize];
+        count += (0..240)
+            .filter(|&z| row[z] == table[x][table[y][z] as usize])
+            .count() as u64;
+    }
+    count
+}
+
+/// Counts the ordered triples of units that associate, returned as (associating triples, all triples).
+pub fn unit_associating_fraction() -> (u64, u64) {
+    (
+        (0..240).map(associating_triples_from).sum(),
+        240 * 240 * 240,
+    )
+}
+
+/// Counts the ordered triples of units that associate in parallel, returned as (associating triples, all triples).
+#[cfg(feature = "par")]
+pub fn unit_associating_fraction_par() -> (u64, u64) {
+    (
+        (0..240).into_par_iter().map(associating_triples_from).sum(),
+        240 * 240 * 240,
+    )
+}
+
+/// Returns the permutation of unit indices x ↦ u·x induced by left multiplication by a unit, or `None` if `u` is not a unit.
+pub fn left_translation_permutation(u: &Octavian<i8>) -> Option<[u16; 240]> {
+    let i = u.unit_index()? as usize;
+    Some(unit_multiplication_table()[i])
+}
+
+/// Returns the permutation of unit indices x ↦ x·u induced by right multiplication by a unit, or `None` if `u` is not a unit.
+pub fn right_translation_permutation(u: &Octavian<i8>) -> Option<[u16; 240]> {
+    let i = u.unit_index()? as usize;
+    let table = unit_multiplication_table();
+    Some(core::array::from_fn(|x| table[x][i]))
+}
+
+/// Composes two permutations of unit indices, applying `q` first and then `p`.
+/// Returns `None` if `p` or `q` is not a permutation of 0..240.
+pub fn compose_permutations(p: &[u16; 240], q: &[u16; 240]) -> Option<[u16; 240]> {
+    if !is_permutation(p.iter().copied()) || !is_permutation(q.iter().copied()) {
+        return None;
+    }
+    Some(q.map(|x| p[x as usize]))
+}
+
+/// Returns the lengths of the cycles of a permutation of unit indices in increasing order, including fixed points.
+/// Returns `None` if `p` is not a permutation of 0..240.
+pub fn cycle_type(p: &[u16; 240]) -> Option<Vec<usize>> {
+    if !is_permutation(p.iter().copied()) {
+        return None;
+    }
+    let mut seen = [false; 240];
+    let mut lengths = Vec::new();
+    for start in 0..240 {
+        let mut length = 0;
+        let mut x = start;
+        while !seen[x] {
+            seen[x] = true;
+            x = p[x] as usize;
+            length += 1;
+        }
+        if length > 0 {
+            lengths.push(length);
+        }
+    }
+    lengths.sort_unstable();
+    Some(lengths)
+}
+
+/// Returns the order of a permutation of unit indices, the least common multiple of its cycle lengths.
+/// Returns `None` if `p` is not a permutation of 0..240.
+pub fn permutation_order(p: &[u16; 240]) -> Option<usize> {
+    Some(cycle_type(p)?.into_iter().fold(1, num::integer::lcm))
+}
+
+/// Returns the indices of the units `a` satisfying the associativity condition `associates(a, x, y)` for all units x and y.
+fn nucleus_indices(associates: impl Fn(usize, usize, usize) -> bool + Sync) -> Vec<u16> {
+    let is_nuclear = |&a: &u16| (0..240).all(|x| (0..240).all(|y| associates(a as usize, x, y)));
+    #[cfg(feature = "par")]
+    return (0..240u16).into_par_iter().filter(is_nuclear).collect();
+    #[cfg(not(feature = "par"))]
+    return (0..240u16).filter(is_nuclear).collect();
+}
+
+/// Returns the left nucleus of the unit loop, the units a with (ax)y = a(xy) for all units x and y.
+pub fn loop_left_nucleus() -> Vec<Octavian<i8>> {
+    let t = unit_multiplication_table();
+    nucleus_indices(|a, x, y| t[t[a][x] as usize][y] == t[a][t[x][y] as usize])
+        .into_iter()
+        .map(|i| Octavian::UNITS[i as usize])
+        .collect()
+}
+
+/// Returns the middle nucleus of the unit loop, the units a with (xa)y = x(ay) for all units x and y.
+pub fn loop_middle_nucleus() -> Vec<Octavian<i8>> {
+    let t = unit_multiplication_table();
+    nucleus_indices(|a, x, y| t[t[x][a] as usize][y] == t[x][t[a][y] as usize])
+        .into_iter()
+        .map(|i| Octavian::UNITS[i as usize])
+        .collect()
+}
+
+/// Returns the right nucleus of the unit loop, the units a with (xy)a = x(ya) for all units x and y.
+pub fn loop_right_nucleus() -> Vec<Octavian<i8>> {
+    let t = unit_multiplication_table();
+    nucleus_indices(|a, x, y| t[t[x][y] as usize][a] == t[x][t[y][a] as usize])
+        .into_iter()
+        .map(|i| Octavian::UNITS[i as usize])
+        .collect()
+}
+
+/// Returns the nucleus of the unit loop, the intersection of the left, middle and right nuclei.
+pub fn loop_nucleus() -> Vec<Octavian<i8>> {
+    let middle = loop_middle_nucleus();
+    let right = loop_right_nucleus();
+    loop_left_nucleus()
+        .into_iter()
+        .filter(|a| middle.contains(a) && right.contains(a))
+        .collect()
+}
+
+/// Returns the center of the unit loop, the elements of the nucleus that commute with every unit.
+pub fn loop_center() -> Vec<Octavian<i8>> {
+    loop_nucleus()
+        .into_iter()
+        .filter(|a| Octavian::UNITS.iter().all(|x| *a * *x == *x * *a))
+        .collect()
+}
+
+/// Returns a GAP script defining the unit loop with the LOOPS package.
+/// Element `i` of the GAP loop corresponds to `UNITS[i - 1]`, since GAP numbers elements from 1.
+pub fn export_loop_gap() -> String {
+    let table = unit_multiplication_table();
+    let rows: Vec<String> = table
+        .iter()
+        .map(|row| {
+            let entries: Vec<String> = row.iter().map(|&x| (x + 1).to_string()).collect();
+            format!("[{}]", entries.join(", "))
+        })
+        .collect();
+    format!(
+        "# The unit loop of the octavian integers; element i is UNITS[i - 1].\nLoadPackage(\"loops\");\nunitLoop := LoopByCayleyTable([\n{}\n]);\n",
+        rows.join(",\n")
+    )
+}
+
+/// Returns a GAP list of the E8 lattice coordinates of the units, numbered consistently with `export_loop_gap`.
+pub fn export_units_gap() -> String {
+    let rows: Vec<String> = Octavian::UNITS
+        .iter()
+        .map(|u| {
+            let entries: Vec<String> = u.coefficients.iter().map(|x| x.to_string()).collect();
+            format!("[{}]", entries.join(", "))
+        })
+        .collect();
+    format!("units := [\n{}\n];\n", rows.join(",\n"))
+}
+
+/// Returns the subgroups of the unit loop isomorphic to the binary tetrahedral group 2T, the unit group of the Hurwitz quaternions.
+pub fn hurwitz_unit_subgroups() -> Vec<[u16; 24]> {
+    maximal_associative_subloops()
+        .into_iter()
+        .filter(|s| s.kind == GroupType::BinaryTetrahedral)
+        .map(|s| s.elements.try_into().expect("2T has 24 elements"))
+        .collect()
+}
+
+/// Returns a quaternion embedding whose image contains the given Hurwitz unit subgroup,
+/// sending i and j to two anticommuting elements of order 4 in the subgroup.
+/// The 24 units then pull back to the Hurwitz units ±1, ±i, ±j, ±k and (±1 ± i ± j ± k)/2.
+/// Returns `AlcoError::IndexOutOfRange` for an index of 240 or more,
+/// and `AlcoError::NotASubgroup` if the indices are not the elements of a copy of 2T.
+pub fn hurwitz_embedding(subgroup: &[u16; 24]) -> Result<QuaternionEmbedding, AlcoError> {
+    subgroup.iter().try_for_each(|&x| check_unit_index(x))?;
+    let mut elements = subgroup.to_vec();
+    elements.sort_unstable();
+    let table = unit_multiplication_table();
+    let distinct = elements.windows(2).all(|w| w[0] < w[1]);
+    let closed = elements.iter().all(|&x| {
+        elements.iter().all(|&y| {
+            elements
+                .binary_search(&table[x as usize][y as usize])
+                .is_ok()
+        })
+    });
+    // A closed set of 24 distinct units is a subloop; associativity makes it a group and the order spectrum 2T.
+    if !distinct
+        || !closed
+        || !is_associative_in(table, &elements)
+        || SubloopInfo::new(elements).kind != GroupType::BinaryTetrahedral
+    {
+        return Err(AlcoError::NotASubgroup);
+    }
+    let widen = |i: u16| Octavian::new(Octavian::UNITS[i as usize].coefficients.map(i64::from));
+    let order_four: Vec<Octavian<i64>> = subgroup
+        .iter()
+        .filter(|&&x| order_of(x) == 4)
+        .map(|&x| widen(x))
+        .collect();
+    // The six elements of order 4 form the copy of Q8 in 2T, so any one of them has an anticommuting partner.
+    let i = order_four[0];
+    order_four
+        .iter()
+        .find_map(|&j| QuaternionEmbedding::new(i, j))
+        .ok_or(AlcoError::NotASubgroup)
+}
+
+/// Pairs the index of each unit with the index of its negative, ordered by class index.
+/// The first entry of each pair is the smaller index, so the pair for class `c` is `(c, index of -UNITS[c])`.
+pub fn unit_sign_classes() -> [(u16, u16); 120] {
+    core::array::from_fn(|c| {
+        let negative = (-Octavian::UNITS[c]).unit_index().unwrap();
+        (c as u16, negative)
+    })
+}
+
+/// Returns the unit nearest to `x` together with the distance sqrt(norm(x - u)) between them.
+/// Ties are broken in favour of the unit with the smallest index. No allocation takes place.
+pub fn nearest_unit(x: &Octavian<f64>) -> (Octavian<i8>, f64) {
+    let mut best = (Octavian::UNITS[0], f64::INFINITY);
+    for u in &Octavian::UNITS {
+        let d = (*x - Octavian::new(u.coefficients.map(f64::from))).norm();
+        if d < best.1 {
+            best = (*u, d);
+        }
+    }
+    (best.0, best.1.sqrt())
+}
+
+/// Returns the unit nearest to `x` together with the norm of the difference norm(x - u).
+/// Ties are broken in favour of the unit with the smallest index. No allocation takes place.
+/// Returns `None` if the norm of `x` or of the difference does not fit in i128.
+pub fn nearest_unit_exact(x: &Octavian<i64>) -> Option<(Octavian<i8>, i128)> {
+    // norm(x - u) = norm(x) - ⟨x, u⟩ + 1 for a unit u, so the nearest unit has the largest inner product with x.
+    let mut best = (Octavian::UNITS[0], i128::MIN);
+    for u in &Octavian::UNITS {
+        let ip: i128 = Octavian::new(u.coefficients.map(i64::from)).checked_inner_product_in(x)?;
+        if ip > best.1 {
+            best = (*u, ip);
+        }
+    }
+    let distance = x.norm_i128().ok()?.checked_sub(best.1)?.checked_add(1)?;
+    Some((best.0, distance))
+}
+
+/// Returns the sum of ⟨u, v⟩^k over the 240 units u.
+/// The units form a spherical 7-design, so the sum vanishes for odd k and, writing q = ⟨v, v⟩ = 2 norm(v),
+/// equals 60 q for k = 2, 36 q² for k = 4 and 30 q³ for k = 6, the averages of ⟨x, v⟩^k over the sphere of radius √2.
+/// From k = 8 on the sum depends on the direction of v and not only on its norm.
+/// Returns `None` if an inner product, power or partial sum does not fit in i128.
+pub fn power_sum_over_units(v: &Octavian<i64>, k: u32) -> Option<i128> {
+    Octavian::UNITS.iter().try_fold(0i128, |sum, u| {
+        let ip: i128 = Octavian::new(u.coefficients.map(i64::from)).checked_inner_product_in(v)?;
+        sum.checked_add(ip.checked_pow(k)?)
+    })
+}
+
+/// The units other than a fixed unit r, partitioned by their inner product with r.
+/// Every class holds unit indices in increasing order, and the class sizes are 1, 56, 126, 56 and 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitPartition {
+    /// The units u with ⟨r, u⟩ = 2, which is r alone.
+    pub equal: Vec<u16>,
+    /// The units u with ⟨r, u⟩ = 1.
+    pub acute: Vec<u16>,
+    /// The units u with ⟨r, u⟩ = 0.
+    pub orthogonal: Vec<u16>,
+    /// The units u with ⟨r, u⟩ = -1.
+    pub obtuse: Vec<u16>,
+    /// The units u with ⟨r, u⟩ = -2, which is -r alone.
+    pub opposite: Vec<u16>,
+}
+
+impl UnitPartition {
+    /// Returns the five classes in order of decreasing inner product, from 2 down to -2.
+    pub fn classes(&self) -> [&[u16]; 5] {
+        [
+            &self.equal,
+            &self.acute,
+            &self.orthogonal,
+            &self.obtuse,
+            &self.opposite,
+        ]
+    }
+}
+
+/// Partitions the units by their inner product with `r`, which lies in {2, 1, 0, -1, -2} since the units are the roots of E8.
+/// The class of inner product 1 is the neighbourhood of r in the Gosset graph on the 240 roots.
+/// Returns `AlcoError::NotAUnit` if `r` is not a unit octavian.
+pub fn unit_neighbors(r: &Octavian<i8>) -> Result<UnitPartition, AlcoError> {
+    r.unit_index().ok_or(AlcoError::NotAUnit)?;
+    let mut partition = UnitPartition {
+        equal: Vec::new(),
+        acute: Vec::new(),
+        orthogonal: Vec::new(),
+        obtuse: Vec::new(),
+        opposite: Vec::new(),
+    };
+    for (i, u) in Octavian::UNITS.iter().enumerate() {
+        let class = match r.inner_product(u) {
+            2 => &mut partition.equal,
+            1 => &mut partition.acute,
+            0 => &mut partition.orthogonal,
+            -1 => &mut partition.obtuse,
+            -2 => &mut partition.opposite,
+            p => unreachable!("two roots of E8 cannot have inner product {p}"),
+        };
+        class.push(i as u16);
+    }
+    Ok(partition)
+}
+
+/// Tests whether a map of unit indices is a loop homomorphism, that is f(u·v) = f(u)·f(v) for all units u and v.
+pub fn is_loop_homomorphism(map: &[u16; 240]) -> bool {
+    let table = unit_multiplication_table();
+    (0..240).all(|u| {
+        (0..240).all(|v| map[table[u][v] as usize] == table[map[u] as usize][map[v] as usize])
+    })
+}
+
+/// Returns the order of each element of a loop given by its Cayley table, or `None` if some powers never reach the identity.
+/// Powers are taken as x^(n+1) = x^n·x, which is unambiguous for power-associative loops.
+fn element_orders(table: &[[u16; 240]; 240], identity: u16) -> Option<[u32; 240]> {
+    let mut orders = [0u32; 240];
+    for (x, order) in orders.iter_mut().enumerate() {
+        let mut power = x as u16;
+        *order = 1;
+        while power != identity {
+            power = table[power as usize][x];
+            *order += 1;
+            if *order > 240 {
+                return None;
+            }
+        }
+    }
+    Some(orders)
+}
+
+/// Returns, for each element of a loop, its order together with the number of elements it commutes with.
+/// An isomorphism must preserve these invariants, which prunes the search for one.
+fn element_invariants(table: &[[u16; 240]; 240], identity: u16) -> Option<[(u32, usize); 240]> {
+    let orders = element_orders(table, identity)?;
+    Some(core::array::from_fn(|x| {
+        let commuting = (0..240).filter(|&y| table[x][y] == table[y][x]).count();
+        (orders[x], commuting)
+    }))
+}
+
+/// A partial map from the unit loop to another loop, extended to the subloop generated by its domain.
+#[derive(Clone)]
+struct PartialIsomorphism<'a> {
+    other: &'a [[u16; 240]; 240],
+    map: [Option<u16>; 240],
+    used: [bool; 240],
+    domain: Vec<u16>,
+}
+
+impl PartialIsomorphism<'_> {
+    /// Sends `x` to `y` and closes the map under products, returning `false` on any conflict with injectivity or multiplication.
+    fn extend(&mut self, x: u16, y: u16) -> bool {
+        let table = unit_multiplication_table();
+        let mut queue = vec![(x, y)];
+        while let Some((x, y)) = queue.pop() {
+            match self.map[x as usize] {
+                Some(z) if z == y => continue,
+                Some(_) => return false,
+                None if self.used[y as usize] => return false,
+                None => {}
+            }
+            self.map[x as usize] = Some(y);
+            self.used[y as usize] = true;
+            self.domain.push(x);
+            for &a in &self.domain {
+                let b = self.map[a as usize].unwrap();
+                queue.push((
+                    table[x as usize][a as usize],
+                    self.other[y as usize][b as usize],
+                ));
+                queue.push((
+                    table[a as usize][x as usize],
+                    self.other[b as usize][y as usize],
+                ));
+            }
+        }
+        true
+    }
+}
+
+/// Tests whether the given entries are exactly the indices 0..240, each appearing once.
+fn is_permutation(entries: impl Iterator<Item = u16>) -> bool {
+    let mut seen = [false; 240];
+    let mut count = 0;
+    for x in entries {
+        if x >= 240 || std::mem::replace(&mut seen[x as usize], true) {
+            return false;
+        }
+        count += 1;
+    }
+    count == 240
+}
+
+/// Searches for an isomorphism from the unit loop to the loop with the given Cayley table on indices 0..240.
+///
+/// The unit loop is generated by a few units, and an isomorphism is determined by their images.
+/// Images are chosen by backtracking among the elements with the same order and commuting count,
+/// and each choice is closed under products so that conflicts are detected as early as possible.
+/// Tables that are not Latin squares, or whose invariants differ from those of the unit loop, are rejected without searching.
+pub fn find_isomorphism(other_table: &[[u16; 240]; 240]) -> Option<[u16; 240]> {
+    let table = unit_multiplication_table();
+    for i in 0..240 {
+        if !is_permutation(other_table[i].iter().copied())
+            || !is_permutation(other_table.iter().map(|row| row[i]))
+        {
+            return None;
+        }
+    }
+    let other_identity = (0..240u16).find(|&e| {
+        (0..240u16).all(|x| {
+            other_table[e as usize][x as usize] == x && other_table[x as usize][e as usize] == x
+        })
+    })?;
+
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let invariants =
+        element_invariants(table, identity).expect("the unit loop is power-associative");
+    let other_invariants = element_invariants(other_table, other_identity)?;
+    let mut spectrum = invariants;
+    let mut other_spectrum = other_invariants;
+    spectrum.sort_unstable();
+    other_spectrum.sort_unstable();
+    if spectrum != other_spectrum {
+        return None;
+    }
+
+    // Greedily choose generators, each enlarging the subloop generated by the previous ones.
+    let mut generators = Vec::new();
+    let mut generated = subloop_indices(&generators);
+    while generated.len() < 240 {
+        let g = (0..240u16)
+            .filter(|x| generated.binary_search(x).is_err())
+            .max_by_key(|&x| subloop_indices(&[generators.as_slice(), &[x]].concat()).len())
+            .unwrap();
+        generators.push(g);
+        generated = subloop_indices(&generators);
+    }
+
+    let mut start = PartialIsomorphism {
+        other: other_table,
+        map: [None; 240],
+        used: [false; 240],
+        domain: Vec::new(),
+    };
+    if !start.extend(identity, other_identity) {
+        return None;
+    }
+    let map = search_isomorphism(&start, &generators, &invariants, &other_invariants)?;
+    Some(map.map(|y| y.expect("the generators generate the whole loop")))
+}
+
+/// Chooses images for the remaining generators by depth-first search, returning the first complete map found.
+fn search_isomorphism(
+    partial: &PartialIsomorphism,
+    generators: &[u16],
+    invariants: &[(u32, usize); 240],
+    other_invariants: &[(u32, usize); 240],
+) -> Option<[Option<u16>; 240]> {
+    let Some((&g, rest)) = generators.split_first() else {
+        return Some(partial.map);
+    };
+    (0..240u16)
+        .filter(|&y| {
+            !partial.used[y as usize] && other_invariants[y as usize] == invariants[g as usize]
+        })
+        .find_map(|y| {
+            let mut next = partial.clone();
+            if next.extend(g, y) {
+                search_isomorphism(&next, rest, invariants, other_invariants)
+            } else {
+                None
+            }
+        })
+}
+
+/// Returns the units u with u·u = `target` in the order of `Octavian::UNITS`, or an empty list if `target` is not a unit.
+pub fn square_roots_of(target: &Octavian<i8>) -> Vec<Octavian<i8>> {
+    let Some(t) = target.unit_index() else {
+        return Vec::new();
+    };
+    let table = unit_multiplication_table();
+    (0..240)
+        .filter(|&u| table[u][u] == t)
+        .map(|u| Octavian::UNITS[u])
+        .collect()
+}
+
+/// Returns the octavians y with y·y = `target` and norm at most `bound`, in increasing order of their coefficients.
+/// Since the norm is multiplicative, a square root must have norm sqrt(N(target)), so a single norm shell is searched.
+pub fn square_roots_with_norm_at_most(target: &Octavian<i64>, bound: i64) -> Vec<Octavian<i64>> {
+    let norm = target.norm();
+    let n = norm.isqrt();
+    if n * n != norm || n > bound {
+        return Vec::new();
+    }
+    norm_shell(n)
+        .into_iter()
+        .filter(|y| *y * *y == *target)
+        .collect()
+}
+
+/// Returns the exponent of the unit loop, the least common multiple of the orders of the units.
+pub fn loop_exponent() -> u32 {
+    (0..240).map(order_of).fold(1, num::integer::lcm)
+}
+
+/// Returns the map u ↦ u^k on unit indices, which is well defined because the unit loop is power-associative.
+/// The map is a permutation exactly when k is coprime to `loop_exponent()`.
+pub fn power_map(k: u32) -> [u16; 240] {
+    let table = unit_multiplication_table();
+    let identity = Octavian::<i8>::one().unit_index().unwrap();
+    let map: [u16; 240] = core::array::from_fn(|u| {
+        let mut power = identity;
+        for _ in 0..k % order_of(u as u16) {
+            power = table[power as usize][u];
+        }
+        power
+    });
+    debug_assert!(
+        num::integer::gcd(k, loop_exponent()) != 1 || is_permutation(map.iter().copied()),
+        "a power map coprime to the exponent must be a bijection"
+    );
+    map
+}
+
+/// Groups the units by their k-th powers, as pairs of a k-th power and the units with that power, ordered by index.
+pub fn kth_power_classes(k: u32) -> Vec<(u16, Vec<u16>)> {
+    let map = power_map(k);
+    let mut powers = map.to_vec();
+    powers.sort_unstable();
+    powers.dedup();
+    powers
+        .into_iter()
+        .map(|p| (p, (0..240).filter(|&u| map[u as usize] == p).collect()))
+        .collect()
+}
+
+/// Partitions the units into orbits under the inner mapping group of the unit loop, ordered by their least element.
+///
+/// The inner mapping group is generated by the maps fixing the identity that come from translations:
+/// the left maps x ↦ (vu)⁻¹(v(ux)), the right maps x ↦ ((xu)v)(uv)⁻¹ and the conjugations x ↦ u⁻¹(xu).
+/// The orbits are the connected components of the graph joining each x to its images, found with a union-find.
+pub fn unit_conjugacy_classes() -> Vec<Vec<u16>> {
+    let t = unit_multiplication_table();
+    let inv = |x: u16| inverse_index(x) as usize;
+    let mut parent: [u16; 240] = core::array::from_fn(|i| i as u16);
+    fn find(parent: &mut [u16; 240], mut x: u16) -> u16 {
+        while parent[x as usize] != x {
+            parent[x as usize] = parent[parent[x as usize] as usize];
+            x = parent[x as usize];
+        }
+        x
+    }
+    for u in 0..240 {
+        for x in 0..240 {
+            let conjugate = t[inv(u as u16)][t[x][u] as usize];
+            let (a, b) = (find(&mut parent, x as u16), find(&mut parent, conjugate));
+            parent[a.max(b) as usize] = a.min(b);
+        }
+        for v in 0..240 {
+            let vu = inv(t[v][u]);
+            let uv = inv(t[u][v]);
+            for x in 0..240 {
+                let left = t[vu][t[v][t[u][x] as usize] as usize];
+                let right = t[t[t[x][u] as usize][v] as usize][uv];
+                for y in [left, right] {
+                    let (a, b) = (find(&mut parent, x as u16), find(&mut parent, y));
+                    parent[a.max(b) as usize] = a.min(b);
+                }
+            }
+        }
+    }
+    let mut classes: Vec<Vec<u16>> = Vec::new();
+    let mut class_of = [usize::MAX; 240];
+    for x in 0..240u16 {
+        let root = find(&mut parent, x) as usize;
+        if class_of[root] == usize::MAX {
+            class_of[root] = classes.len();
+            classes.push(Vec::new());
+        }
+        classes[class_of[root]].push(x);
+    }
+    classes
+}
+
+/// Tests whether the units with indices `u` and `v` generate a group, that is an associative subloop.
+/// Returns `AlcoError::IndexOutOfRange` if either index is not below 240.
+pub fn pair_generates_group(u: u16, v: u16) -> Result<bool, AlcoError> {
+    let subloop = generated_subloop_indices(&[u, v])?;
+    Ok(is_associative_in(unit_multiplication_table(), &subloop))
+}
+
+/// Verifies that the unit loop is diassociative, meaning that every pair of units generates a group.
+pub fn verify_diassociativity() -> bool {
+    verify_diassociativity_in(unit_multiplication_table())
+}
+
+/// Verifies diassociativity for the given multiplication table on the unit indices.
+/// Once the subloop generated by a pair is known to be a group, every pair inside it generates a subgroup of it,
+/// so those pairs are marked as covered and skipped.
+pub(crate) fn verify_diassociativity_in(table: &[[u16; 240]; 240]) -> bool {
+    let mut covered = vec![[false; 240]; 240];
+    for u in 0..240u16 {
+        for v in u + 1..240 {
+            if covered[u as usize][v as usize] {
+                continue;
+            }
+            let subloop = generated_subloop_in(table, &[u, v]);
+            if !is_associative_in(table, &subloop) {
+                return false;
+            }
+            for &x in &subloop {
+                for &y in &subloop {
+                    covered[x as usize][y as usize] = true;
+                }
+            }
+        }
+    }
+    true
+}
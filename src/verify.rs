@@ -0,0 +1,207 @@
+//! A self-check of the constant tables, to run after regenerating them.
+//! Every check works from the tables alone: products are computed with the adjoint matrices,
+//! norms with the Gram matrix, and conjugation as trace(x) one() - x with the trace read from the Gram matrix as <x, one()>.
+
+use crate::lattice::determinant;
+use crate::octavian::Octavian;
+use core::fmt;
+use std::collections::{HashMap, HashSet};
+
+type Coefficients = [i64; 8];
+
+/// A check of `verify_tables` that failed, with the entries or indices where it failed.
+/// Unit indices refer to `Octavian::OCTAVIAN_UNITS_COEFFICIENTS` and basis indices to the simple roots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TableDefect {
+    /// Entries `[row][column]` and `[column][row]` of the Gram matrix differ, with `row < column`.
+    GramNotSymmetric { row: usize, column: usize },
+    /// The Gram matrix has this determinant instead of 1.
+    GramDeterminant(i128),
+    /// `one() * b` differs from the basis vector `b`.
+    NotLeftIdentity { basis_index: usize },
+    /// `b * one()` differs from the basis vector `b`.
+    NotRightIdentity { basis_index: usize },
+    /// The unit has inner product `inner_product` with itself instead of 2.
+    UnitNorm { index: usize, inner_product: i64 },
+    /// Two units have the same coefficients, with `first < second`.
+    DuplicateUnit { first: usize, second: usize },
+    /// The product of two units is not in the unit table.
+    ProductNotUnit { left: usize, right: usize },
+    /// The negative of a unit is not in the unit table.
+    NegationNotUnit { index: usize },
+    /// Conjugating the basis vector twice does not give it back.
+    ConjugateNotInvolution { basis_index: usize },
+    /// conj(a b) differs from conj(b) conj(a) for two basis vectors a and b.
+    ConjugateNotAntiHomomorphism { left: usize, right: usize },
+}
+
+impl fmt::Display for TableDefect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableDefect::GramNotSymmetric { row, column } => {
+                write!(
+                    f,
+                    "Gram matrix entries [{0}][{1}] and [{1}][{0}] differ",
+                    row, column
+                )
+            }
+            TableDefect::GramDeterminant(d) => write!(f, "Gram matrix has determinant {}", d),
+            TableDefect::NotLeftIdentity { basis_index } => {
+                write!(f, "one() * b{} != b{}", basis_index, basis_index)
+            }
+            TableDefect::NotRightIdentity { basis_index } => {
+                write!(f, "b{} * one() != b{}", basis_index, basis_index)
+            }
+            TableDefect::UnitNorm {
+                index,
+                inner_product,
+            } => write!(f, "unit {} has <u, u> = {}", index, inner_product),
+            TableDefect::DuplicateUnit { first, second } => {
+                write!(f, "units {} and {} are equal", first, second)
+            }
+            TableDefect::ProductNotUnit { left, right } => {
+                write!(
+                    f,
+                    "the product of units {} and {} is not a unit",
+                    left, right
+                )
+            }
+            TableDefect::NegationNotUnit { index } => {
+                write!(f, "the negative of unit {} is not a unit", index)
+            }
+            TableDefect::ConjugateNotInvolution { basis_index } => {
+                write!(f, "conj(conj(b{})) != b{}", basis_index, basis_index)
+            }
+            TableDefect::ConjugateNotAntiHomomorphism { left, right } => {
+                write!(f, "conj(b{0} b{1}) != conj(b{1}) conj(b{0})", left, right)
+            }
+        }
+    }
+}
+
+/// Checks the constant tables on `Octavian`: the Gram matrix is symmetric with determinant 1,
+/// `one()` is a two-sided identity for the product given by the adjoint matrices,
+/// the units have inner product 2 with themselves, are distinct, and are closed under multiplication and negation,
+/// and conjugation, with the trace taken from the Gram matrix, is an involution and an anti-homomorphism on the basis.
+/// Returns every failed check. Without the `tables` feature there is no unit table and the unit checks are skipped.
+pub fn verify_tables() -> Result<(), Vec<TableDefect>> {
+    #[cfg(feature = "tables")]
+    let units: &[[i8; 8]] = &Octavian::<i8>::OCTAVIAN_UNITS_COEFFICIENTS;
+    #[cfg(not(feature = "tables"))]
+    let units: &[[i8; 8]] = &[];
+    verify_tables_with(
+        &Octavian::<i8>::GRAM_MATRIX,
+        &Octavian::<i8>::OCTAVIAN_ADJOINT_MATRICES,
+        units,
+    )
+}
+
+/// Runs the checks of `verify_tables` on the given tables.
+pub(crate) fn verify_tables_with(
+    gram: &[[i8; 8]; 8],
+    adjoint: &[[[i8; 8]; 8]; 8],
+    units: &[[i8; 8]],
+) -> Result<(), Vec<TableDefect>> {
+    let mut defects = Vec::new();
+    let mul = |x: &Coefficients, y: &Coefficients| -> Coefficients {
+        let mut product = [0; 8];
+        for (k, matrix) in adjoint.iter().enumerate() {
+            for (p, row) in product.iter_mut().zip(matrix) {
+                for (j, &a) in row.iter().enumerate() {
+                    *p += x[k] * i64::from(a) * y[j];
+                }
+            }
+        }
+        product
+    };
+    let inner_product = |x: &Coefficients, y: &Coefficients| -> i64 {
+        let mut sum = 0;
+        for (i, row) in gram.iter().enumerate() {
+            for (j, &g) in row.iter().enumerate() {
+                sum += i64::from(g) * x[i] * y[j];
+            }
+        }
+        sum
+    };
+    let one = Octavian::<i64>::one().coefficients;
+    // The trace form gives trace(x) = <x, one()>, so conjugation is an involution exactly when <one(), one()> = 2.
+    let conjugate = |x: &Coefficients| -> Coefficients {
+        let trace = inner_product(x, &one);
+        core::array::from_fn(|i| trace * one[i] - x[i])
+    };
+    let basis: [Coefficients; 8] =
+        core::array::from_fn(|i| core::array::from_fn(|j| i64::from(i == j)));
+
+    for (row, entries) in gram.iter().enumerate() {
+        for (column, &g) in entries.iter().enumerate().skip(row + 1) {
+            if g != gram[column][row] {
+                defects.push(TableDefect::GramNotSymmetric { row, column });
+            }
+        }
+    }
+    let d = determinant(&gram.map(|row| row.map(i64::from)));
+    if d != 1 {
+        defects.push(TableDefect::GramDeterminant(d));
+    }
+
+    for (basis_index, b) in basis.iter().enumerate() {
+        if mul(&one, b) != *b {
+            defects.push(TableDefect::NotLeftIdentity { basis_index });
+        }
+        if mul(b, &one) != *b {
+            defects.push(TableDefect::NotRightIdentity { basis_index });
+        }
+    }
+
+    let units: Vec<Coefficients> = units.iter().map(|u| u.map(i64::from)).collect();
+    for (index, u) in units.iter().enumerate() {
+        let norm = inner_product(u, u);
+        if norm != 2 {
+            defects.push(TableDefect::UnitNorm {
+                index,
+                inner_product: norm,
+            });
+        }
+    }
+    let mut first_index = HashMap::new();
+    for (second, u) in units.iter().enumerate() {
+        if let Some(&first) = first_index.get(u) {
+            defects.push(TableDefect::DuplicateUnit { first, second });
+        } else {
+            first_index.insert(*u, second);
+        }
+    }
+    let unit_set: HashSet<Coefficients> = units.iter().copied().collect();
+    for (left, a) in units.iter().enumerate() {
+        for (right, b) in units.iter().enumerate() {
+            if !unit_set.contains(&mul(a, b)) {
+                defects.push(TableDefect::ProductNotUnit { left, right });
+            }
+        }
+    }
+    for (index, u) in units.iter().enumerate() {
+        if !unit_set.contains(&u.map(|c| -c)) {
+            defects.push(TableDefect::NegationNotUnit { index });
+        }
+    }
+
+    for (basis_index, b) in basis.iter().enumerate() {
+        if conjugate(&conjugate(b)) != *b {
+            defects.push(TableDefect::ConjugateNotInvolution { basis_index });
+        }
+    }
+    for (left, a) in basis.iter().enumerate() {
+        for (right, b) in basis.iter().enumerate() {
+            if conjugate(&mul(a, b)) != mul(&conjugate(b), &conjugate(a)) {
+                defects.push(TableDefect::ConjugateNotAntiHomomorphism { left, right });
+            }
+        }
+    }
+
+    if defects.is_empty() {
+        Ok(())
+    } else {
+        Err(defects)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::octavian::{from_i8, inner_product_of, Coefficient, Octavian};
+use core::ops::{Mul, Neg};
+use num_traits::{FromPrimitive, Num};
+
+/// A borrowed view of the coefficients of an octavian, for data that already lives in a buffer.
+/// The norm and inner product read the borrowed coefficients in place; products are returned as owned octavians.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OctavianRef<'a, T> {
+    coefficients: &'a [T; 8],
+}
+
+impl<'a, T> OctavianRef<'a, T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Create a new `OctavianRef` borrowing the given E8 lattice coordinates.
+    pub fn new(coefficients: &'a [T; 8]) -> Self {
+        OctavianRef { coefficients }
+    }
+
+    /// Returns the borrowed coefficients.
+    pub fn coefficients(&self) -> &'a [T; 8] {
+        self.coefficients
+    }
+
+    /// Returns an owned copy of the octavian.
+    pub fn to_octavian(&self) -> Octavian<T> {
+        Octavian::new(*self.coefficients)
+    }
+
+    /// Returns the inner product with another view, as `Octavian::inner_product` does.
+    pub fn inner_product(&self, rhs: &OctavianRef<'_, T>) -> T {
+        inner_product_of(self.coefficients, rhs.coefficients)
+    }
+
+    /// Returns the norm, as `Octavian::norm` does.
+    pub fn norm(&self) -> T {
+        inner_product_of(self.coefficients, self.coefficients) / from_i8(2)
+    }
+}
+
+/// Multiplies two views into an owned octavian.
+impl<T> Mul for OctavianRef<'_, T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    type Output = Octavian<T>;
+
+    fn mul(self, rhs: Self) -> Octavian<T> {
+        let mut product = Octavian::zero();
+        self.to_octavian()
+            .mul_into(&rhs.to_octavian(), &mut product);
+        product
+    }
+}
+
+impl<T> PartialEq<Octavian<T>> for OctavianRef<'_, T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn eq(&self, other: &Octavian<T>) -> bool {
+        *self.coefficients == other.coefficients
+    }
+}
+
+impl<T> PartialEq<OctavianRef<'_, T>> for Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    fn eq(&self, other: &OctavianRef<'_, T>) -> bool {
+        self.coefficients == *other.coefficients
+    }
+}
+
+impl<T> Octavian<T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Returns a borrowed view of the octavian.
+    pub fn as_ref_view(&self) -> OctavianRef<'_, T> {
+        OctavianRef::new(&self.coefficients)
+    }
+}
+
+/// A borrowed view of a flat buffer of coefficients as a sequence of octavians, eight coefficients each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OctavianSlice<'a, T> {
+    data: &'a [T],
+}
+
+impl<'a, T> OctavianSlice<'a, T>
+where
+    T: FromPrimitive + Num + Copy + Neg<Output = T> + Coefficient,
+{
+    /// Create a new `OctavianSlice` over a flat buffer of coefficients.
+    /// Returns `None` if the length of the buffer is not a multiple of 8.
+    pub fn new(data: &'a [T]) -> Option<Self> {
+        data.len()
+            .is_multiple_of(8)
+            .then_some(OctavianSlice { data })
+    }
+
+    /// Returns the number of octavians in the buffer.
+    pub fn len(&self) -> usize {
+        self.data.len() / 8
+    }
+
+    /// Tests whether the buffer holds no octavians.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns a view of the i-th octavian, or `None` if the index is out of range.
+    pub fn get(&self, i: usize) -> Option<OctavianRef<'a, T>> {
+        let start = i.checked_mul(8)?;
+        let chunk = self.data.get(start..start.checked_add(8)?)?;
+        Some(OctavianRef::new(chunk.try_into().ok()?))
+    }
+
+    /// Returns an iterator over views of the octavians in the buffer.
+    pub fn iter(&self) -> impl Iterator<Item = OctavianRef<'a, T>> {
+        self.data
+            .chunks_exact(8)
+            .map(|chunk| OctavianRef::new(chunk.try_into().expect("the chunks have length 8")))
+    }
+}
@@ -0,0 +1,96 @@
+use crate::octavian::Octavian;
+use crate::tables::unit_multiplication_table;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+
+/// A random walk on the unit loop by left multiplication, x ↦ g·x, with each step's unit g drawn from a weighted generator set.
+/// As an iterator, it yields the unit index reached after each step, starting from the identity.
+#[derive(Debug, Clone)]
+pub struct UnitWalk<R> {
+    rng: R,
+    generators: Vec<u16>,
+    weights: Vec<f64>,
+    distribution: WeightedIndex<f64>,
+    position: u16,
+}
+
+impl<R: Rng> UnitWalk<R> {
+    /// Create a new `UnitWalk` choosing `generators[i]` with probability proportional to `weights[i]`.
+    /// Returns `None` if the lengths differ, some generator is not a unit index, or the weights are not a valid distribution.
+    pub fn new(rng: R, generators: &[u16], weights: &[f64]) -> Option<Self> {
+        if generators.len() != weights.len() || generators.iter().any(|&g| g >= 240) {
+            return None;
+        }
+        let distribution = WeightedIndex::new(weights).ok()?;
+        Some(UnitWalk {
+            rng,
+            generators: generators.to_vec(),
+            weights: weights.to_vec(),
+            distribution,
+            position: Octavian::<i8>::one().unit_index().unwrap(),
+        })
+    }
+
+    /// Create a new `UnitWalk` choosing each of the generators with equal probability.
+    pub fn uniform(rng: R, generators: &[u16]) -> Option<Self> {
+        Self::new(rng, generators, &vec![1.0; generators.len()])
+    }
+
+    /// Returns the unit index the walk has currently reached.
+    pub fn position(&self) -> u16 {
+        self.position
+    }
+
+    /// Returns the walk to the identity.
+    pub fn restart(&mut self) {
+        self.position = Octavian::<i8>::one().unit_index().unwrap();
+    }
+
+    /// Returns the exact distribution of the position after the given number of steps from the identity.
+    pub fn distribution_after(&self, steps: usize) -> [f64; 240] {
+        let table = unit_multiplication_table();
+        let total: f64 = self.weights.iter().sum();
+        let mut p = [0.0; 240];
+        p[Octavian::<i8>::one().unit_index().unwrap() as usize] = 1.0;
+        for _ in 0..steps {
+            let mut next = [0.0; 240];
+            for (x, &px) in p.iter().enumerate() {
+                for (&g, &w) in self.generators.iter().zip(&self.weights) {
+                    next[table[g as usize][x] as usize] += px * w / total;
+                }
+            }
+            p = next;
+        }
+        p
+    }
+
+    /// Estimates the total-variation distance to the uniform distribution after the given number of steps,
+    /// from the empirical distribution of the endpoints of `trials` walks started at the identity.
+    /// The estimate is biased upwards by sampling noise, which shrinks as the number of trials grows.
+    pub fn mixing_statistics(&mut self, steps: usize, trials: usize) -> f64 {
+        let mut counts = [0usize; 240];
+        for _ in 0..trials {
+            self.restart();
+            for _ in 0..steps {
+                self.next();
+            }
+            counts[self.position as usize] += 1;
+        }
+        self.restart();
+        counts
+            .iter()
+            .map(|&c| (c as f64 / trials as f64 - 1.0 / 240.0).abs())
+            .sum::<f64>()
+            / 2.0
+    }
+}
+
+impl<R: Rng> Iterator for UnitWalk<R> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        let g = self.generators[self.distribution.sample(&mut self.rng)];
+        self.position = unit_multiplication_table()[g as usize][self.position as usize];
+        Some(self.position)
+    }
+}
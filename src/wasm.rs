@@ -0,0 +1,72 @@
+//! `wasm-bindgen` wrappers for use from JavaScript, behind the `wasm` feature.
+//! Coefficients cross the boundary as JavaScript numbers, so they are exact up to 2^53 in magnitude.
+
+use crate::lattice;
+use crate::octavian::Octavian;
+use crate::units;
+use wasm_bindgen::prelude::*;
+
+/// An octavian with `i64` E8 lattice coordinates, exported to JavaScript.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmOctavian {
+    inner: Octavian<i64>,
+}
+
+impl From<Octavian<i64>> for WasmOctavian {
+    fn from(inner: Octavian<i64>) -> Self {
+        WasmOctavian { inner }
+    }
+}
+
+impl From<WasmOctavian> for Octavian<i64> {
+    fn from(x: WasmOctavian) -> Self {
+        x.inner
+    }
+}
+
+#[wasm_bindgen]
+impl WasmOctavian {
+    /// Creates an octavian from an array of 8 E8 lattice coordinates,
+    /// or returns `undefined` unless there are exactly 8 coordinates and all of them are safe integers.
+    #[wasm_bindgen(js_name = fromArray)]
+    pub fn from_array(coefficients: &[f64]) -> Option<WasmOctavian> {
+        const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+        let coefficients: [f64; 8] = coefficients.try_into().ok()?;
+        coefficients
+            .iter()
+            .all(|c| c.fract() == 0.0 && c.abs() <= MAX_SAFE_INTEGER)
+            .then(|| Octavian::new(coefficients.map(|c| c as i64)).into())
+    }
+
+    /// Returns the E8 lattice coordinates.
+    pub fn coefficients(&self) -> Vec<f64> {
+        self.inner.coefficients.map(|c| c as f64).to_vec()
+    }
+
+    /// Returns the product with another octavian.
+    pub fn mul(&self, rhs: &WasmOctavian) -> WasmOctavian {
+        (self.inner * rhs.inner).into()
+    }
+
+    /// Returns the norm.
+    pub fn norm(&self) -> f64 {
+        self.inner.norm() as f64
+    }
+
+    /// Returns the unit nearest to the octavian, with ties broken as in `units::nearest_unit_exact`,
+    /// or `undefined` if its norm is too large to compare exactly.
+    #[wasm_bindgen(js_name = nearestUnit)]
+    pub fn nearest_unit(&self) -> Option<WasmOctavian> {
+        let (unit, _) = units::nearest_unit_exact(&self.inner)?;
+        Some(Octavian::new(unit.coefficients.map(i64::from)).into())
+    }
+}
+
+/// Returns an octavian nearest to the point with the given 8 E8 lattice coordinates, as `lattice::closest_octavian` does,
+/// or `undefined` if there are not exactly 8 finite coordinates.
+#[wasm_bindgen(js_name = cvpDecode)]
+pub fn cvp_decode(point: &[f64]) -> Option<WasmOctavian> {
+    let point: [f64; 8] = point.try_into().ok()?;
+    lattice::closest_octavian(&Octavian::new(point)).map(WasmOctavian::from)
+}
@@ -0,0 +1,417 @@
+use crate::dedup::Dedup;
+use crate::error::AlcoError;
+use crate::lattice::{determinant, hermite_normal_form, norm_shell};
+use crate::octavian::Octavian;
+use crate::progress::{Enumeration, Progress};
+use crate::tables;
+use core::ops::ControlFlow;
+
+/// An automorphism of the E8 lattice, given by an integer matrix preserving the Gram matrix.
+/// The matrix acts on row vectors of E8 lattice coordinates, so that x is sent to x·M.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct WeylElement {
+    matrix: [[i64; 8]; 8],
+}
+
+impl WeylElement {
+    /// Create a new `WeylElement` from a matrix whose rows are the images of the basis vectors.
+    /// Returns `None` unless the matrix preserves the Gram matrix, that is M G Mᵀ = G.
+    pub fn new(matrix: [[i64; 8]; 8]) -> Option<Self> {
+        let gram = tables::gram_matrix_i64();
+        for i in 0..8 {
+            for j in 0..8 {
+                let mut entry = 0;
+                for a in 0..8 {
+                    for b in 0..8 {
+                        entry += matrix[i][a] * gram[a][b] * matrix[j][b];
+                    }
+                }
+                if entry != gram[i][j] {
+                    return None;
+                }
+            }
+        }
+        Some(WeylElement { matrix })
+    }
+
+    /// The identity automorphism.
+    pub fn identity() -> Self {
+        let mut matrix = [[0i64; 8]; 8];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1;
+        }
+        WeylElement { matrix }
+    }
+
+    /// Returns the reflection x ↦ x - ⟨x, a⟩ a in the hyperplane orthogonal to a root, or `None` if `a` is not a unit.
+    pub fn reflection(a: &Octavian<i8>) -> Option<Self> {
+        a.unit_index()?;
+        let a = Octavian::new(a.coefficients.map(i64::from));
+        let matrix = Octavian::<i64>::basis_vectors()
+            .map(|b| (b - a.scale(b.inner_product(&a))).coefficients);
+        Some(WeylElement { matrix })
+    }
+
+    /// Returns the reflections in the eight simple roots, the basis vectors, which generate the Weyl group of E8.
+    pub fn simple_reflections() -> [Self; 8] {
+        Octavian::<i8>::basis_vectors()
+            .map(|b| Self::reflection(&b).expect("the simple roots are units"))
+    }
+
+    /// Returns the matrix of the automorphism, whose rows are the images of the basis vectors.
+    pub fn matrix(&self) -> &[[i64; 8]; 8] {
+        &self.matrix
+    }
+
+    /// Applies the automorphism to an octavian.
+    pub fn apply(&self, x: &Octavian<i64>) -> Octavian<i64> {
+        let mut coefficients = [0i64; 8];
+        for (j, c) in coefficients.iter_mut().enumerate() {
+            *c = (0..8).map(|i| x.coefficients[i] * self.matrix[i][j]).sum();
+        }
+        Octavian::new(coefficients)
+    }
+
+    /// Returns the composition applying `self` first and then `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        let mut matrix = [[0i64; 8]; 8];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = (0..8).map(|k| self.matrix[i][k] * other.matrix[k][j]).sum();
+            }
+        }
+        WeylElement { matrix }
+    }
+
+    /// Returns the inverse automorphism, G Mᵀ G⁻¹ since M preserves the Gram matrix G.
+    pub fn inverse(&self) -> Self {
+        let mut transpose = [[0i64; 8]; 8];
+        for (i, row) in transpose.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = self.matrix[j][i];
+            }
+        }
+        let g = WeylElement {
+            matrix: *tables::gram_matrix_i64(),
+        };
+        let t = WeylElement { matrix: transpose };
+        let h = WeylElement {
+            matrix: *tables::inverse_gram_matrix_i64(),
+        };
+        g.then(&t).then(&h)
+    }
+
+    /// Returns the permutation of unit indices induced by the automorphism, which maps roots to roots.
+    pub fn unit_permutation(&self) -> [u16; 240] {
+        core::array::from_fn(|i| {
+            let u = Octavian::new(Octavian::UNITS[i].coefficients.map(i64::from));
+            let image = self.apply(&u);
+            Octavian::new(image.coefficients.map(|c| c as i8))
+                .unit_index()
+                .expect("lattice automorphisms permute the roots")
+        })
+    }
+
+    /// Returns the determinant of the matrix, which is ±1.
+    pub fn det(&self) -> i8 {
+        if determinant(&self.matrix) == 1 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Writes the automorphism as a shortest product of simple reflections, returning the indices of the simple roots
+    /// in the order the reflections are applied, so that composing them with `then` gives back `self`.
+    /// Reflections are peeled off while some simple root is sent to a negative root, each step shortening the word by one,
+    /// so the word has the length of the element, at most 120, the length of -1.
+    pub fn simple_reflection_word(&self) -> Vec<u8> {
+        let simple = Self::simple_reflections();
+        let mut w = *self;
+        let mut word = Vec::new();
+        while let Some(i) = (0..8).find(|&i| w.matrix[i].iter().all(|&c| c <= 0)) {
+            // The row is the image of the simple root b_i, so w s_i is shorter than w, and s_i is applied first.
+            w = simple[i].then(&w);
+            word.push(i as u8);
+        }
+        debug_assert_eq!(w, Self::identity());
+        word
+    }
+
+    /// Returns the dimension of the space the automorphism moves, the rank of M - I.
+    pub fn moved_rank(&self) -> usize {
+        let rows: Vec<[i64; 8]> = (0..8)
+            .map(|i| {
+                let mut row = self.matrix[i];
+                row[i] -= 1;
+                row
+            })
+            .collect();
+        hermite_normal_form(&rows).len()
+    }
+
+    /// Returns the spinor norm for the quadratic form ⟨x, x⟩, as the squarefree representative 1 or 2 of its class
+    /// in Q*/Q*²: the product of ⟨r, r⟩ over the roots r of `reflection_decomposition`, taken modulo squares.
+    /// Every root has ⟨r, r⟩ = 2, so the spinor norm is 2 exactly when the number of reflections is odd,
+    /// that is when det is -1. On the Weyl group of E8 the pair (det, spinor norm) therefore takes only
+    /// the values (1, 1) and (-1, 2); -1 is the product of 8 reflections and has det 1 and spinor norm 1.
+    pub fn spinor_norm(&self) -> i8 {
+        // Each reflection contributes the class of ⟨r, r⟩ = 2, and 2 · 2 is a square.
+        if reflection_decomposition(self).len().is_multiple_of(2) {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+/// Writes the automorphism as a product of as few reflections in roots as possible,
+/// returning the positive roots in the order the reflections are applied, so that composing them with `then` gives `w`.
+/// By Carter's theorem the fewest reflections is the rank of M - I, at most 8, and some root reflection applied after
+/// any automorphism other than the identity lowers that rank by one; the roots are found one such step at a time.
+/// Reflecting basis vectors onto their images, as in the proof of the Cartan–Dieudonné theorem,
+/// would also take at most 8 reflections, but in vectors that need not be roots.
+pub fn reflection_decomposition(w: &WeylElement) -> Vec<Octavian<i64>> {
+    let positive_roots: Vec<(Octavian<i64>, WeylElement)> = Octavian::UNITS
+        .iter()
+        .filter(|u| u.coefficients.iter().all(|&c| c >= 0))
+        .map(|u| {
+            let reflection = WeylElement::reflection(u).expect("the units are the roots");
+            (Octavian::new(u.coefficients.map(i64::from)), reflection)
+        })
+        .collect();
+    let mut remaining = *w;
+    let mut rank = remaining.moved_rank();
+    let mut roots = Vec::with_capacity(rank);
+    while rank > 0 {
+        let (root, shorter) = positive_roots
+            .iter()
+            .map(|(root, reflection)| (root, remaining.then(reflection)))
+            .find(|(_, shorter)| shorter.moved_rank() == rank - 1)
+            .expect("by Carter's theorem some root reflection lowers the rank");
+        // remaining is shorter followed by the reflection, which is its own inverse.
+        roots.push(*root);
+        remaining = shorter;
+        rank -= 1;
+    }
+    roots.reverse();
+    roots
+}
+
+/// Returns an automorphism w of E8 carrying the sublattice spanned by `basis_a` onto the one spanned by `basis_b`,
+/// so that the vectors a·w for a in `basis_a` form a basis of the second lattice, or `None` if there is none.
+/// Returns `AlcoError::NotABasis` unless both bases consist of 8 linearly independent vectors;
+/// sublattices of lower rank are not supported.
+///
+/// Lattices whose Gram determinants differ, or that hold different numbers of vectors of norm 1 or 2, are rejected at once.
+/// Otherwise the rows of the Hermite normal form H of the first lattice, hᵢ = Σⱼ Hᵢⱼ bⱼ with j running from i to 7,
+/// tie each basis vector bᵢ of E8 to the later ones, so the roots w(b₇), w(b₆), ..., w(b₀) are chosen in turn,
+/// backtracking whenever an inner product with an earlier image is wrong or w(hᵢ) falls outside the second lattice.
+/// Isometric lattices that are not related by an automorphism of E8 also give `None`. When the invariants agree
+/// but the answer is `None`, the search can visit a large part of the Weyl group, which takes tens of seconds;
+/// `find_isometry_with` bounds it.
+pub fn find_isometry(
+    basis_a: &[Octavian<i64>],
+    basis_b: &[Octavian<i64>],
+) -> Result<Option<WeylElement>, AlcoError> {
+    Ok(find_isometry_with(basis_a, basis_b, &())?.elements.pop())
+}
+
+/// Searches like `find_isometry`, reporting the number of partial automorphisms tried so far after each one
+/// and stopping early if `progress` breaks. The result holds the automorphism if one was found,
+/// and is incomplete if the search stopped before deciding whether there is one.
+pub fn find_isometry_with(
+    basis_a: &[Octavian<i64>],
+    basis_b: &[Octavian<i64>],
+    progress: &impl Progress,
+) -> Result<Enumeration<WeylElement>, AlcoError> {
+    // Returns the basis with the determinant of its Gram matrix, which vanishes exactly for dependent vectors.
+    let basis = |basis: &[Octavian<i64>]| {
+        let basis: [Octavian<i64>; 8] = basis.try_into().map_err(|_| AlcoError::NotABasis)?;
+        match determinant(&basis.map(|x| basis.map(|y| x.inner_product(&y)))) {
+            0 => Err(AlcoError::NotABasis),
+            det => Ok((basis, det)),
+        }
+    };
+    let ((a, det_a), (b, det_b)) = (basis(basis_a)?, basis(basis_b)?);
+    let none = Enumeration {
+        elements: Vec::new(),
+        complete: true,
+    };
+    if det_a != det_b {
+        return Ok(none);
+    }
+    let hermite: [[i64; 8]; 8] = hermite_normal_form(&a.map(|x| x.coefficients))
+        .try_into()
+        .expect("a basis has a square Hermite normal form");
+    let target = hermite_normal_form(&b.map(|x| x.coefficients));
+    for n in 1..=2 {
+        let shell = norm_shell(n);
+        let count = |hnf: &[[i64; 8]]| shell.iter().filter(|x| in_span(hnf, x)).count();
+        if count(&hermite) != count(&target) {
+            return Ok(none);
+        }
+    }
+    let roots = Octavian::<i64>::unit_vectors();
+    let mut images = [Octavian::zero(); 8];
+    let mut search = IsometrySearch {
+        hermite: &hermite,
+        target: &target,
+        roots: &roots,
+        progress,
+        tried: 0,
+    };
+    Ok(match search.extend(&mut images, 8) {
+        ControlFlow::Break(()) => Enumeration {
+            elements: Vec::new(),
+            complete: false,
+        },
+        ControlFlow::Continue(false) => none,
+        ControlFlow::Continue(true) => Enumeration {
+            elements: WeylElement::new(images.map(|x| x.coefficients))
+                .into_iter()
+                .collect(),
+            complete: true,
+        },
+    })
+}
+
+/// The state of the backtracking search of `find_isometry_with`.
+struct IsometrySearch<'a, P> {
+    hermite: &'a [[i64; 8]; 8],
+    target: &'a [[i64; 8]],
+    roots: &'a [Octavian<i64>],
+    progress: &'a P,
+    tried: u64,
+}
+
+impl<P: Progress> IsometrySearch<'_, P> {
+    /// Chooses the image of the basis vector bᵢ for i = `remaining` - 1 among the roots, given the images of the later
+    /// basis vectors, and recurses; returns whether the images of all of them were completed, or breaks if cancelled.
+    fn extend(
+        &mut self,
+        images: &mut [Octavian<i64>; 8],
+        remaining: usize,
+    ) -> ControlFlow<(), bool> {
+        let Some(i) = remaining.checked_sub(1) else {
+            return ControlFlow::Continue(true);
+        };
+        let gram = tables::gram_matrix_i64();
+        let tail = (i + 1..8).fold(Octavian::zero(), |sum, j| {
+            sum + images[j].scale(self.hermite[i][j])
+        });
+        for r in self.roots {
+            if (i + 1..8).all(|j| r.inner_product(&images[j]) == gram[i][j])
+                && in_span(self.target, &(r.scale(self.hermite[i][i]) + tail))
+            {
+                self.tried += 1;
+                self.progress.report(self.tried, None)?;
+                images[i] = *r;
+                if self.extend(images, i)? {
+                    return ControlFlow::Continue(true);
+                }
+            }
+        }
+        ControlFlow::Continue(false)
+    }
+}
+
+/// Tests whether x lies in the lattice spanned by the rows of a Hermite normal form, by reducing it with the rows in turn.
+fn in_span(hnf: &[[i64; 8]], x: &Octavian<i64>) -> bool {
+    let mut x = x.coefficients;
+    for row in hnf {
+        let pivot = row
+            .iter()
+            .position(|&c| c != 0)
+            .expect("rows of a Hermite normal form are nonzero");
+        if x[pivot] % row[pivot] != 0 {
+            return false;
+        }
+        let q = x[pivot] / row[pivot];
+        for (c, &r) in x.iter_mut().zip(row) {
+            *c -= q * r;
+        }
+    }
+    x == [0; 8]
+}
+
+/// Returns the orbit sum of the monomial x₀^e₀ ⋯ x₇^e₇ in variables attached to the E8 lattice coordinates,
+/// the Weyl-invariant Laurent polynomial that is the sum of the monomials whose exponent vectors make up the Weyl orbit
+/// of e, each with coefficient 1. These sums, over e in the dominant chamber, form a basis of the invariants of the
+/// Weyl group in the group ring of E8. The exponent vectors are returned in increasing order of the coefficients.
+/// With `cap`, the orbit search stops once it has found that many exponent vectors, and the sum is marked incomplete
+/// and holds `cap` of its terms, even if the orbit has exactly `cap` elements.
+pub fn orbit_sum_monomial(exponents: [u32; 8], cap: Option<usize>) -> Enumeration<Octavian<i64>> {
+    let e = Octavian::new(exponents.map(i64::from));
+    let limit = |done: u64, _| match cap {
+        Some(cap) if done >= cap as u64 => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    };
+    let mut orbit = weyl_orbit_with(&e, &limit);
+    if let Some(cap) = cap {
+        orbit.complete &= orbit.elements.len() <= cap;
+        orbit.elements.truncate(cap);
+    }
+    orbit
+}
+
+/// Returns the orbit of the unit with index `start` under the group generated by the given automorphisms, in increasing order.
+/// Each automorphism is converted once into a permutation of unit indices, so the search itself only follows indices.
+/// Since the group is finite, closing under the generators alone also closes under their inverses.
+pub fn unit_orbit_under(automorphisms: &[WeylElement], start: u16) -> Vec<u16> {
+    let permutations: Vec<[u16; 240]> = automorphisms
+        .iter()
+        .map(WeylElement::unit_permutation)
+        .collect();
+    let mut member = [false; 240];
+    member[start as usize] = true;
+    let mut orbit = vec![start];
+    let mut frontier = vec![start];
+    while let Some(x) = frontier.pop() {
+        for p in &permutations {
+            let y = p[x as usize];
+            if !member[y as usize] {
+                member[y as usize] = true;
+                orbit.push(y);
+                frontier.push(y);
+            }
+        }
+    }
+    orbit.sort_unstable();
+    orbit
+}
+
+/// Returns the orbit of `x` under the Weyl group of E8, in increasing order of the coefficients.
+/// The orbit of a generic vector has 696729600 elements, so this is only practical for special vectors such as roots.
+pub fn weyl_orbit(x: &Octavian<i64>) -> Vec<Octavian<i64>> {
+    weyl_orbit_with(x, &()).elements
+}
+
+/// Returns the orbit like `weyl_orbit`, found layer by layer under the simple reflections,
+/// reporting the size of the orbit found after each layer and stopping early if `progress` breaks.
+pub fn weyl_orbit_with(x: &Octavian<i64>, progress: &impl Progress) -> Enumeration<Octavian<i64>> {
+    let reflections = WeylElement::simple_reflections();
+    let mut seen = Dedup::new();
+    seen.insert(x);
+    let mut elements = vec![*x];
+    let mut start = 0;
+    let mut complete = true;
+    while start < elements.len() {
+        let end = elements.len();
+        for i in start..end {
+            for r in &reflections {
+                let y = r.apply(&elements[i]);
+                if seen.insert(&y) {
+                    elements.push(y);
+                }
+            }
+        }
+        start = end;
+        if progress.report(elements.len() as u64, None).is_break() && start < elements.len() {
+            complete = false;
+            break;
+        }
+    }
+    elements.sort_unstable_by_key(|y| y.coefficients);
+    Enumeration { elements, complete }
+}
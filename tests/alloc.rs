@@ -0,0 +1,70 @@
+//! Checks that hot paths do not allocate, counting allocations through a wrapping global allocator.
+//! The allocator is process-wide, so these tests live in their own test binary.
+
+use alco_rs::octavian::Octavian;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Wraps the system allocator to count allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by the current thread while running `f`.
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(|a| a.get());
+    let result = f();
+    (result, ALLOCATIONS.with(|a| a.get()) - before)
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that looking up a unit index does not allocate.
+fn unit_index_does_not_allocate() {
+    let (index, allocations) = count_allocations(|| Octavian::E7.unit_index());
+    assert!(index.is_some());
+    assert_eq!(0, allocations);
+}
+
+#[cfg(feature = "tables")]
+#[test]
+/// Ensure that snapping perturbed units back to the nearest unit does not allocate.
+fn nearest_unit_does_not_allocate() {
+    use alco_rs::units;
+    let units = Octavian::UNITS.map(|u| Octavian::new(u.coefficients.map(f64::from)));
+    let (result, allocations) = count_allocations(|| {
+        let mut ok = true;
+        for (i, u) in units.iter().enumerate() {
+            let noise = [0, 1, 2, 3, 4, 5, 6, 7].map(|j| ((i * 8 + j) % 13) as f64 / 100.0 - 0.06);
+            let (nearest, distance) = units::nearest_unit(&(*u + Octavian::new(noise)));
+            ok &= nearest == Octavian::UNITS[i] && distance < 0.5;
+        }
+        ok
+    });
+    assert!(result);
+    assert_eq!(0, allocations);
+}
+
+#[test]
+/// Ensure that collecting exactly eight coefficients does not allocate.
+fn try_from_iter_does_not_allocate() {
+    let (result, allocations) = count_allocations(|| Octavian::<i64>::try_from_iter(0..8));
+    assert_eq!(Ok(Octavian::new([0, 1, 2, 3, 4, 5, 6, 7])), result);
+    assert_eq!(0, allocations);
+}
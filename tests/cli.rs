@@ -0,0 +1,221 @@
+//! Runs the command-line binary on known inputs.
+
+use std::process::{Command, Output, Stdio};
+
+fn alco(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_alco-rs"))
+        .args(args)
+        .output()
+        .expect("the binary runs")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = alco(args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+const ONE: &str = "-2,-3,-4,-6,-5,-4,-3,-2";
+const MAX: &str = "9223372036854775807,0,0,0,0,0,0,0";
+const MIN: &str = "-9223372036854775808,0,0,0,0,0,0,0";
+
+#[test]
+fn mul_norm_conj() {
+    assert_eq!(
+        stdout(&["mul", ONE, "1,0,0,0,0,0,0,0"]),
+        "1,0,0,0,0,0,0,0\n"
+    );
+    assert_eq!(stdout(&["norm", "1, 0, 0, 0, 0, 0, 0, 0"]), "1\n");
+    assert_eq!(stdout(&["norm", "0,0,0,0,0,0,0,0"]), "0\n");
+    assert_eq!(stdout(&["conj", ONE]), format!("{}\n", ONE));
+    let conj = stdout(&["conj", "1,0,0,0,0,0,0,0"]);
+    let product = stdout(&["mul", conj.trim(), "1,0,0,0,0,0,0,0"]);
+    assert_eq!(product, format!("{}\n", ONE));
+}
+
+#[test]
+fn octonion_notation() {
+    assert_eq!(stdout(&["mul", "1", "e1"]), stdout(&["conj", "-e1"]));
+    assert_eq!(stdout(&["conj", "1 + e1"]), stdout(&["mul", "1-e1", "1"]));
+    assert_eq!(stdout(&["norm", "1/2+(1/2)e1+(1/2)e2+(1/2)e3"]), "1\n");
+    assert_eq!(stdout(&["norm", "1 + i + j + kl"]), "4\n");
+    assert_eq!(
+        stdout(&[
+            "mul",
+            "-2b0 - 3b1 - 4b2 - 6b3 - 5b4 - 4b5 - 3b6 - 2b7",
+            "b7"
+        ]),
+        "0,0,0,0,0,0,0,1\n"
+    );
+    assert_eq!(stdout(&["conj", ONE]), stdout(&["conj", "1"]));
+}
+
+#[test]
+fn factor_into_primes() {
+    let factors = stdout(&["factor", "2b0+3b1-b7"]);
+    let factors: Vec<&str> = factors.lines().collect();
+    assert_eq!(factors.len(), 2);
+    let norms: Vec<String> = factors.iter().map(|f| stdout(&["norm", f])).collect();
+    assert_eq!(norms, ["2\n", "7\n"]);
+    let product = stdout(&["mul", factors[0], factors[1]]);
+    assert_eq!(product, stdout(&["mul", "2b0+3b1-b7", "1"]));
+    assert_eq!(stdout(&["factor", "e3"]), stdout(&["mul", "e3", "1"]));
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn units_and_shells() {
+    let csv = stdout(&["units"]);
+    assert_eq!(csv.lines().count(), 240);
+    assert_eq!(csv, stdout(&["units", "--format", "csv"]));
+    assert_eq!(csv, stdout(&["shell", "1"]));
+    assert_eq!(stdout(&["shell", "2"]).lines().count(), 2160);
+    let gap = stdout(&["units", "--format", "gap"]);
+    assert!(gap.starts_with("[ [ "));
+    assert_eq!(gap.matches('[').count(), 241);
+}
+
+#[test]
+fn closure_of_generators() {
+    let path = std::env::temp_dir().join(format!("alco-rs-cli-{}.csv", std::process::id()));
+    std::fs::write(&path, "1,0,0,0,0,0,0,0\n0,0,0,0,0,0,1,0\n0,0,0,0,0,0,0,1\n").unwrap();
+    let path = path.to_str().unwrap();
+    let closure = stdout(&["closure", "--generators", path]);
+    assert_eq!(closure.lines().count(), 240);
+    let capped = alco(&["closure", "--generators", path, "--cap", "10"]);
+    assert!(!capped.status.success());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn malformed_input_fails() {
+    for args in [
+        &["mul", "1,2,3", ONE][..],
+        &["norm", "1,0,0,x,0,0,0,0"],
+        &["norm"],
+        &["shell", "two"],
+        &["units", "--format", "xml"],
+        &["closure", "--generators", "/nonexistent/generators.csv"],
+        &["frobnicate"],
+        &["mul", MAX, MAX],
+        &["conj", MIN],
+        &["factor", "0"],
+        &["factor", "16777259b0"],
+        &["factor", "1,0,0,0,0,0,0,0", ONE],
+        &["norm", "(1/2)e1"],
+        &["norm", "1 + xe1"],
+    ] {
+        let output = alco(args);
+        assert!(!output.status.success(), "{:?} succeeded", args);
+        assert!(output.stdout.is_empty());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.starts_with("error: "), "{}", stderr);
+        assert!(!stderr.contains("panicked"), "{}", stderr);
+    }
+    let stderr = String::from_utf8(alco(&["mul", "1,2,3", ONE]).stderr).unwrap();
+    assert!(stderr.contains("expected 8 coordinates, found 3"));
+    let stderr = String::from_utf8(alco(&["conj", MIN]).stderr).unwrap();
+    assert!(stderr.contains("the conjugate does not fit in 64 bits"));
+    let stderr = String::from_utf8(alco(&["factor", "0"]).stderr).unwrap();
+    assert!(stderr.contains("could not factor: the octavian is zero"));
+    let stderr = String::from_utf8(alco(&["norm", "(1/2)e1"]).stderr).unwrap();
+    assert!(stderr.contains("not an octavian integer"), "{}", stderr);
+}
+
+fn alco_stdin(args: &[&str], input: &str) -> Output {
+    use std::io::Write;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_alco-rs"))
+        .arg("--stdin")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("the binary runs");
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()).unwrap());
+    let output = child.wait_with_output().unwrap();
+    writer.join().unwrap();
+    output
+}
+
+#[test]
+fn stdin_batch() {
+    let mut script = String::new();
+    for i in 0..1000 {
+        match i % 3 {
+            0 => script.push_str(&format!("mul {} {},0,0,0,0,0,0,0\n", ONE, i)),
+            1 => script.push_str(&format!("norm {},0,0,0,0,0,0,0\n", i)),
+            _ => script.push_str(&format!("conj 0,0,0,0,0,0,0,{}\n", i)),
+        }
+    }
+    let output = alco_stdin(&[], &script);
+    assert!(output.status.success());
+    let plain = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(lines.len(), 1000);
+    assert_eq!(lines[3], "3 0 0 0 0 0 0 0");
+    assert_eq!(lines[4], "16");
+
+    let csv = alco_stdin(&["--output", "csv"], &script);
+    assert_eq!(
+        String::from_utf8(csv.stdout).unwrap(),
+        plain.replace(' ', ",")
+    );
+    assert_eq!(alco_stdin(&[], &script).stdout, plain.as_bytes());
+}
+
+#[test]
+fn stdin_batch_continues_after_errors() {
+    let script = "norm 1,0,0,0,0,0,0,0\n\nnorm 1,0,0\nfrobnicate\nnorm 0,2,0,0,0,0,0,0\n";
+    let output = alco_stdin(&["--output", "csv"], script);
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1\n4\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let errors: Vec<&str> = stderr.lines().collect();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("error: line 3: "));
+    assert!(errors[1].starts_with("error: line 4: "));
+
+    let output = alco_stdin(&["--output", "json"], "");
+    assert!(!output.status.success());
+}
+
+#[cfg(feature = "tables")]
+#[test]
+fn stdin_batch_keeps_output_intact() {
+    // The separator only joins coordinates, so GAP lists and the usage text keep their commas.
+    let output = alco_stdin(&[], "units --format gap\nhelp\n");
+    assert!(output.status.success());
+    let plain = String::from_utf8(output.stdout).unwrap();
+    assert!(plain.starts_with("[ [ -2, -3, -4, -6, -5, -4, -3, -2 ],"));
+    assert!(plain.contains("such as 1,0,0,0,0,0,0,0,"));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn stdin_batch_survives_panics() {
+    // The closure of a huge generator overflows, which panics in debug builds.
+    let path = std::env::temp_dir().join(format!("alco-rs-panic-{}.csv", std::process::id()));
+    std::fs::write(&path, "9223372036854775807,0,0,0,0,0,0,0\n").unwrap();
+    let script = format!(
+        "closure --generators {}\nnorm 1,0,0,0,0,0,0,0\n",
+        path.display()
+    );
+    let output = alco_stdin(&[], &script);
+    std::fs::remove_file(&path).unwrap();
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "1\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.starts_with("error: line 1: internal error: "),
+        "{}",
+        stderr
+    );
+    assert_eq!(stderr.lines().count(), 1);
+}
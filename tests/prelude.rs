@@ -0,0 +1,80 @@
+//! Uses the crate through its prelude alone, as downstream code would.
+
+use alco_rs::prelude::*;
+
+#[cfg(feature = "tables")]
+#[test]
+fn prelude_slice_methods_agree_with_loops() {
+    let units: Vec<Octavian<i64>> = UNITS
+        .iter()
+        .step_by(7)
+        .map(|u| Octavian::new(u.coefficients.map(i64::from)))
+        .collect();
+
+    let mut sum = Octavian::zero();
+    for x in &units {
+        sum += *x;
+    }
+    assert_eq!(sum, units.sum_octavians());
+
+    let mut product = units[0];
+    for x in &units[1..] {
+        product *= *x;
+    }
+    assert_eq!(product, units.product_left());
+    assert_eq!(1, units.product_left().norm());
+
+    let scaled: Vec<Octavian<i64>> = units.iter().zip(1..).map(|(x, t)| x.scale(t)).collect();
+    let mut norms = Vec::new();
+    let mut max = None;
+    for x in &scaled {
+        norms.push(x.norm());
+        max = max.max(Some(x.norm()));
+    }
+    assert_eq!(norms, scaled.norms());
+    assert_eq!(max, scaled.max_norm());
+
+    let mut repeated = units.clone();
+    repeated.extend(units.iter().rev());
+    let mut distinct: Vec<Octavian<i64>> = Vec::new();
+    for x in &repeated {
+        if !distinct.contains(x) {
+            distinct.push(*x);
+        }
+    }
+    assert_eq!(distinct, repeated.dedup_exact());
+    assert_eq!(units, distinct);
+
+    let empty: [Octavian<i64>; 0] = [];
+    assert_eq!(Octavian::zero(), empty.sum_octavians());
+    assert_eq!(Octavian::one(), empty.product_left());
+    assert_eq!(None, empty.max_norm());
+    assert!(empty.norms().is_empty() && empty.dedup_exact().is_empty());
+
+    let floats = [Octavian::new([0.5; 8]), Octavian::new([f64::NAN; 8])];
+    assert_eq!(None, floats.max_norm());
+    assert_eq!(Some(floats[0].norm()), floats[..1].max_norm());
+}
+
+#[test]
+fn prelude_reexports_core_types() {
+    let parsed: Result<Octavian<i64>, AlcoError> = "1,0,0,0,0,0,0,0".parse();
+    let x = parsed.unwrap();
+    #[cfg(feature = "tables")]
+    assert!(UNITS.iter().any(|u| x.eq_coeffs(u)));
+    assert!(1i64.coefficient_eq(&1i8));
+    let left: LeftMultiplier<i64> = x.left_multiplier();
+    let right: RightMultiplier<i64> = x.right_multiplier();
+    assert_eq!(left.apply(&x), right.apply(&x));
+    let view: OctavianRef<'_, i64> = x.as_ref_view();
+    assert_eq!(x.norm(), view.norm());
+    let data = [x.coefficients, x.coefficients].concat();
+    assert_eq!(2, OctavianSlice::new(&data).unwrap().len());
+    let quiet: &dyn Progress = &();
+    assert!(quiet.report(1, None).is_continue());
+    let done = Enumeration {
+        elements: vec![x],
+        complete: true,
+    };
+    assert!(done.complete);
+}